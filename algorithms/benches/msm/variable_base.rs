@@ -55,10 +55,38 @@ fn variable_base_edwards_bls12(c: &mut Criterion) {
     }
 }
 
+fn variable_base_precomputed_bls12_377(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    const SIZE: usize = 1 << 16;
+    const NUM_REPETITIONS: usize = 8;
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(SIZE * NUM_REPETITIONS);
+
+    c.bench_function(&format!("VariableBase MSM on BLS12-377 ({SIZE} x {NUM_REPETITIONS})"), |b| {
+        b.iter(|| scalars.chunks(SIZE).map(|scalars| VariableBase::msm(&bases[..SIZE], scalars)).collect::<Vec<_>>())
+    });
+    for window_bits in [8, 12, 16] {
+        c.bench_function(&format!("VariableBase precompute on BLS12-377 ({SIZE}, {window_bits} bits)"), |b| {
+            b.iter(|| VariableBase::precompute(&bases[..SIZE], window_bits))
+        });
+
+        // The table is built once, as in a committer key, and reused for every MSM.
+        let table = VariableBase::precompute(&bases[..SIZE], window_bits);
+        c.bench_function(
+            &format!("VariableBase MSM with precompute on BLS12-377 ({SIZE} x {NUM_REPETITIONS}, {window_bits} bits)"),
+            |b| {
+                b.iter(|| {
+                    let msm = |scalars| VariableBase::msm_with_precompute(&table, scalars);
+                    scalars.chunks(SIZE).map(msm).collect::<Vec<_>>()
+                })
+            },
+        );
+    }
+}
+
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base_bls12_377, variable_base_edwards_bls12
+    targets = variable_base_bls12_377, variable_base_edwards_bls12, variable_base_precomputed_bls12_377
 }
 
 criterion_main!(variable_base_group);
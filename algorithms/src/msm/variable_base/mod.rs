@@ -13,15 +13,73 @@
 // limitations under the License.
 
 pub mod batched;
+pub mod precomputed;
 pub mod standard;
 
+pub use precomputed::MsmPrecomputeTable;
+
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
 use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
 use snarkvm_fields::PrimeField;
 
-use core::any::TypeId;
+use core::{
+    any::TypeId,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use std::sync::{Once, OnceLock};
+
+/// The environment variable that disables CUDA, when set to any value other than `0` or `false`.
+pub const DISABLE_CUDA_ENV_VAR: &str = "SNARKVM_DISABLE_CUDA";
+
+/// The switch for dispatching MSMs to the GPU, which is initialized from `SNARKVM_DISABLE_CUDA` on first use.
+static CUDA_ENABLED: OnceLock<AtomicBool> = OnceLock::new();
+
+/// Returns the switch for dispatching MSMs to the GPU.
+fn cuda_switch() -> &'static AtomicBool {
+    CUDA_ENABLED.get_or_init(|| {
+        let disabled = match std::env::var(DISABLE_CUDA_ENV_VAR) {
+            Ok(value) => !matches!(value.trim().to_lowercase().as_str(), "" | "0" | "false"),
+            Err(_) => false,
+        };
+        AtomicBool::new(!disabled)
+    })
+}
+
+/// The warning for falling back to the CPU, which is only printed for the first GPU failure.
+static CPU_FALLBACK_WARNING: Once = Once::new();
+
+/// Returns `true` if variable base MSMs may be dispatched to the GPU, i.e. if snarkVM is compiled with CUDA
+/// support and CUDA is not disabled by `SNARKVM_DISABLE_CUDA` or `set_cuda_enabled`.
+/// The CPU is still used if the GPU fails.
+pub fn cuda_available() -> bool {
+    cfg!(all(feature = "cuda", target_arch = "x86_64")) && cuda_switch().load(Ordering::Relaxed)
+}
+
+/// Enables or disables dispatching variable base MSMs to the GPU, overriding `SNARKVM_DISABLE_CUDA`.
+pub fn set_cuda_enabled(enabled: bool) {
+    cuda_switch().store(enabled, Ordering::Relaxed);
+}
+
+/// Returns the result of the GPU MSM, or computes the MSM on the CPU if the GPU failed with the given error code.
+#[cfg_attr(not(all(feature = "cuda", target_arch = "x86_64")), allow(dead_code))]
+fn gpu_or_cpu_msm<G: AffineCurve, C: core::fmt::Display>(
+    gpu_result: Result<G::Projective, C>,
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> G::Projective {
+    match gpu_result {
+        Ok(result) => result,
+        Err(code) => {
+            // Note: MSMs are on the hot path, so the warning is only printed once per process.
+            CPU_FALLBACK_WARNING.call_once(|| {
+                eprintln!("Falling back to the CPU for MSMs (CUDA error code {code}), without reporting later failures")
+            });
+            batched::msm(bases, scalars)
+        }
+    }
+}
 
 pub struct VariableBase;
 
@@ -31,13 +89,12 @@ impl VariableBase {
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
             // TODO SNP: where to set the threshold
-            if scalars.len() > 1024 {
+            if scalars.len() > 1024 && cuda_available() {
                 let result = snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
                     bases, scalars,
                 );
-                if let Ok(result) = result {
-                    return result;
-                }
+                // If the kernel cannot be loaded or the device fails, fall back to the CPU.
+                return gpu_or_cpu_msm(result.map_err(|error| error.code), bases, scalars);
             }
             batched::msm(bases, scalars)
         }
@@ -47,6 +104,19 @@ impl VariableBase {
         }
     }
 
+    /// Returns a table of the given bases, for repeated MSMs over the same bases with `msm_with_precompute`.
+    pub fn precompute<G: AffineCurve>(bases: &[G], window_bits: usize) -> MsmPrecomputeTable<G> {
+        MsmPrecomputeTable::new(bases, window_bits)
+    }
+
+    /// Performs a variable base MSM over the bases in the given table, which must cover every scalar.
+    pub fn msm_with_precompute<G: AffineCurve>(
+        table: &MsmPrecomputeTable<G>,
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> G::Projective {
+        precomputed::msm(table, scalars)
+    }
+
     #[cfg(test)]
     fn msm_naive<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
         use itertools::Itertools;
@@ -74,6 +144,8 @@ mod tests {
     use snarkvm_fields::PrimeField;
     use snarkvm_utilities::rand::TestRng;
 
+    use serial_test::serial;
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     use snarkvm_curves::ProjectiveCurve;
 
@@ -105,8 +177,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msm_with_precompute() {
+        use snarkvm_curves::ProjectiveCurve;
+        let mut rng = TestRng::default();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 1000);
+
+        for window_bits in [1, 4, 8, 13] {
+            let table = VariableBase::precompute(bases.as_slice(), window_bits);
+            assert_eq!(table.num_bases(), bases.len());
+
+            // Ensure the table may be reused for MSMs of several lengths.
+            for msm_size in [0, 1, 5, 10, 50, 100, 500, 1000] {
+                let expected = VariableBase::msm_naive(&bases[..msm_size], &scalars[..msm_size]).to_affine();
+                let candidate = VariableBase::msm_with_precompute(&table, &scalars[..msm_size]).to_affine();
+                assert_eq!(expected, candidate, "MSM size: {msm_size}, window bits: {window_bits}");
+            }
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_msm_sequential_matches_parallel() {
+        use snarkvm_curves::ProjectiveCurve;
+        let mut rng = TestRng::default();
+
+        // Initialize a thread pool with a single thread, which runs every parallel iterator sequentially.
+        let sequential = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+
+        for msm_size in [1, 10, 100, 1000, 2048] {
+            let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+            let expected = VariableBase::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+            let candidate = sequential.install(|| VariableBase::msm(bases.as_slice(), scalars.as_slice())).to_affine();
+            assert_eq!(expected, candidate, "MSM size: {msm_size}");
+
+            let table = VariableBase::precompute(bases.as_slice(), 8);
+            let candidate = sequential.install(|| VariableBase::msm_with_precompute(&table, scalars.as_slice()));
+            assert_eq!(expected, candidate.to_affine(), "MSM size: {msm_size}");
+        }
+    }
+
+    // Note: This test toggles the global CUDA switch, so it is serialized with the tests that depend on it.
+    #[test]
+    #[serial]
+    fn test_msm_cuda_disabled() {
+        use snarkvm_curves::ProjectiveCurve;
+        let mut rng = TestRng::default();
+
+        // Retrieve the CUDA switch, to restore it afterwards.
+        let cuda_enabled = cuda_switch().load(Ordering::Relaxed);
+
+        // Ensure CUDA may be disabled.
+        set_cuda_enabled(false);
+        assert!(!cuda_available());
+
+        // Ensure the MSM falls back to the CPU, above the GPU threshold.
+        for msm_size in [1025, 2048] {
+            let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+            let expected = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice()).to_affine();
+            let candidate = VariableBase::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+            assert_eq!(expected, candidate, "MSM size: {msm_size}");
+        }
+
+        // Ensure CUDA is only available if compiled with CUDA support.
+        set_cuda_enabled(true);
+        assert_eq!(cuda_available(), cfg!(all(feature = "cuda", target_arch = "x86_64")));
+
+        // Restore the CUDA switch.
+        set_cuda_enabled(cuda_enabled);
+    }
+
+    #[test]
+    fn test_msm_gpu_error_falls_back_to_cpu() {
+        use snarkvm_curves::ProjectiveCurve;
+        use snarkvm_fields::Zero;
+        let mut rng = TestRng::default();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 1025);
+
+        // Ensure a GPU error is replaced by the CPU result.
+        let expected = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice()).to_affine();
+        let candidate = gpu_or_cpu_msm::<G1Affine, i32>(Err(-1), bases.as_slice(), scalars.as_slice()).to_affine();
+        assert_eq!(expected, candidate);
+
+        // Ensure a GPU result is returned as is.
+        let gpu_result = <G1Affine as AffineCurve>::Projective::zero();
+        let candidate = gpu_or_cpu_msm::<G1Affine, i32>(Ok(gpu_result), bases.as_slice(), scalars.as_slice());
+        assert_eq!(gpu_result, candidate);
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
+    #[serial]
     fn test_msm_cuda() {
         let mut rng = TestRng::default();
         for i in 2..17 {
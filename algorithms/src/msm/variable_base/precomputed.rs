@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_chunks, cfg_iter, serialize::*, BigInteger};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A table of bases for repeated variable base MSMs over the same bases.
+///
+/// Each base is stored shifted by every window, i.e. as `base * 2^(j * window_bits)` for each window `j`.
+/// An MSM over the table sorts every window of every scalar into a single set of buckets,
+/// which removes the doublings between windows from each call.
+#[derive(Clone, Debug)]
+pub struct MsmPrecomputeTable<G: AffineCurve> {
+    /// The number of bits in each window.
+    window_bits: usize,
+    /// The number of windows in each scalar.
+    num_windows: usize,
+    /// The shifted bases, where the entry at `i * num_windows + j` is `bases[i] * 2^(j * window_bits)`.
+    shifted_bases: Vec<G>,
+}
+
+impl<G: AffineCurve> MsmPrecomputeTable<G> {
+    /// The maximum number of bits in each window.
+    pub const MAX_WINDOW_BITS: usize = 20;
+
+    /// Initializes a new table for the given bases, with windows of `window_bits` bits.
+    pub fn new(bases: &[G], window_bits: usize) -> Self {
+        assert!((1..=Self::MAX_WINDOW_BITS).contains(&window_bits), "Invalid MSM window size ({window_bits} bits)");
+
+        // Compute the number of windows in each scalar.
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = num_bits.div_ceil(window_bits);
+
+        // Compute the shifted bases for each base.
+        let shifted_bases = cfg_iter!(bases)
+            .flat_map(|base| {
+                let mut current = base.to_projective();
+                let mut shifted_bases = Vec::with_capacity(num_windows);
+                for _ in 0..num_windows {
+                    shifted_bases.push(current);
+                    for _ in 0..window_bits {
+                        current.double_in_place();
+                    }
+                }
+                shifted_bases
+            })
+            .collect::<Vec<_>>();
+
+        Self { window_bits, num_windows, shifted_bases: G::Projective::batch_normalization_into_affine(shifted_bases) }
+    }
+
+    /// Returns the number of bits in each window.
+    pub const fn window_bits(&self) -> usize {
+        self.window_bits
+    }
+
+    /// Returns the number of bases in the table.
+    pub fn num_bases(&self) -> usize {
+        self.shifted_bases.len() / self.num_windows
+    }
+}
+
+impl<G: AffineCurve> CanonicalSerialize for MsmPrecomputeTable<G> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.window_bits.serialize_with_mode(&mut writer, compress)?;
+        self.shifted_bases.serialize_with_mode(&mut writer, compress)?;
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.window_bits.serialized_size(compress) + self.shifted_bases.serialized_size(compress)
+    }
+}
+
+impl<G: AffineCurve> CanonicalDeserialize for MsmPrecomputeTable<G> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let window_bits: usize = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        if !(1..=Self::MAX_WINDOW_BITS).contains(&window_bits) {
+            return Err(SerializationError::InvalidData);
+        }
+        let num_windows = <G::ScalarField as PrimeField>::size_in_bits().div_ceil(window_bits);
+        let shifted_bases = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let table = Self { window_bits, num_windows, shifted_bases };
+        if let Validate::Yes = validate {
+            table.check()?;
+        }
+        Ok(table)
+    }
+}
+
+impl<G: AffineCurve> Valid for MsmPrecomputeTable<G> {
+    fn check(&self) -> Result<(), SerializationError> {
+        // Ensure the shifted bases hold every window of each base.
+        match self.shifted_bases.len() % self.num_windows {
+            0 => Valid::check(&self.shifted_bases),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+/// Sorts every window of each scalar into the buckets, and returns the sum of the buckets.
+fn bucket_sum<G: AffineCurve>(
+    shifted_bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    window_bits: usize,
+    num_windows: usize,
+) -> G::Projective {
+    // We don't need the "zero" bucket, so we only have 2^c - 1 buckets.
+    let mut buckets = vec![G::Projective::zero(); (1 << window_bits) - 1];
+
+    for (scalar, shifted_bases) in scalars.iter().zip(shifted_bases.chunks(num_windows)) {
+        let mut scalar = *scalar;
+        for shifted_base in shifted_bases {
+            // We mod the remaining bits by the window size.
+            let digit = scalar.as_ref()[0] % (1 << window_bits);
+            // We right-shift by the window size, to move to the next window.
+            scalar.divn(window_bits as u32);
+            // If the digit is non-zero, we update the corresponding bucket.
+            if digit != 0 {
+                buckets[(digit - 1) as usize].add_assign_mixed(shifted_base);
+            }
+        }
+    }
+
+    buckets
+        .into_iter()
+        .rev()
+        .scan(G::Projective::zero(), |sum, bucket| {
+            *sum += bucket;
+            Some(*sum)
+        })
+        .sum()
+}
+
+/// Returns the MSM of the given scalars over the bases in the table.
+/// The `i`-th scalar is multiplied by the `i`-th base, so there may be fewer scalars than bases.
+///
+/// # Panics
+///
+/// Panics if there are more scalars than bases in the table.
+pub fn msm<G: AffineCurve>(
+    table: &MsmPrecomputeTable<G>,
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> G::Projective {
+    assert!(scalars.len() <= table.num_bases(), "Found more scalars than bases in the MSM table");
+
+    let num_windows = table.num_windows;
+    // Split the scalars into one chunk per thread, and sum the buckets for each chunk.
+    let chunk_size = match scalars.len() / snarkvm_utilities::parallel::max_available_threads() {
+        0 => 1,
+        chunk_size => chunk_size,
+    };
+    cfg_chunks!(scalars, chunk_size)
+        .enumerate()
+        .map(|(i, scalars)| {
+            let start = i * chunk_size * num_windows;
+            let end = start + scalars.len() * num_windows;
+            bucket_sum(&table.shifted_bases[start..end], scalars, table.window_bits, num_windows)
+        })
+        .sum()
+}
@@ -29,10 +29,20 @@ use snarkvm_utilities::{
 };
 
 use crate::srs::{UniversalProver, UniversalVerifier};
-use anyhow::Result;
-use core::ops::{Add, AddAssign};
+use anyhow::{ensure, Result};
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Add, AddAssign},
+};
+use parking_lot::Mutex;
 use rand_core::RngCore;
-use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    io::{self, Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
 
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
 #[derive(Clone, Debug)]
@@ -179,6 +189,126 @@ impl<E: PairingEngine> LagrangeBasis<'_, E> {
     }
 }
 
+/// A source of bytes that `LazyPowersOfBetaG` reads the powers from.
+trait PowersSource: Read + Seek + Send {}
+
+impl<T: Read + Seek + Send> PowersSource for T {}
+
+/// `LazyPowersOfBetaG` reads the powers of beta G from a `Read + Seek` source on demand,
+/// so that a commitment only holds the powers for one chunk of coefficients at a time.
+///
+/// The source holds the byte encoding of the powers, starting at `offset`.
+/// A memory-mapped file is used as a source by wrapping it in a `std::io::Cursor`.
+pub struct LazyPowersOfBetaG<E: PairingEngine> {
+    /// The source of the powers.
+    source: Mutex<Box<dyn PowersSource>>,
+    /// The position of the first power in the source.
+    offset: u64,
+    /// The number of powers in the source.
+    num_powers: usize,
+    /// The number of powers that are read for each chunk of coefficients.
+    chunk_size: usize,
+    _engine: PhantomData<E>,
+}
+
+impl<E: PairingEngine> LazyPowersOfBetaG<E> {
+    /// Initializes the lazy powers from the `num_powers` powers at `offset` in `source`,
+    /// which are read `chunk_size` powers at a time.
+    pub fn new<S: Read + Seek + Send + 'static>(
+        mut source: S,
+        offset: u64,
+        num_powers: usize,
+        chunk_size: usize,
+    ) -> Result<Self> {
+        ensure!(chunk_size > 0, "The chunk size of the lazy powers must be positive");
+
+        // Ensure the source holds every power.
+        let source_len = source.seek(SeekFrom::End(0))?;
+        let powers_len = (num_powers as u64).saturating_mul(Self::power_size()?);
+        ensure!(
+            offset.saturating_add(powers_len) <= source_len,
+            "The source of the lazy powers is too short for {num_powers} powers"
+        );
+
+        Ok(Self { source: Mutex::new(Box::new(source)), offset, num_powers, chunk_size, _engine: PhantomData })
+    }
+
+    /// Returns the number of bytes in the encoding of each power.
+    pub fn power_size() -> Result<u64> {
+        Ok(E::G1Affine::prime_subgroup_generator().to_bytes_le()?.len() as u64)
+    }
+
+    /// Returns the number of powers in `self`.
+    pub const fn num_powers(&self) -> usize {
+        self.num_powers
+    }
+
+    /// Returns the number of powers that are read for each chunk of coefficients.
+    pub const fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns the powers of beta G that lie within `range`.
+    pub fn read(&self, range: Range<usize>) -> Result<Vec<E::G1Affine>> {
+        ensure!(
+            range.start <= range.end && range.end <= self.num_powers,
+            "The range {range:?} exceeds the {} lazy powers",
+            self.num_powers
+        );
+
+        let mut source = self.source.lock();
+        source.seek(SeekFrom::Start(self.offset + range.start as u64 * Self::power_size()?))?;
+        let mut reader = io::BufReader::new(&mut *source);
+        range.map(|_| Ok(E::G1Affine::read_le(&mut reader)?)).collect()
+    }
+}
+
+impl<E: PairingEngine> fmt::Debug for LazyPowersOfBetaG<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LazyPowersOfBetaG")
+            .field("offset", &self.offset)
+            .field("num_powers", &self.num_powers)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
+impl<E: PairingEngine> CanonicalSerialize for LazyPowersOfBetaG<E> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        // Note: The encoding holds every power, so this reads all of the powers into memory.
+        self.chunk_size.serialize_with_mode(&mut writer, compress)?;
+        self.read(0..self.num_powers)?.serialize_with_mode(&mut writer, compress)?;
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        let power_size = E::G1Affine::prime_subgroup_generator().serialized_size(compress);
+        self.chunk_size.serialized_size(compress)
+            + self.num_powers.serialized_size(compress)
+            + self.num_powers * power_size
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for LazyPowersOfBetaG<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let chunk_size: usize = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let powers: Vec<E::G1Affine> = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        // The deserialized powers are read from an in-memory buffer.
+        Ok(Self::new(io::Cursor::new(powers.to_bytes_le()?), 0, powers.len(), chunk_size)?)
+    }
+}
+
+impl<E: PairingEngine> Valid for LazyPowersOfBetaG<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        // The powers are checked as they are read from the source.
+        Ok(())
+    }
+}
+
 /// `VerifierKey` is used to check evaluation proofs for a given commitment.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct VerifierKey<E: PairingEngine> {
@@ -21,7 +21,7 @@
 
 use crate::{
     fft::{DensePolynomial, Polynomial},
-    msm::VariableBase,
+    msm::{MsmPrecomputeTable, VariableBase},
     polycommit::PCError,
 };
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
@@ -29,7 +29,10 @@ use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_utilities::{cfg_iter, cfg_iter_mut, rand::Uniform, BitIteratorBE};
 
 use anyhow::{anyhow, ensure, Result};
-use core::{marker::PhantomData, ops::Mul};
+use core::{
+    marker::PhantomData,
+    ops::{Mul, Range},
+};
 use itertools::Itertools;
 use rand_core::RngCore;
 
@@ -99,6 +102,29 @@ impl<E: PairingEngine> KZG10<E> {
         polynomial: &Polynomial<'_, E::Fr>,
         hiding_bound: Option<usize>,
         rng: Option<&mut dyn RngCore>,
+    ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
+        Self::commit_inner(powers, None, polynomial, hiding_bound, rng)
+    }
+
+    /// Outputs a commitment to `polynomial`, using the given table of `powers.powers_of_beta_g` for the MSM.
+    ///
+    /// The table must be built over a prefix of `powers.powers_of_beta_g`, such as in `CommitterKey`.
+    pub fn commit_with_precompute(
+        powers: &Powers<E>,
+        table: &MsmPrecomputeTable<E::G1Affine>,
+        polynomial: &Polynomial<'_, E::Fr>,
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
+        Self::commit_inner(powers, Some(table), polynomial, hiding_bound, rng)
+    }
+
+    fn commit_inner(
+        powers: &Powers<E>,
+        table: Option<&MsmPrecomputeTable<E::G1Affine>>,
+        polynomial: &Polynomial<'_, E::Fr>,
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
     ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
         Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
 
@@ -108,8 +134,18 @@ impl<E: PairingEngine> KZG10<E> {
             hiding_bound,
         ));
 
-        let mut commitment = match polynomial {
-            Polynomial::Dense(polynomial) => {
+        let mut commitment = match (polynomial, table) {
+            // The table covers every coefficient, so the leading zeros are kept as zero scalars.
+            (Polynomial::Dense(polynomial), Some(table)) if polynomial.coeffs.len() <= table.num_bases() => {
+                let plain_coeffs = convert_to_bigints(&polynomial.coeffs);
+
+                let msm_time = start_timer!(|| "MSM with precompute to compute commitment to plaintext poly");
+                let commitment = VariableBase::msm_with_precompute(table, &plain_coeffs);
+                end_timer!(msm_time);
+
+                commitment
+            }
+            (Polynomial::Dense(polynomial), _) => {
                 let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
 
                 let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + plain_coeffs.len())];
@@ -120,7 +156,7 @@ impl<E: PairingEngine> KZG10<E> {
 
                 commitment
             }
-            Polynomial::Sparse(polynomial) => polynomial
+            (Polynomial::Sparse(polynomial), _) => polynomial
                 .coeffs()
                 .map(|(i, coeff)| {
                     powers.powers_of_beta_g[*i].mul_bits(BitIteratorBE::new_without_leading_zeros(coeff.to_bigint()))
@@ -204,6 +240,94 @@ impl<E: PairingEngine> KZG10<E> {
         Ok((KZGCommitment(commitment.into()), randomness))
     }
 
+    /// Outputs a non-hiding commitment to the polynomial whose coefficients are given in consecutive `chunks`.
+    ///
+    /// The MSM is accumulated chunk by chunk, and the powers of beta G for each chunk are requested from
+    /// `powers_of_beta_g` just before they are used, so this function holds one chunk of powers at a time.
+    /// The peak memory also depends on the source of the powers, e.g. `UniversalParams` keeps every power it loads.
+    pub fn commit_streaming<'a>(
+        mut powers_of_beta_g: impl FnMut(Range<usize>) -> Result<Vec<E::G1Affine>>,
+        chunks: impl IntoIterator<Item = &'a [E::Fr]>,
+    ) -> Result<KZGCommitment<E>, PCError> {
+        let commit_time = start_timer!(|| "Committing to polynomial in chunks");
+
+        let mut commitment = E::G1Projective::zero();
+        let mut offset = 0;
+        for chunk in chunks {
+            let range = offset..(offset + chunk.len());
+            let bases = powers_of_beta_g(range.clone())?;
+            if bases.len() != chunk.len() {
+                Err(anyhow!("Expected {} powers for the range {range:?}, found {}", chunk.len(), bases.len()))?;
+            }
+
+            let coeffs = chunk.iter().map(|c| c.to_bigint()).collect::<Vec<_>>();
+            let msm_time = start_timer!(|| format!("MSM over the coefficients in {range:?}"));
+            commitment += VariableBase::msm(&bases, &coeffs);
+            end_timer!(msm_time);
+
+            offset = range.end;
+        }
+
+        end_timer!(commit_time);
+        Ok(KZGCommitment(commitment.into()))
+    }
+
+    /// Outputs a commitment to `polynomial`, reading the powers of beta G from `powers_of_beta_g`
+    /// one chunk of coefficients at a time with `commit_streaming`.
+    pub fn commit_lazy(
+        powers_of_beta_g: &LazyPowersOfBetaG<E>,
+        powers_of_beta_times_gamma_g: &[E::G1Affine],
+        polynomial: &Polynomial<'_, E::Fr>,
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
+        Self::check_degree_is_too_large(polynomial.degree(), powers_of_beta_g.num_powers())?;
+
+        let commit_time = start_timer!(|| format!(
+            "Committing lazily to polynomial of degree {} with hiding_bound: {:?}",
+            polynomial.degree(),
+            hiding_bound,
+        ));
+
+        let mut commitment = match polynomial {
+            Polynomial::Dense(polynomial) => Self::commit_streaming(
+                |range| powers_of_beta_g.read(range),
+                polynomial.coeffs.chunks(powers_of_beta_g.chunk_size()),
+            )?
+            .0
+            .to_projective(),
+            Polynomial::Sparse(polynomial) => {
+                let mut commitment = E::G1Projective::zero();
+                for (i, coeff) in polynomial.coeffs() {
+                    let power = powers_of_beta_g.read(*i..(*i + 1))?[0];
+                    commitment += power.mul_bits(BitIteratorBE::new_without_leading_zeros(coeff.to_bigint()));
+                }
+                commitment
+            }
+        };
+
+        let mut randomness = KZGRandomness::empty();
+        if let Some(hiding_degree) = hiding_bound {
+            let mut rng = rng.ok_or(PCError::MissingRng)?;
+            let sample_random_poly_time =
+                start_timer!(|| format!("Sampling a random polynomial of degree {hiding_degree}"));
+
+            randomness = KZGRandomness::rand(hiding_degree, false, &mut rng);
+            Self::check_hiding_bound(randomness.blinding_polynomial.degree(), powers_of_beta_times_gamma_g.len())?;
+            end_timer!(sample_random_poly_time);
+        }
+
+        let random_ints = convert_to_bigints(&randomness.blinding_polynomial.coeffs);
+        let msm_time = start_timer!(|| "MSM to compute commitment to random poly");
+        let random_commitment = VariableBase::msm(powers_of_beta_times_gamma_g, random_ints.as_slice()).to_affine();
+        end_timer!(msm_time);
+
+        commitment.add_assign_mixed(&random_commitment);
+
+        end_timer!(commit_time);
+        Ok((KZGCommitment(commitment.into()), randomness))
+    }
+
     /// Compute witness polynomial.
     ///
     /// The witness polynomial w(x) the quotient of the division (p(x) - p(z)) / (x - z)
@@ -519,6 +643,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_commit_streaming() {
+        let rng = &mut TestRng::default();
+        let max_degree = 64;
+        let pp = KZG_Bls12_377::load_srs(max_degree).unwrap();
+
+        for degree in [1, 7, 16, 33, max_degree] {
+            let (powers, _) = KZG_Bls12_377::trim(&pp, degree, None);
+            let polynomial = DensePolynomial::<Fr>::rand(degree, rng);
+            let (expected, _) = KZG_Bls12_377::commit(&powers, &(&polynomial).into(), None, None).unwrap();
+
+            for chunk_size in [1, 3, 8, degree + 1] {
+                // Record the range of every fetch of the powers.
+                let mut ranges = vec![];
+                let powers_of_beta_g = |range: Range<usize>| {
+                    ranges.push(range.clone());
+                    pp.powers_of_beta_g(range.start, range.end)
+                };
+                let candidate =
+                    KZG_Bls12_377::commit_streaming(powers_of_beta_g, polynomial.coeffs.chunks(chunk_size)).unwrap();
+                assert_eq!(expected, candidate);
+
+                // Ensure the powers are fetched once per chunk, in order.
+                let expected_ranges = (0..=degree)
+                    .step_by(chunk_size)
+                    .map(|start| start..(start + chunk_size).min(degree + 1))
+                    .collect::<Vec<_>>();
+                assert_eq!(ranges, expected_ranges);
+            }
+
+            // Ensure a source that returns too few powers is rejected.
+            let too_few_powers = |range: Range<usize>| pp.powers_of_beta_g(range.start, range.end - 1);
+            assert!(KZG_Bls12_377::commit_streaming(too_few_powers, polynomial.coeffs.chunks(4)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_commit_lazy() {
+        let rng = &mut TestRng::default();
+        let max_degree = 64;
+        let pp = KZG_Bls12_377::load_srs(max_degree).unwrap();
+
+        for degree in [1, 7, 16, 33, max_degree] {
+            let (powers, _) = KZG_Bls12_377::trim(&pp, degree, Some(1));
+            let source = std::io::Cursor::new(powers.powers_of_beta_g.to_vec().to_bytes_le().unwrap());
+            let lazy_powers = LazyPowersOfBetaG::<Bls12_377>::new(source, 0, powers.size(), 4).unwrap();
+
+            // Ensure the dense and sparse commitments match the ones with the powers in memory.
+            let dense = DensePolynomial::<Fr>::rand(degree, rng);
+            let sparse = crate::fft::SparsePolynomial::from_coefficients([(0, Fr::rand(rng)), (degree, Fr::rand(rng))]);
+            for polynomial in [Polynomial::from(&dense), Polynomial::from(&sparse)] {
+                let seed = rng.next_u64();
+                let expected = KZG_Bls12_377::commit(&powers, &polynomial, Some(1), Some(&mut TestRng::fixed(seed)));
+                let candidate = KZG_Bls12_377::commit_lazy(
+                    &lazy_powers,
+                    &powers.powers_of_beta_times_gamma_g,
+                    &polynomial,
+                    Some(1),
+                    Some(&mut TestRng::fixed(seed)),
+                );
+                assert_eq!(expected.unwrap(), candidate.unwrap());
+            }
+        }
+
+        // Ensure a source that holds too few powers is rejected.
+        let (powers, _) = KZG_Bls12_377::trim(&pp, max_degree, None);
+        let source = std::io::Cursor::new(powers.powers_of_beta_g[1..].to_vec().to_bytes_le().unwrap());
+        assert!(LazyPowersOfBetaG::<Bls12_377>::new(source, 0, powers.size(), 4).is_err());
+    }
+
     #[test]
     fn test_kzg10_universal_params_serialization() {
         let degree = 4;
@@ -13,17 +13,25 @@
 // limitations under the License.
 
 use super::{LabeledPolynomial, PolynomialInfo};
-use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
+use crate::{
+    crypto_hash::sha256::sha256,
+    fft::EvaluationDomain,
+    msm::MsmPrecomputeTable,
+    polycommit::{kzg10, PCError},
+};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
+use sha2::{Digest, Sha256};
 use std::{
     borrow::{Borrow, Cow},
     collections::{BTreeMap, BTreeSet},
     fmt,
+    io::{self, Seek},
     ops::{AddAssign, MulAssign, SubAssign},
+    sync::Arc,
 };
 
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
@@ -36,7 +44,7 @@ pub type Randomness<E> = kzg10::KZGRandomness<E>;
 pub type Commitment<E> = kzg10::KZGCommitment<E>;
 
 /// `CommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
-#[derive(Debug)]
+#[derive(Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct CommitterKey<E: PairingEngine> {
     /// The key used to commit to polynomials.
     pub powers_of_beta_g: Vec<E::G1Affine>,
@@ -59,6 +67,14 @@ pub struct CommitterKey<E: PairingEngine> {
     /// Sorted in ascending order from smallest bound to largest bound.
     /// This is `None` if `self` does not support enforcing any degree bounds.
     pub enforced_degree_bounds: Option<Vec<usize>>,
+
+    /// A table of `powers_of_beta_g` for repeated MSMs, which is used to commit to large polynomials.
+    /// This is `None` unless it is built with `precompute_powers_of_beta_g`, and it is not part of the byte encoding.
+    pub precomputed_powers_of_beta_g: Option<MsmPrecomputeTable<E::G1Affine>>,
+
+    /// The source of `powers_of_beta_g` for a key opened with `read_lazy`, in which case `powers_of_beta_g` is empty.
+    /// This is `None` if the powers are held in memory.
+    pub lazy_powers_of_beta_g: Option<Arc<kzg10::LazyPowersOfBetaG<E>>>,
 }
 
 impl<E: PairingEngine> FromBytes for CommitterKey<E> {
@@ -71,6 +87,152 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
             powers_of_beta_g.push(power);
         }
 
+        // Hash the group elements, starting with `powers`.
+        let mut hasher = Sha256::new();
+        hasher.update(powers_of_beta_g.to_bytes_le().map_err(|_| error("Could not serialize powers"))?);
+
+        Self::read_le_after_powers(reader, powers_of_beta_g, hasher)
+    }
+}
+
+impl<E: PairingEngine> ToBytes for CommitterKey<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        // Read the powers of a lazy key into memory.
+        let powers_of_beta_g = match &self.lazy_powers_of_beta_g {
+            Some(lazy_powers) => Cow::Owned(lazy_powers.read(0..lazy_powers.num_powers()).map_err(error)?),
+            None => Cow::Borrowed(&self.powers_of_beta_g),
+        };
+
+        // Serialize `powers`.
+        (powers_of_beta_g.len() as u32).write_le(&mut writer)?;
+        for power in powers_of_beta_g.iter() {
+            power.write_le(&mut writer)?;
+        }
+
+        // Serialize `powers`.
+        (self.lagrange_bases_at_beta_g.len() as u32).write_le(&mut writer)?;
+        for (size, powers) in &self.lagrange_bases_at_beta_g {
+            (*size as u32).write_le(&mut writer)?;
+            for power in powers {
+                power.write_le(&mut writer)?;
+            }
+        }
+
+        // Serialize `powers_of_beta_times_gamma_g`.
+        (self.powers_of_beta_times_gamma_g.len() as u32).write_le(&mut writer)?;
+        for power_of_gamma_g in &self.powers_of_beta_times_gamma_g {
+            power_of_gamma_g.write_le(&mut writer)?;
+        }
+
+        // Serialize `shifted_powers_of_beta_g`.
+        self.shifted_powers_of_beta_g.is_some().write_le(&mut writer)?;
+        if let Some(shifted_powers_of_beta_g) = &self.shifted_powers_of_beta_g {
+            (shifted_powers_of_beta_g.len() as u32).write_le(&mut writer)?;
+            for shifted_power in shifted_powers_of_beta_g {
+                shifted_power.write_le(&mut writer)?;
+            }
+        }
+
+        // Serialize `shifted_powers_of_beta_times_gamma_g`.
+        self.shifted_powers_of_beta_times_gamma_g.is_some().write_le(&mut writer)?;
+        if let Some(shifted_powers_of_beta_times_gamma_g) = &self.shifted_powers_of_beta_times_gamma_g {
+            (shifted_powers_of_beta_times_gamma_g.len() as u32).write_le(&mut writer)?;
+            for (key, shifted_powers_of_beta_g) in shifted_powers_of_beta_times_gamma_g {
+                (*key as u32).write_le(&mut writer)?;
+                (shifted_powers_of_beta_g.len() as u32).write_le(&mut writer)?;
+                for shifted_power in shifted_powers_of_beta_g {
+                    shifted_power.write_le(&mut writer)?;
+                }
+            }
+        }
+
+        // Serialize `enforced_degree_bounds`.
+        self.enforced_degree_bounds.is_some().write_le(&mut writer)?;
+        if let Some(enforced_degree_bounds) = &self.enforced_degree_bounds {
+            (enforced_degree_bounds.len() as u32).write_le(&mut writer)?;
+            for enforced_degree_bound in enforced_degree_bounds {
+                (*enforced_degree_bound as u32).write_le(&mut writer)?;
+            }
+        }
+
+        // Construct the hash of the group elements.
+        let mut hash_input = powers_of_beta_g.to_bytes_le().map_err(|_| error("Could not serialize powers"))?;
+        self.powers_of_beta_times_gamma_g
+            .write_le(&mut hash_input)
+            .map_err(|_| error("Could not serialize powers_of_beta_times_gamma_g"))?;
+
+        if let Some(shifted_powers_of_beta_g) = &self.shifted_powers_of_beta_g {
+            shifted_powers_of_beta_g
+                .write_le(&mut hash_input)
+                .map_err(|_| error("Could not serialize shifted_powers_of_beta_g"))?;
+        }
+
+        if let Some(shifted_powers_of_beta_times_gamma_g) = &self.shifted_powers_of_beta_times_gamma_g {
+            for value in shifted_powers_of_beta_times_gamma_g.values() {
+                value.write_le(&mut hash_input).map_err(|_| error("Could not serialize shifted_power_of_gamma_g"))?;
+            }
+        }
+
+        // Serialize `hash`
+        let hash = sha256(&hash_input);
+        hash.write_le(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> CommitterKey<E> {
+    /// The minimum number of coefficients in a polynomial for `commit` to use the precomputed table.
+    pub const MIN_PRECOMPUTE_SIZE: usize = 1 << 12;
+
+    fn len(&self) -> usize {
+        if self.shifted_powers_of_beta_g.is_some() { self.shifted_powers_of_beta_g.as_ref().unwrap().len() } else { 0 }
+    }
+
+    /// Opens a committer key in lazy mode from its byte encoding in `source`, such as a file,
+    /// or a memory-mapped file wrapped in a `std::io::Cursor`.
+    ///
+    /// The powers of beta G are checked and hashed `chunk_size` powers at a time, but are not held in memory.
+    /// Instead, `SonicKZG10::commit` reads them from `source` one chunk of coefficients at a time.
+    /// A lazy key only commits to polynomials without a degree bound; opening evaluation proofs,
+    /// `trim_further`, and `precompute_powers_of_beta_g` require a key that is read with `read_le`.
+    pub fn read_lazy<S: Read + Seek + Send + 'static>(mut source: S, chunk_size: usize) -> io::Result<Self> {
+        if chunk_size == 0 {
+            return Err(error("The chunk size of a lazy committer key must be positive"));
+        }
+
+        // Deserialize the number of `powers`, and record the position of the first power.
+        let powers_len: u32 = FromBytes::read_le(&mut source)?;
+        let offset = source.stream_position()?;
+
+        // Check and hash `powers` in chunks.
+        let power_size = kzg10::LazyPowersOfBetaG::<E>::power_size().map_err(error)? as usize;
+        let mut hasher = Sha256::new();
+        let mut buffer = vec![0u8; chunk_size * power_size];
+        let mut num_remaining = powers_len as usize;
+        while num_remaining > 0 {
+            let num_powers = num_remaining.min(chunk_size);
+            let bytes = &mut buffer[..num_powers * power_size];
+            source.read_exact(bytes)?;
+            for power in bytes.chunks_exact(power_size) {
+                let _: E::G1Affine = FromBytes::read_le(power)?;
+            }
+            hasher.update(&*bytes);
+            num_remaining -= num_powers;
+        }
+
+        let mut committer_key = Self::read_le_after_powers(&mut source, Vec::new(), hasher)?;
+        let lazy_powers_of_beta_g =
+            kzg10::LazyPowersOfBetaG::new(source, offset, powers_len as usize, chunk_size).map_err(error)?;
+        committer_key.lazy_powers_of_beta_g = Some(Arc::new(lazy_powers_of_beta_g));
+        Ok(committer_key)
+    }
+
+    /// Deserializes the fields after `powers_of_beta_g`, given a `hasher` that has absorbed `powers_of_beta_g`,
+    /// and enforces that the group elements construct the expected hash.
+    fn read_le_after_powers<R: Read>(
+        mut reader: R,
+        powers_of_beta_g: Vec<E::G1Affine>,
+        mut hasher: Sha256,
+    ) -> io::Result<Self> {
         // Deserialize `lagrange_basis_at_beta`.
         let lagrange_bases_at_beta_len: u32 = FromBytes::read_le(&mut reader)?;
         let mut lagrange_bases_at_beta_g = BTreeMap::new();
@@ -149,9 +311,8 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
         };
 
         // Construct the hash of the group elements.
-        let mut hash_input = powers_of_beta_g.to_bytes_le().map_err(|_| error("Could not serialize powers"))?;
-        powers_of_beta_times_gamma_g
-            .write_le(&mut hash_input)
+        let mut hash_input = powers_of_beta_times_gamma_g
+            .to_bytes_le()
             .map_err(|_| error("Could not serialize powers_of_beta_times_gamma_g"))?;
 
         if let Some(shifted_powers_of_beta_g) = &shifted_powers_of_beta_g {
@@ -165,9 +326,10 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
                 value.write_le(&mut hash_input).map_err(|_| error("Could not serialize shifted_power_of_gamma_g"))?;
             }
         }
+        hasher.update(&hash_input);
 
         // Deserialize `hash`.
-        let hash = sha256(&hash_input);
+        let hash: [u8; 32] = hasher.finalize().into();
         let expected_hash: [u8; 32] = FromBytes::read_le(&mut reader)?;
 
         // Enforce the group elements construct the expected hash.
@@ -182,91 +344,108 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
             shifted_powers_of_beta_g,
             shifted_powers_of_beta_times_gamma_g,
             enforced_degree_bounds,
+            precomputed_powers_of_beta_g: None,
+            lazy_powers_of_beta_g: None,
         })
     }
-}
 
-impl<E: PairingEngine> ToBytes for CommitterKey<E> {
-    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
-        // Serialize `powers`.
-        (self.powers_of_beta_g.len() as u32).write_le(&mut writer)?;
-        for power in &self.powers_of_beta_g {
-            power.write_le(&mut writer)?;
+    /// Builds a table of `powers_of_beta_g` with windows of `window_bits` bits,
+    /// which `commit` uses for polynomials with at least `MIN_PRECOMPUTE_SIZE` coefficients.
+    pub fn precompute_powers_of_beta_g(&mut self, window_bits: usize) {
+        self.precomputed_powers_of_beta_g = Some(MsmPrecomputeTable::new(&self.powers_of_beta_g, window_bits));
+    }
+
+    /// Specializes `self` to a smaller `supported_degree`, set of Lagrange basis sizes, `supported_hiding_bound`,
+    /// and set of `enforced_degree_bounds`, without reloading the universal parameters.
+    ///
+    /// Each of the requested parameters must be supported by `self`. As the verifier key is universal,
+    /// proofs created with the resulting key are checked with the same `UniversalVerifier` as for `self`.
+    /// The precomputed table is not carried over, as it is built for the larger degree.
+    pub fn trim_further(
+        &self,
+        supported_degree: usize,
+        supported_lagrange_sizes: impl IntoIterator<Item = usize>,
+        supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
+    ) -> anyhow::Result<Self> {
+        // Ensure the requested degree and hiding bound are supported.
+        if supported_degree + 1 > self.powers_of_beta_g.len() {
+            return Err(PCError::TrimmingDegreeTooLarge.into());
         }
-
-        // Serialize `powers`.
-        (self.lagrange_bases_at_beta_g.len() as u32).write_le(&mut writer)?;
-        for (size, powers) in &self.lagrange_bases_at_beta_g {
-            (*size as u32).write_le(&mut writer)?;
-            for power in powers {
-                power.write_le(&mut writer)?;
+        let num_gamma_powers = self.powers_of_beta_times_gamma_g.len();
+        if supported_hiding_bound + 2 > num_gamma_powers {
+            return Err(PCError::HidingBoundToolarge {
+                hiding_poly_degree: supported_hiding_bound,
+                num_powers: num_gamma_powers,
             }
+            .into());
         }
 
-        // Serialize `powers_of_beta_times_gamma_g`.
-        (self.powers_of_beta_times_gamma_g.len() as u32).write_le(&mut writer)?;
-        for power_of_gamma_g in &self.powers_of_beta_times_gamma_g {
-            power_of_gamma_g.write_le(&mut writer)?;
-        }
+        let powers_of_beta_g = self.powers_of_beta_g[..=supported_degree].to_vec();
+        let powers_of_beta_times_gamma_g = self.powers_of_beta_times_gamma_g[..supported_hiding_bound + 2].to_vec();
 
-        // Serialize `shifted_powers_of_beta_g`.
-        self.shifted_powers_of_beta_g.is_some().write_le(&mut writer)?;
-        if let Some(shifted_powers_of_beta_g) = &self.shifted_powers_of_beta_g {
-            (shifted_powers_of_beta_g.len() as u32).write_le(&mut writer)?;
-            for shifted_power in shifted_powers_of_beta_g {
-                shifted_power.write_le(&mut writer)?;
-            }
+        let mut lagrange_bases_at_beta_g = BTreeMap::new();
+        for size in supported_lagrange_sizes {
+            let basis = self.lagrange_bases_at_beta_g.get(&size).ok_or(PCError::UnsupportedLagrangeBasisSize(size))?;
+            lagrange_bases_at_beta_g.insert(size, basis.clone());
         }
 
-        // Serialize `shifted_powers_of_beta_times_gamma_g`.
-        self.shifted_powers_of_beta_times_gamma_g.is_some().write_le(&mut writer)?;
-        if let Some(shifted_powers_of_beta_times_gamma_g) = &self.shifted_powers_of_beta_times_gamma_g {
-            (shifted_powers_of_beta_times_gamma_g.len() as u32).write_le(&mut writer)?;
-            for (key, shifted_powers_of_beta_g) in shifted_powers_of_beta_times_gamma_g {
-                (*key as u32).write_le(&mut writer)?;
-                (shifted_powers_of_beta_g.len() as u32).write_le(&mut writer)?;
-                for shifted_power in shifted_powers_of_beta_g {
-                    shifted_power.write_le(&mut writer)?;
+        let enforced_degree_bounds = enforced_degree_bounds.map(|bounds| {
+            let mut v = bounds.to_vec();
+            v.sort_unstable();
+            v.dedup();
+            v
+        });
+
+        let (shifted_powers_of_beta_g, shifted_powers_of_beta_times_gamma_g) = match enforced_degree_bounds.as_ref() {
+            Some(enforced_degree_bounds) if !enforced_degree_bounds.is_empty() => {
+                let (Some(current_powers), Some(current_gamma_powers), Some(current_bounds)) = (
+                    &self.shifted_powers_of_beta_g,
+                    &self.shifted_powers_of_beta_times_gamma_g,
+                    &self.enforced_degree_bounds,
+                ) else {
+                    return Err(PCError::UnsupportedDegreeBound(enforced_degree_bounds[0]).into());
+                };
+                for degree_bound in enforced_degree_bounds {
+                    if !current_bounds.contains(degree_bound) {
+                        return Err(PCError::UnsupportedDegreeBound(*degree_bound).into());
+                    }
+                }
+                let highest_enforced_degree_bound = *enforced_degree_bounds.last().unwrap();
+                if highest_enforced_degree_bound > supported_degree {
+                    anyhow::bail!(
+                        "The highest enforced degree bound {highest_enforced_degree_bound} is larger than the supported degree {supported_degree}"
+                    );
                 }
-            }
-        }
-
-        // Serialize `enforced_degree_bounds`.
-        self.enforced_degree_bounds.is_some().write_le(&mut writer)?;
-        if let Some(enforced_degree_bounds) = &self.enforced_degree_bounds {
-            (enforced_degree_bounds.len() as u32).write_le(&mut writer)?;
-            for enforced_degree_bound in enforced_degree_bounds {
-                (*enforced_degree_bound as u32).write_le(&mut writer)?;
-            }
-        }
-
-        // Construct the hash of the group elements.
-        let mut hash_input = self.powers_of_beta_g.to_bytes_le().map_err(|_| error("Could not serialize powers"))?;
-        self.powers_of_beta_times_gamma_g
-            .write_le(&mut hash_input)
-            .map_err(|_| error("Could not serialize powers_of_beta_times_gamma_g"))?;
-
-        if let Some(shifted_powers_of_beta_g) = &self.shifted_powers_of_beta_g {
-            shifted_powers_of_beta_g
-                .write_le(&mut hash_input)
-                .map_err(|_| error("Could not serialize shifted_powers_of_beta_g"))?;
-        }
 
-        if let Some(shifted_powers_of_beta_times_gamma_g) = &self.shifted_powers_of_beta_times_gamma_g {
-            for value in shifted_powers_of_beta_times_gamma_g.values() {
-                value.write_le(&mut hash_input).map_err(|_| error("Could not serialize shifted_power_of_gamma_g"))?;
+                // The shifted powers end at the maximum degree, so the powers for a lower bound are a suffix.
+                let shifted_powers_of_beta_g =
+                    current_powers[current_powers.len() - (highest_enforced_degree_bound + 1)..].to_vec();
+                // The shifted hiding powers for each bound are a prefix, sized as in `SonicKZG10::trim`.
+                let shifted_powers_of_beta_times_gamma_g = enforced_degree_bounds
+                    .iter()
+                    .map(|degree_bound| {
+                        let powers = &current_gamma_powers[degree_bound];
+                        let num_powers = powers.len().min((*degree_bound).min(supported_hiding_bound) + 2);
+                        (*degree_bound, powers[..num_powers].to_vec())
+                    })
+                    .collect();
+
+                (Some(shifted_powers_of_beta_g), Some(shifted_powers_of_beta_times_gamma_g))
             }
-        }
-
-        // Serialize `hash`
-        let hash = sha256(&hash_input);
-        hash.write_le(&mut writer)
-    }
-}
+            _ => (None, None),
+        };
 
-impl<E: PairingEngine> CommitterKey<E> {
-    fn len(&self) -> usize {
-        if self.shifted_powers_of_beta_g.is_some() { self.shifted_powers_of_beta_g.as_ref().unwrap().len() } else { 0 }
+        Ok(Self {
+            powers_of_beta_g,
+            lagrange_bases_at_beta_g,
+            powers_of_beta_times_gamma_g,
+            shifted_powers_of_beta_g,
+            shifted_powers_of_beta_times_gamma_g,
+            enforced_degree_bounds,
+            precomputed_powers_of_beta_g: None,
+            lazy_powers_of_beta_g: None,
+        })
     }
 }
 
@@ -294,6 +473,14 @@ pub struct CommitterUnionKey<'a, E: PairingEngine> {
     /// Sorted in ascending order from smallest bound to largest bound.
     /// This is `None` if `self` does not support enforcing any degree bounds.
     pub enforced_degree_bounds: Option<Vec<usize>>,
+
+    /// A table of `powers_of_beta_g` for repeated MSMs, which is used to commit to large polynomials.
+    /// This is `None` if the key with the most powers has no precomputed table.
+    pub precomputed_powers_of_beta_g: Option<&'a MsmPrecomputeTable<E::G1Affine>>,
+
+    /// The source of `powers_of_beta_g`, which is used to commit to polynomials one chunk at a time.
+    /// This is `None` unless the key with the most powers was opened with `CommitterKey::read_lazy`.
+    pub lazy_powers_of_beta_g: Option<&'a kzg10::LazyPowersOfBetaG<E>>,
 }
 
 impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
@@ -347,6 +534,8 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
             shifted_powers_of_beta_g: None,
             shifted_powers_of_beta_times_gamma_g: None,
             enforced_degree_bounds: None,
+            precomputed_powers_of_beta_g: None,
+            lazy_powers_of_beta_g: None,
         };
         let mut enforced_degree_bounds = vec![];
         let mut biggest_ck: Option<&CommitterKey<E>> = None;
@@ -373,6 +562,8 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
         ck_union.powers_of_beta_g = Some(&biggest_ck.powers_of_beta_g);
         ck_union.powers_of_beta_times_gamma_g = Some(&biggest_ck.powers_of_beta_times_gamma_g);
         ck_union.shifted_powers_of_beta_g = biggest_ck.shifted_powers_of_beta_g.as_ref();
+        ck_union.precomputed_powers_of_beta_g = biggest_ck.precomputed_powers_of_beta_g.as_ref();
+        ck_union.lazy_powers_of_beta_g = biggest_ck.lazy_powers_of_beta_g.as_deref();
 
         if !enforced_degree_bounds.is_empty() {
             enforced_degree_bounds.sort();
@@ -152,6 +152,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             shifted_powers_of_beta_g,
             shifted_powers_of_beta_times_gamma_g,
             enforced_degree_bounds,
+            precomputed_powers_of_beta_g: None,
+            lazy_powers_of_beta_g: None,
         };
 
         let vk = pp.to_universal_verifier()?;
@@ -233,7 +235,29 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                                 ck.powers()
                             };
 
-                            kzg10::KZG10::commit(&powers, &polynomial, hiding_bound, rng_ref)?
+                            // The lazy powers and the precomputed table only cover the unshifted powers.
+                            match (ck.lazy_powers_of_beta_g, ck.precomputed_powers_of_beta_g) {
+                                (Some(lazy_powers), _) if degree_bound.is_none() => kzg10::KZG10::commit_lazy(
+                                    lazy_powers,
+                                    &powers.powers_of_beta_times_gamma_g,
+                                    &polynomial,
+                                    hiding_bound,
+                                    rng_ref,
+                                )?,
+                                (_, Some(table))
+                                    if degree_bound.is_none()
+                                        && polynomial.degree() + 1 >= CommitterKey::<E>::MIN_PRECOMPUTE_SIZE =>
+                                {
+                                    kzg10::KZG10::commit_with_precompute(
+                                        &powers,
+                                        table,
+                                        &polynomial,
+                                        hiding_bound,
+                                        rng_ref,
+                                    )?
+                                }
+                                _ => kzg10::KZG10::commit(&powers, &polynomial, hiding_bound, rng_ref)?,
+                            }
                         }
                     }
                 };
@@ -643,15 +667,15 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
         let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
 
+        // Note: The prepared G2 elements are borrowed from the verifier key, as they are costly to clone.
         for (degree_bound, comm) in combined_comms.into_iter() {
             let shift_power = if let Some(degree_bound) = degree_bound {
                 // Find the appropriate prepared shift for the degree bound.
                 vk.prepared_negative_powers_of_beta_h
                     .get(&degree_bound)
-                    .cloned()
                     .ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
             } else {
-                vk.vk.prepared_h.clone()
+                &vk.vk.prepared_h
             };
 
             g1_projective_elems.push(comm);
@@ -659,10 +683,10 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         }
 
         g1_projective_elems.push(-combined_adjusted_witness);
-        g2_prepared_elems.push(vk.vk.prepared_h.clone());
+        g2_prepared_elems.push(&vk.vk.prepared_h);
 
         g1_projective_elems.push(-combined_witness);
-        g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+        g2_prepared_elems.push(&vk.vk.prepared_beta_h);
 
         let g1_prepared_elems_iter = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
             .into_iter()
@@ -670,7 +694,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
 
         ensure!(g1_prepared_elems_iter.len() == g2_prepared_elems.len());
-        let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems.iter());
+        let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems);
         let is_one: bool = E::product_of_pairings(g1_g2_prepared).is_one();
         end_timer!(check_time);
         Ok(is_one)
@@ -681,12 +705,28 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 mod tests {
     #![allow(non_camel_case_types)]
 
-    use super::{CommitterKey, SonicKZG10};
-    use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::*};
-    use snarkvm_curves::bls12_377::{Bls12_377, Fq};
-    use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
-
-    use rand::distributions::Distribution;
+    use super::{
+        CommitterKey,
+        CommitterUnionKey,
+        Evaluations,
+        LabeledPolynomial,
+        LabeledPolynomialWithBasis,
+        QuerySet,
+        SonicKZG10,
+    };
+    use crate::{crypto_hash::PoseidonSponge, fft::DensePolynomial, polycommit::test_templates::*, AlgebraicSponge};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::{
+        rand::{TestRng, Uniform},
+        CanonicalDeserialize,
+        CanonicalSerialize,
+        FromBytes,
+        ToBytes,
+    };
+
+    use rand::{distributions::Distribution, RngCore};
+    use std::io::Cursor;
 
     type Sponge = PoseidonSponge<Fq, 2, 1>;
     type PC_Bls12_377 = SonicKZG10<Bls12_377, Sponge>;
@@ -710,6 +750,275 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_committer_key_canonical_serialization() {
+        let max_degree = 64;
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+
+        let lagrange_size = |d: usize| if d.is_power_of_two() { d } else { d.next_power_of_two() >> 1 };
+
+        for supported_degree in [1, 7, 16, 33, max_degree] {
+            let lagrange_sizes = [lagrange_size(supported_degree)];
+            let degree_bounds = [supported_degree];
+            let (ck, _vk) = PC_Bls12_377::trim(&pp, supported_degree, lagrange_sizes, 1, Some(&degree_bounds)).unwrap();
+
+            // Check the compressed encoding.
+            let mut compressed = Vec::new();
+            ck.serialize_compressed(&mut compressed).unwrap();
+            assert_eq!(compressed.len(), ck.compressed_size());
+            let recovered = CommitterKey::<Bls12_377>::deserialize_compressed(&compressed[..]).unwrap();
+            assert_eq!(ck.to_bytes_le().unwrap(), recovered.to_bytes_le().unwrap());
+
+            // Check the uncompressed encoding.
+            let mut uncompressed = Vec::new();
+            ck.serialize_uncompressed(&mut uncompressed).unwrap();
+            assert_eq!(uncompressed.len(), ck.uncompressed_size());
+            assert!(compressed.len() < uncompressed.len());
+            let recovered = CommitterKey::<Bls12_377>::deserialize_uncompressed(&uncompressed[..]).unwrap();
+            assert_eq!(ck.to_bytes_le().unwrap(), recovered.to_bytes_le().unwrap());
+
+            // Ensure truncated bytes are rejected.
+            assert!(CommitterKey::<Bls12_377>::deserialize_compressed(&compressed[..compressed.len() - 1]).is_err());
+            // Ensure a corrupted group element is rejected. The first 8 bytes are the length of `powers_of_beta_g`.
+            let mut corrupted = compressed.clone();
+            corrupted[8] ^= 0xff;
+            assert!(CommitterKey::<Bls12_377>::deserialize_compressed(&corrupted[..]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_commit_with_precompute() {
+        let rng = &mut TestRng::default();
+        let max_degree = CommitterKey::<Bls12_377>::MIN_PRECOMPUTE_SIZE;
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+
+        let (mut ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 1, None).unwrap();
+
+        // Sample polynomials below, at, and above the threshold, including one with leading zeros.
+        let mut polynomials = [1, max_degree - 1, max_degree, max_degree]
+            .into_iter()
+            .map(|degree| DensePolynomial::<Fr>::rand(degree, rng))
+            .collect::<Vec<_>>();
+        polynomials[3].coeffs[..3].fill(Fr::zero());
+        let polynomials = polynomials
+            .into_iter()
+            .enumerate()
+            .map(|(i, polynomial)| LabeledPolynomial::new(format!("Test{i}"), polynomial, None, Some(1)))
+            .collect::<Vec<_>>();
+
+        let seed = rng.next_u64();
+        let commit = |ck: &CommitterKey<Bls12_377>| {
+            let ck = CommitterUnionKey::union(std::iter::once(ck));
+            let rng = &mut TestRng::fixed(seed);
+            PC_Bls12_377::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap()
+        };
+        let (expected, _) = commit(&ck);
+
+        for window_bits in [4, 8] {
+            ck.precompute_powers_of_beta_g(window_bits);
+            let table = ck.precomputed_powers_of_beta_g.as_ref().unwrap();
+            assert_eq!(table.num_bases(), ck.powers_of_beta_g.len());
+
+            // Ensure the commitments match the ones without the table.
+            let (candidate, _) = commit(&ck);
+            assert_eq!(expected, candidate);
+
+            // Ensure the table survives the canonical encoding, but not the byte encoding.
+            let mut compressed = Vec::new();
+            ck.serialize_compressed(&mut compressed).unwrap();
+            let recovered = CommitterKey::<Bls12_377>::deserialize_compressed(&compressed[..]).unwrap();
+            assert_eq!(recovered.precomputed_powers_of_beta_g.unwrap().window_bits(), window_bits);
+            let recovered: CommitterKey<Bls12_377> = FromBytes::read_le(&ck.to_bytes_le().unwrap()[..]).unwrap();
+            assert!(recovered.precomputed_powers_of_beta_g.is_none());
+        }
+    }
+
+    #[test]
+    fn test_commit_with_lazy_committer_key() {
+        let rng = &mut TestRng::default();
+        let max_degree = 64;
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 1, None).unwrap();
+        let ck_bytes = ck.to_bytes_le().unwrap();
+
+        // Sample polynomials of several degrees, including one with leading zeros.
+        let mut polynomials = [1, 7, 16, 33, max_degree]
+            .into_iter()
+            .map(|degree| DensePolynomial::<Fr>::rand(degree, rng))
+            .collect::<Vec<_>>();
+        polynomials[4].coeffs[..3].fill(Fr::zero());
+        let polynomials = polynomials
+            .into_iter()
+            .enumerate()
+            .map(|(i, polynomial)| LabeledPolynomial::new(format!("Test{i}"), polynomial, None, Some(1)))
+            .collect::<Vec<_>>();
+
+        let seed = rng.next_u64();
+        let commit = |ck: &CommitterKey<Bls12_377>| {
+            let ck = CommitterUnionKey::union(std::iter::once(ck));
+            let rng = &mut TestRng::fixed(seed);
+            PC_Bls12_377::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap()
+        };
+        let (expected_comms, expected_rands) = commit(&ck);
+
+        for chunk_size in [1, 3, 8, max_degree + 1] {
+            let lazy_ck = CommitterKey::<Bls12_377>::read_lazy(Cursor::new(ck_bytes.clone()), chunk_size).unwrap();
+            assert!(lazy_ck.powers_of_beta_g.is_empty());
+            assert_eq!(lazy_ck.lazy_powers_of_beta_g.as_ref().unwrap().num_powers(), ck.powers_of_beta_g.len());
+
+            // Ensure the commitments match the ones with the powers in memory.
+            let (comms, rands) = commit(&lazy_ck);
+            assert_eq!(expected_comms, comms);
+            assert_eq!(expected_rands, rands);
+
+            // Ensure the lazy key has the same byte encoding.
+            assert_eq!(ck_bytes, lazy_ck.to_bytes_le().unwrap());
+        }
+
+        // Ensure a corrupted power is rejected. The first 4 bytes are the number of powers.
+        let mut corrupted = ck_bytes.clone();
+        corrupted[4] ^= 1;
+        assert!(CommitterKey::<Bls12_377>::read_lazy(Cursor::new(corrupted), 8).is_err());
+        // Ensure truncated bytes and an empty chunk size are rejected.
+        assert!(CommitterKey::<Bls12_377>::read_lazy(Cursor::new(ck_bytes[..ck_bytes.len() - 1].to_vec()), 8).is_err());
+        assert!(CommitterKey::<Bls12_377>::read_lazy(Cursor::new(ck_bytes), 0).is_err());
+    }
+
+    #[test]
+    fn test_committer_key_trim_further() {
+        let rng = &mut TestRng::default();
+        let max_degree = 64;
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+
+        let (ck, vk) = PC_Bls12_377::trim(&pp, 64, [32, 64], 2, Some(&[7, 15, 31, 63][..])).unwrap();
+
+        // Trim the committer key twice.
+        let ck = ck.trim_further(32, [32], 1, Some(&[7, 15, 31][..])).unwrap();
+        let ck = ck.trim_further(16, None, 1, Some(&[7, 15][..])).unwrap();
+        assert_eq!(ck.powers_of_beta_g.len(), 17);
+        assert_eq!(ck.powers_of_beta_times_gamma_g.len(), 3);
+        assert!(ck.lagrange_bases_at_beta_g.is_empty());
+        assert_eq!(ck.enforced_degree_bounds, Some(vec![7, 15]));
+
+        // Commit to, open, and check polynomials with the twice-trimmed committer key.
+        let polynomials = [Some(7), Some(15), None]
+            .into_iter()
+            .enumerate()
+            .map(|(i, degree_bound)| {
+                let polynomial = DensePolynomial::rand(degree_bound.unwrap_or(16), rng);
+                LabeledPolynomial::new(format!("Test{i}"), polynomial, degree_bound, Some(1))
+            })
+            .collect::<Vec<_>>();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+        let (comms, rands) =
+            PC_Bls12_377::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+
+        let point = Fr::rand(rng);
+        let mut query_set = QuerySet::new();
+        let mut values = Evaluations::new();
+        for polynomial in &polynomials {
+            query_set.insert((polynomial.label().to_string(), ("rand".into(), point)));
+            values.insert((polynomial.label().to_string(), point), polynomial.evaluate(point));
+        }
+
+        let proof = PC_Bls12_377::batch_open(
+            universal_prover,
+            &ck,
+            polynomials.iter(),
+            &query_set,
+            rands.iter(),
+            &mut Sponge::new(),
+        )
+        .unwrap();
+        assert!(PC_Bls12_377::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+    }
+
+    #[test]
+    fn test_committer_key_trim_further_rejects_larger_parameters() {
+        let max_degree = 64;
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, 32, [16], 1, Some(&[15][..])).unwrap();
+
+        // Ensure the same parameters are supported.
+        assert!(ck.trim_further(32, [16], 1, Some(&[15][..])).is_ok());
+        // Ensure a larger degree, Lagrange basis, hiding bound, or new degree bound are rejected.
+        assert!(ck.trim_further(33, None, 1, Some(&[15][..])).is_err());
+        assert!(ck.trim_further(32, [32], 1, Some(&[15][..])).is_err());
+        assert!(ck.trim_further(32, None, 2, Some(&[15][..])).is_err());
+        assert!(ck.trim_further(32, None, 1, Some(&[7][..])).is_err());
+    }
+
+    #[test]
+    fn test_commit_matches_individual_commits() {
+        let rng = &mut TestRng::default();
+        let max_degree = 64;
+        let supported_degree = 32;
+        let num_polynomials = 16;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, supported_degree, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let polynomials = (0..num_polynomials)
+            .map(|i| {
+                let polynomial = DensePolynomial::rand(supported_degree, rng);
+                LabeledPolynomial::new(format!("Test{i}"), polynomial, None, Some(1))
+            })
+            .collect::<Vec<_>>();
+
+        // Commit to all of the polynomials at once.
+        let seed = rng.next_u64();
+        let batch_rng = &mut TestRng::from_seed(seed);
+        let (batch_comms, batch_rands) =
+            PC_Bls12_377::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(batch_rng)).unwrap();
+        assert_eq!(batch_comms.len(), num_polynomials);
+        assert_eq!(batch_rands.len(), num_polynomials);
+
+        // Commit to each polynomial individually, using the same RNG, and ensure the results match in order.
+        let individual_rng = &mut TestRng::from_seed(seed);
+        for (i, polynomial) in polynomials.iter().enumerate() {
+            let polynomial = LabeledPolynomialWithBasis::from(polynomial);
+            let (comms, rands) =
+                PC_Bls12_377::commit(universal_prover, &ck, std::iter::once(polynomial), Some(individual_rng)).unwrap();
+            assert_eq!(comms[0], batch_comms[i]);
+            assert_eq!(rands[0], batch_rands[i]);
+        }
+    }
+
+    #[test]
+    fn test_commit_sequential_matches_parallel() {
+        let rng = &mut TestRng::default();
+        let supported_degree = 32;
+
+        let pp = PC_Bls12_377::load_srs(64).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, supported_degree, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let polynomials = (0..16)
+            .map(|i| {
+                let polynomial = DensePolynomial::rand(supported_degree, rng);
+                LabeledPolynomial::new(format!("Test{i}"), polynomial, None, Some(1))
+            })
+            .collect::<Vec<_>>();
+
+        // Commit to the polynomials with the same RNG, once in parallel and once on a single thread.
+        let seed = rng.next_u64();
+        let commit = |rng: &mut TestRng| {
+            PC_Bls12_377::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap()
+        };
+        let (expected_comms, expected_rands) = commit(&mut TestRng::from_seed(seed));
+        let sequential = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+        let (comms, rands) = sequential.install(|| commit(&mut TestRng::from_seed(seed)));
+        assert_eq!(expected_comms, comms);
+        assert_eq!(expected_rands, rands);
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks the peak memory of committing with a lazy committer key. The counting allocator is global,
+//! so this test lives in its own binary, where no other test allocates concurrently.
+
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    fft::DensePolynomial,
+    polycommit::sonic_pc::{CommitterKey, CommitterUnionKey, LabeledPolynomial, SonicKZG10},
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+use snarkvm_utilities::{rand::TestRng, ToBytes};
+
+use rand::RngCore;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    io::Cursor,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+type PC = SonicKZG10<Bls12_377, PoseidonSponge<Fq, 2, 1>>;
+
+/// The degree of the committed polynomial.
+const DEGREE: usize = (1 << 14) - 1;
+/// The number of powers that the lazy committer key reads for each chunk of coefficients.
+const CHUNK_SIZE: usize = 1 << 10;
+/// The maximum number of bytes that may be allocated at once while committing with the lazy committer key.
+const PEAK_ALLOCATION_BOUND: usize = 1 << 20;
+
+/// The number of bytes that are currently allocated.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+/// The maximum number of bytes that were allocated at once since the last call to `peak_allocation`.
+static PEAK_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// An allocator that counts the allocated bytes, and otherwise defers to the system allocator.
+struct CountingAllocator;
+
+impl CountingAllocator {
+    /// Records an allocation of `size` bytes, and updates the peak.
+    fn record_allocation(size: usize) {
+        let allocated = ALLOCATED.fetch_add(size, Ordering::SeqCst) + size;
+        PEAK_ALLOCATED.fetch_max(allocated, Ordering::SeqCst);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // Note: The new allocation is recorded first, as a reallocation may copy into a new buffer.
+            Self::record_allocation(new_size);
+            ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the output of `f`, and the peak number of bytes that `f` allocates on top of the current allocations.
+fn peak_allocation<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let baseline = ALLOCATED.load(Ordering::SeqCst);
+    PEAK_ALLOCATED.store(baseline, Ordering::SeqCst);
+    let output = f();
+    (output, PEAK_ALLOCATED.load(Ordering::SeqCst) - baseline)
+}
+
+#[test]
+fn test_lazy_commit_peak_allocation() {
+    let rng = &mut TestRng::default();
+
+    let pp = PC::load_srs(DEGREE).unwrap();
+    let universal_prover = &pp.to_universal_prover().unwrap();
+    let (ck, _vk) = PC::trim(&pp, DEGREE, None, 1, None).unwrap();
+    let lazy_ck = CommitterKey::<Bls12_377>::read_lazy(Cursor::new(ck.to_bytes_le().unwrap()), CHUNK_SIZE).unwrap();
+
+    let polynomial = DensePolynomial::<Fr>::rand(DEGREE, rng);
+    let polynomial = LabeledPolynomial::new("Test".to_string(), polynomial, None, Some(1));
+
+    // Commit on a single thread, so that the peak allocation does not depend on the number of threads.
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+    let seed = rng.next_u64();
+    let commit = |ck: &CommitterKey<Bls12_377>| {
+        let ck = CommitterUnionKey::union(std::iter::once(ck));
+        let rng = &mut TestRng::fixed(seed);
+        pool.install(|| {
+            peak_allocation(|| {
+                PC::commit(universal_prover, &ck, std::iter::once((&polynomial).into()), Some(rng)).unwrap()
+            })
+        })
+    };
+
+    let (expected, in_memory_peak) = commit(&ck);
+    let (candidate, lazy_peak) = commit(&lazy_ck);
+    assert_eq!(expected, candidate);
+
+    // Ensure the lazy commitment stays within the bound, which the commitment with the powers in memory exceeds.
+    assert!(lazy_peak <= PEAK_ALLOCATION_BOUND, "Committing lazily allocated {lazy_peak} bytes at once");
+    assert!(in_memory_peak > PEAK_ALLOCATION_BOUND, "Committing in memory allocated {in_memory_peak} bytes at once");
+}
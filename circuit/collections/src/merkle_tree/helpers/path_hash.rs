@@ -15,15 +15,27 @@
 use super::*;
 use snarkvm_circuit_algorithms::{Hash, Poseidon, BHP};
 
+#[cfg(console)]
+use console::merkle_tree::PathHash as ConsolePathHash;
+
 /// A trait for a Merkle path hash function.
 pub trait PathHash<E: Environment> {
     type Hash: FieldTrait;
 
+    /// The padding rule for the Merkle tree.
+    /// Note: This must be the `PADDING_MODE` of the corresponding console `PathHash`.
+    #[cfg(console)]
+    const PADDING_MODE: console::merkle_tree::PaddingMode;
+
     /// Returns the hash of the given child nodes.
     fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Self::Hash;
 
-    /// Returns the empty hash.
+    /// Returns the empty hash, as defined by the padding mode.
     fn hash_empty(&self) -> Self::Hash {
+        #[cfg(console)]
+        if let console::merkle_tree::PaddingMode::Zero = Self::PADDING_MODE {
+            return Self::Hash::zero();
+        }
         self.hash_children(&Self::Hash::zero(), &Self::Hash::zero())
     }
 }
@@ -31,6 +43,10 @@ pub trait PathHash<E: Environment> {
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> PathHash<E> for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
     type Hash = Field<E>;
 
+    #[cfg(console)]
+    const PADDING_MODE: console::merkle_tree::PaddingMode =
+        <console::algorithms::BHP<E::Network, NUM_WINDOWS, WINDOW_SIZE> as ConsolePathHash>::PADDING_MODE;
+
     /// Returns the hash of the given child nodes.
     fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Self::Hash {
         let mut input = Vec::new();
@@ -46,6 +62,10 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> PathHash<E> f
 impl<E: Environment, const RATE: usize> PathHash<E> for Poseidon<E, RATE> {
     type Hash = Field<E>;
 
+    #[cfg(console)]
+    const PADDING_MODE: console::merkle_tree::PaddingMode =
+        <console::algorithms::Poseidon<E::Network, RATE> as ConsolePathHash>::PADDING_MODE;
+
     /// Returns the hash of the given child nodes.
     fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Self::Hash {
         // Prepend the nodes with a `1field` byte.
@@ -99,6 +119,52 @@ mod tests {
         }};
     }
 
+    /// A path hasher that pads the Merkle tree with the zero element.
+    struct ZeroPadded(BHP512<Circuit>);
+
+    impl PathHash<Circuit> for ZeroPadded {
+        type Hash = Field<Circuit>;
+
+        const PADDING_MODE: console::merkle_tree::PaddingMode = console::merkle_tree::PaddingMode::Zero;
+
+        fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Self::Hash {
+            self.0.hash_children(left, right)
+        }
+    }
+
+    #[test]
+    fn test_hash_empty() -> Result<()> {
+        type Network = <Circuit as Environment>::Network;
+
+        let native_bhp = snarkvm_console_algorithms::BHP512::<Network>::setup(DOMAIN)?;
+        let native_poseidon = snarkvm_console_algorithms::Poseidon2::<Network>::setup(DOMAIN)?;
+        let circuit_bhp = BHP512::<Circuit>::constant(native_bhp.clone());
+        let circuit_poseidon = Poseidon2::<Circuit>::constant(native_poseidon.clone());
+
+        // Ensure the empty hash matches the native empty hash.
+        let expected = console::merkle_tree::PathHash::hash_empty(&native_bhp)?;
+        assert_eq!(expected, circuit_bhp.hash_empty().eject_value());
+        let expected = console::merkle_tree::PathHash::hash_empty(&native_poseidon)?;
+        assert_eq!(expected, circuit_poseidon.hash_empty().eject_value());
+
+        // Ensure the padding modes are derived from the native path hashers.
+        type NativeBHP512 = snarkvm_console_algorithms::BHP512<Network>;
+        type NativePoseidon2 = snarkvm_console_algorithms::Poseidon2<Network>;
+        assert_eq!(
+            <BHP512<Circuit> as PathHash<Circuit>>::PADDING_MODE,
+            <NativeBHP512 as console::merkle_tree::PathHash>::PADDING_MODE
+        );
+        assert_eq!(
+            <Poseidon2<Circuit> as PathHash<Circuit>>::PADDING_MODE,
+            <NativePoseidon2 as console::merkle_tree::PathHash>::PADDING_MODE
+        );
+
+        // Ensure the empty hash follows the padding mode.
+        assert_eq!(console::Field::zero(), ZeroPadded(circuit_bhp).hash_empty().eject_value());
+        Circuit::reset();
+        Ok(())
+    }
+
     #[test]
     fn test_hash_children_bhp512_constant() -> Result<()> {
         check_hash_children!(BHP512, Constant, (1599, 0, 0, 0))
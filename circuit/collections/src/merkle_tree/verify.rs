@@ -16,6 +16,10 @@ use super::*;
 
 impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
     /// Returns `true` if the Merkle path is valid for the given root and leaf.
+    ///
+    /// Note: This gadget does not take the number of leaves as input, so it does not constrain the leaf index
+    /// against it. As in the console `MerklePath::verify`, a path for a padding position only verifies
+    /// if `hash_leaf(leaf)` equals the padding node, which requires a preimage of the path hash.
     pub fn verify<LH: LeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
         &self,
         leaf_hasher: &LH,
@@ -173,4 +177,56 @@ mod tests {
     fn test_verify_poseidon2_private() -> Result<()> {
         check_verify!(Poseidon4, Poseidon2, Private, 32, 4, (33, 0, 18046, 18046))
     }
+
+    #[test]
+    fn test_verify_matches_native_padding() -> Result<()> {
+        type Network = <Circuit as Environment>::Network;
+        const DEPTH: u8 = 3;
+
+        let mut rng = TestRng::default();
+
+        // Initialize the hashers.
+        let native_leaf_hasher = snarkvm_console_algorithms::BHP1024::<Network>::setup(DOMAIN)?;
+        let native_path_hasher = snarkvm_console_algorithms::BHP512::<Network>::setup(DOMAIN)?;
+        let circuit_leaf_hasher = BHP1024::<Circuit>::constant(native_leaf_hasher.clone());
+        let circuit_path_hasher = BHP512::<Circuit>::constant(native_path_hasher.clone());
+
+        // Construct a Merkle tree with a non-power-of-two number of leaves.
+        let leaves = (0..5).map(|_| (0..1024).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let merkle_tree = console::merkle_tree::MerkleTree::<_, _, _, DEPTH>::new(
+            &native_leaf_hasher,
+            &native_path_hasher,
+            &leaves,
+        )?;
+        assert_eq!(5, merkle_tree.number_of_leaves());
+
+        for (index, merkle_leaf) in leaves.iter().enumerate() {
+            let merkle_path = merkle_tree.prove(index, merkle_leaf)?;
+            assert!(merkle_tree.verify(&merkle_path, merkle_tree.root(), merkle_leaf));
+
+            // Ensure the circuit agrees with the native verification.
+            let path = MerklePath::<Circuit, DEPTH>::new(Mode::Private, merkle_path);
+            let root = Field::new(Mode::Private, *merkle_tree.root());
+            let leaf: Vec<_> = Inject::new(Mode::Private, merkle_leaf.clone());
+            assert!(path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaf).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+
+        // Ensure a path targeting a padding position is rejected by both the native tree and the circuit.
+        // Note: The circuit rejects it because the leaf hash differs from the padding node (see `verify`).
+        let merkle_path = merkle_tree.prove(4, &leaves[4])?;
+        let padding_path = console::merkle_tree::MerklePath::<_, DEPTH>::try_from((
+            console::U64::new(5),
+            merkle_path.siblings().to_vec(),
+        ))?;
+        assert!(!merkle_tree.verify(&padding_path, merkle_tree.root(), &leaves[4]));
+
+        let path = MerklePath::<Circuit, DEPTH>::new(Mode::Private, padding_path);
+        let root = Field::new(Mode::Private, *merkle_tree.root());
+        let leaf: Vec<_> = Inject::new(Mode::Private, leaves[4].clone());
+        assert!(!path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaf).eject_value());
+        Circuit::reset();
+        Ok(())
+    }
 }
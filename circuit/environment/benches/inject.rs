@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_circuit::{
+    environment::{Circuit, Environment, Inject, Mode},
+    types::Field,
+};
+use snarkvm_console_types::prelude::{TestRng, Uniform};
+
+use criterion::Criterion;
+
+type CurrentNetwork = <Circuit as Environment>::Network;
+
+fn inject(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    const ITERATIONS: usize = 1000;
+
+    let values = (0..ITERATIONS)
+        .map(|_| Uniform::rand(rng))
+        .collect::<Vec<snarkvm_console_types::Field<CurrentNetwork>>>();
+
+    c.bench_function("Field::new (x1000)", |b| {
+        b.iter(|| {
+            let _circuits = values.iter().map(|value| Field::<Circuit>::new(Mode::Private, *value)).collect::<Vec<_>>();
+            Circuit::reset();
+        })
+    });
+
+    c.bench_function("Field::inject_all (x1000)", |b| {
+        b.iter(|| {
+            let _circuits = Field::<Circuit>::inject_all(Mode::Private, &values);
+            Circuit::reset();
+        })
+    });
+}
+
+criterion_group! {
+    name = inject_benches;
+    config = Criterion::default().sample_size(10);
+    targets = inject
+}
+
+criterion_main!(inject_benches);
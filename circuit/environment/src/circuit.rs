@@ -201,6 +201,11 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| circuit.borrow().is_satisfied_in_scope())
     }
 
+    /// Returns the index and scope of the first unsatisfied constraint in the environment, if one exists.
+    fn first_unsatisfied_constraint() -> Option<(u64, String)> {
+        CIRCUIT.with(|circuit| circuit.borrow().first_unsatisfied_constraint())
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         CIRCUIT.with(|circuit| circuit.borrow().num_constants())
@@ -253,9 +258,7 @@ impl Environment for Circuit {
 
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
-        let error = message.into();
-        // eprintln!("{}", &error);
-        panic!("{}", &error)
+        Self::halt_with(console::HaltCode::Custom, message)
     }
 
     /// Returns the constraint limit for the circuit, if one exists.
@@ -395,4 +398,25 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_first_unsatisfied_constraint() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let two = one + one;
+
+        Circuit::scope("outer", || {
+            let a = Field::<Circuit>::new(Mode::Private, one);
+            Circuit::assert_eq(&a, &a);
+            assert_eq!(None, Circuit::first_unsatisfied_constraint());
+
+            Circuit::scope("inner", || {
+                Circuit::assert_eq(&a, Field::<Circuit>::new(Mode::Private, two));
+            });
+        });
+        assert!(!Circuit::is_satisfied());
+        assert_eq!(Some((1, "outer.inner".to_string())), Circuit::first_unsatisfied_constraint());
+
+        Circuit::reset();
+        assert_eq!(None, Circuit::first_unsatisfied_constraint());
+    }
 }
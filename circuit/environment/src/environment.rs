@@ -110,6 +110,9 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
+    /// Returns the index and scope of the first unsatisfied constraint in the environment, if one exists.
+    fn first_unsatisfied_constraint() -> Option<(u64, String)>;
+
     /// Returns the number of constants in the entire environment.
     fn num_constants() -> u64;
 
@@ -161,6 +164,12 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         <Self::Network as console::Environment>::halt(message)
     }
 
+    /// Halts the program from further synthesis, evaluation, and execution in the current environment,
+    /// with the given halt code as a `HaltError` panic payload (see `HaltError::from_panic`).
+    fn halt_with<S: Into<String>, T>(code: console::HaltCode, message: S) -> T {
+        <Self::Network as console::Environment>::halt_with(code, message)
+    }
+
     /// Returns the constraint limit for the circuit, if one exists.
     fn get_constraint_limit() -> Option<u64>;
 
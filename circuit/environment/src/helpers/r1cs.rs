@@ -124,6 +124,14 @@ impl<F: PrimeField> R1CS<F> {
         })
     }
 
+    /// Returns the index and scope of the first unsatisfied constraint, if one exists.
+    pub fn first_unsatisfied_constraint(&self) -> Option<(u64, Scope)> {
+        self.constraints.iter().enumerate().find_map(|(index, constraint)| match constraint.is_satisfied() {
+            true => None,
+            false => Some((index as u64, constraint.0.clone())),
+        })
+    }
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     pub(crate) fn is_satisfied_in_scope(&self) -> bool {
         self.counter.is_satisfied_in_scope()
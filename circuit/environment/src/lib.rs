@@ -81,6 +81,8 @@ pub mod prelude {
             SquareRoot as _,
             ToBits as _,
         },
+        HaltCode,
+        HaltError,
         Parser,
         ParserResult,
         TypeName,
@@ -32,6 +32,17 @@ pub trait Inject {
     {
         Self::new(Mode::Constant, value)
     }
+
+    ///
+    /// Initializes circuits of the given mode for each of the given primitive values, in order.
+    ///
+    fn inject_all(mode: Mode, values: &[Self::Primitive]) -> Vec<Self>
+    where
+        Self: Sized,
+        Self::Primitive: Clone,
+    {
+        values.iter().map(|value| Self::new(mode, value.clone())).collect()
+    }
 }
 
 /********************/
@@ -84,3 +95,79 @@ inject_tuple!((C0, 0), (C1, 1));
 inject_tuple!((C0, 0), (C1, 1), (C2, 2));
 inject_tuple!((C0, 0), (C1, 1), (C2, 2), (C3, 3));
 inject_tuple!((C0, 0), (C1, 1), (C2, 2), (C3, 3), (C4, 4));
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+    use snarkvm_console_types as console;
+    use snarkvm_console_types::prelude::{TestRng, Uniform};
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: usize = 10;
+
+    /// Checks that injecting the given values and ejecting them again returns the same values.
+    fn check_inject_all<C: Inject + Eject<Primitive = <C as Inject>::Primitive>>(values: &[<C as Inject>::Primitive])
+    where
+        <C as Inject>::Primitive: Clone + PartialEq + core::fmt::Debug,
+    {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let circuits = C::inject_all(mode, values);
+            assert_eq!(values.len(), circuits.len());
+            for (value, circuit) in values.iter().zip(circuits) {
+                assert_eq!(mode, circuit.eject_mode());
+                assert_eq!(*value, circuit.eject_value());
+            }
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_inject_all() {
+        let rng = &mut TestRng::default();
+
+        let sample = |rng: &mut TestRng| Uniform::rand(rng);
+        check_inject_all::<Boolean<Circuit>>(&(0..ITERATIONS).map(|_| sample(rng)).collect::<Vec<bool>>());
+        check_inject_all::<Field<Circuit>>(
+            &(0..ITERATIONS).map(|_| sample(rng)).collect::<Vec<console::Field<CurrentNetwork>>>(),
+        );
+        check_inject_all::<Scalar<Circuit>>(
+            &(0..ITERATIONS).map(|_| sample(rng)).collect::<Vec<console::Scalar<CurrentNetwork>>>(),
+        );
+        check_inject_all::<Group<Circuit>>(
+            &(0..ITERATIONS).map(|_| sample(rng)).collect::<Vec<console::Group<CurrentNetwork>>>(),
+        );
+        check_inject_all::<Address<Circuit>>(
+            &(0..ITERATIONS).map(|_| sample(rng)).collect::<Vec<console::Address<CurrentNetwork>>>(),
+        );
+        check_inject_all::<U8<Circuit>>(
+            &(0..ITERATIONS).map(|_| sample(rng)).collect::<Vec<console::U8<CurrentNetwork>>>(),
+        );
+        check_inject_all::<I64<Circuit>>(
+            &(0..ITERATIONS).map(|_| sample(rng)).collect::<Vec<console::I64<CurrentNetwork>>>(),
+        );
+    }
+
+    #[test]
+    fn test_inject_all_is_contiguous() {
+        let rng = &mut TestRng::default();
+
+        let values = (0..ITERATIONS).map(|_| Uniform::rand(rng)).collect::<Vec<console::Field<CurrentNetwork>>>();
+        let num_private = Circuit::num_private();
+        let circuits = Field::<Circuit>::inject_all(Mode::Private, &values);
+        // Ensure one private variable is allocated per value.
+        assert_eq!(num_private + ITERATIONS as u64, Circuit::num_private());
+
+        // Ensure the values are allocated at consecutive indices, in order.
+        let indices = circuits
+            .iter()
+            .map(|circuit| {
+                let lc = LinearCombination::from(circuit);
+                assert_eq!(1, lc.to_terms().len());
+                lc.to_terms()[0].0.index()
+            })
+            .collect::<Vec<_>>();
+        assert!(indices.windows(2).all(|pair| pair[1] == pair[0] + 1), "Found non-contiguous indices: {indices:?}");
+        Circuit::reset();
+    }
+}
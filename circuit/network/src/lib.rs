@@ -35,6 +35,9 @@ pub trait Aleo: Environment {
     /// Returns the serial number domain as a constant field element.
     fn serial_number_domain() -> Field<Self>;
 
+    /// Returns the PRF domain as a constant field element.
+    fn prf_domain() -> Field<Self>;
+
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
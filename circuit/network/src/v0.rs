@@ -60,6 +60,8 @@ thread_local! {
     static GRAPH_KEY_DOMAIN: Field<AleoV0> = Field::constant(<console::MainnetV0 as console::Network>::graph_key_domain());
     /// The serial number domain as a constant field element.
     static SERIAL_NUMBER_DOMAIN: Field<AleoV0> = Field::constant(<console::MainnetV0 as console::Network>::serial_number_domain());
+    /// The PRF domain as a constant field element.
+    static PRF_DOMAIN: Field<AleoV0> = Field::constant(<console::MainnetV0 as console::Network>::prf_domain());
 
     /// The BHP hash function, which can take an input of up to 256 bits.
     static BHP_256: BHP256<AleoV0> = BHP256::<AleoV0>::constant(console::BHP_256.clone());
@@ -116,6 +118,11 @@ impl Aleo for AleoV0 {
         SERIAL_NUMBER_DOMAIN.with(|domain| domain.clone())
     }
 
+    /// Returns the PRF domain as a constant field element.
+    fn prf_domain() -> Field<Self> {
+        PRF_DOMAIN.with(|domain| domain.clone())
+    }
+
     /// Returns the scalar multiplication on the generator `G`.
     #[inline]
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
@@ -411,6 +418,11 @@ impl Environment for AleoV0 {
         E::is_satisfied_in_scope()
     }
 
+    /// Returns the index and scope of the first unsatisfied constraint in the environment, if one exists.
+    fn first_unsatisfied_constraint() -> Option<(u64, String)> {
+        E::first_unsatisfied_constraint()
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         E::num_constants()
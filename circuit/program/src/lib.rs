@@ -28,6 +28,9 @@ pub use function_id::*;
 mod id;
 pub use id::*;
 
+mod prf;
+pub use prf::*;
+
 mod request;
 pub use request::*;
 
@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, integers::Integer, Field, U64};
+
+/// The number of low-order bits of the PRF output that are scaled into a range.
+const PRF_RANGE_BITS: usize = 128;
+
+/// Computes the pseudorandom field element for the given seed and counter,
+/// as `Hash(prf_domain, seed, counter)`.
+pub fn prf_psd2<A: Aleo>(seed: &Field<A>, counter: u64) -> Field<A> {
+    A::hash_psd2(&[A::prf_domain(), seed.clone(), Field::constant(console::Field::from_u64(counter))])
+}
+
+/// Computes the pseudorandom integer for the given seed and counter,
+/// by truncating the PRF output to the lower `I::BITS` bits.
+pub fn prf_to_integer<A: Aleo, I: IntegerType>(seed: &Field<A>, counter: u64) -> Integer<A, I> {
+    let bits_le = prf_psd2(seed, counter).to_bits_le();
+    Integer::from_bits_le(&bits_le[..I::BITS as usize])
+}
+
+/// Computes the pseudorandom value in `[0, n)` for the given seed and counter,
+/// as `(x * n) >> 128`, where `x` is the lower 128 bits of the PRF output.
+pub fn prf_to_range<A: Aleo>(seed: &Field<A>, counter: u64, n: u64) -> U64<A> {
+    // Ensure the range is not empty.
+    if n == 0 {
+        A::halt("The PRF range must be nonempty")
+    }
+
+    // Retrieve the lower 128 bits of the PRF output.
+    let bits_le = prf_psd2(seed, counter).to_bits_le();
+    let x = Field::from_bits_le(&bits_le[..PRF_RANGE_BITS]);
+    // Compute `x * n`, which is less than 2^192 and thus does not wrap around the modulus.
+    let product = x * Field::constant(console::Field::from_u64(n));
+    // Return the upper 64 bits of the product.
+    U64::from_bits_le(&product.to_lower_bits_le(PRF_RANGE_BITS + 64)[PRF_RANGE_BITS..])
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_types::environment::assert_scope;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_prf_psd2(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random seed and counter.
+            let seed = Uniform::rand(&mut rng);
+            let counter = u64::rand(&mut rng);
+
+            // Compute the expected output.
+            let expected = console::prf_psd2::<CurrentNetwork>(&seed, counter).unwrap();
+
+            // Inject the seed.
+            let candidate_seed = Field::<Circuit>::new(mode, seed);
+
+            Circuit::scope(format!("PRF {mode} {i}"), || {
+                let candidate = prf_psd2(&candidate_seed, counter);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                // Note: The first iteration initializes the Poseidon parameters and the PRF domain.
+                if i > 0 {
+                    assert_scope!(num_constants, num_public, num_private, num_constraints);
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_prf_to_integer(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random seed and counter.
+            let seed = Uniform::rand(&mut rng);
+            let counter = u64::rand(&mut rng);
+
+            // Compute the expected output.
+            let expected = console::prf_to_integer::<CurrentNetwork, u32>(&seed, counter).unwrap();
+
+            // Inject the seed.
+            let candidate_seed = Field::<Circuit>::new(mode, seed);
+
+            Circuit::scope(format!("PRF to integer {mode} {i}"), || {
+                let candidate = prf_to_integer::<Circuit, u32>(&candidate_seed, counter);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                // Note: The first iteration initializes the Poseidon parameters and the PRF domain.
+                if i > 0 {
+                    assert_scope!(num_constants, num_public, num_private, num_constraints);
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_prf_to_range(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random seed and counter.
+            let seed = Uniform::rand(&mut rng);
+            let counter = u64::rand(&mut rng);
+
+            // Compute the expected output.
+            let expected = console::prf_to_range::<CurrentNetwork>(&seed, counter, 1000).unwrap();
+
+            // Inject the seed.
+            let candidate_seed = Field::<Circuit>::new(mode, seed);
+
+            Circuit::scope(format!("PRF to range {mode} {i}"), || {
+                let candidate = prf_to_range(&candidate_seed, counter, 1000);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                // Note: The first iteration initializes the Poseidon parameters and the PRF domain.
+                if i > 0 {
+                    assert_scope!(num_constants, num_public, num_private, num_constraints);
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_prf_psd2_constant() {
+        check_prf_psd2(Mode::Constant, 2, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_prf_psd2_public() {
+        check_prf_psd2(Mode::Public, 2, 0, 540, 540);
+    }
+
+    #[test]
+    fn test_prf_psd2_private() {
+        check_prf_psd2(Mode::Private, 2, 0, 540, 540);
+    }
+
+    #[test]
+    fn test_prf_to_integer_constant() {
+        check_prf_to_integer(Mode::Constant, 255, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_prf_to_integer_public() {
+        check_prf_to_integer(Mode::Public, 2, 0, 1045, 1047);
+    }
+
+    #[test]
+    fn test_prf_to_integer_private() {
+        check_prf_to_integer(Mode::Private, 2, 0, 1045, 1047);
+    }
+
+    #[test]
+    fn test_prf_to_range_constant() {
+        check_prf_to_range(Mode::Constant, 448, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_prf_to_range_public() {
+        check_prf_to_range(Mode::Public, 3, 0, 1237, 1240);
+    }
+
+    #[test]
+    fn test_prf_to_range_private() {
+        check_prf_to_range(Mode::Private, 3, 0, 1237, 1240);
+    }
+
+    #[test]
+    fn test_prf_to_range_boundaries() {
+        let mut rng = TestRng::default();
+
+        let seed = Uniform::rand(&mut rng);
+        let candidate_seed = Field::<Circuit>::new(Mode::Private, seed);
+
+        for n in [1, 2, u64::MAX] {
+            let expected = console::prf_to_range::<CurrentNetwork>(&seed, 0, n).unwrap();
+            let candidate = prf_to_range(&candidate_seed, 0, n);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(*candidate.eject_value() < n);
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -21,7 +21,10 @@ impl<E: Environment> Boolean<E> {
     pub fn is_less_than_or_equal_constant(circuit_bits_le: &[Boolean<E>], constant_bits_le: &[bool]) -> Boolean<E> {
         // Ensure the length matches.
         if circuit_bits_le.len() != constant_bits_le.len() {
-            E::halt(format!("Mismatching length of bits ({} != {})", circuit_bits_le.len(), constant_bits_le.len()))
+            E::halt_with(
+                HaltCode::TypeMismatch,
+                format!("Mismatching length of bits ({} != {})", circuit_bits_le.len(), constant_bits_le.len()),
+            )
         }
 
         // Compute `!(constant_bits_le < circuit_bits_le)`, equivalent to `constant_bits_le >= circuit_bits_le`.
@@ -22,7 +22,10 @@ impl<E: Environment> FromBits for Boolean<E> {
         // Ensure there is exactly one boolean in the list of booleans.
         match bits_le.len() == 1 {
             true => bits_le[0].clone(),
-            false => E::halt(format!("Attempted to instantiate a boolean with {} bits", bits_le.len())),
+            false => E::halt_with(
+                HaltCode::ParseFailure,
+                format!("Attempted to instantiate a boolean with {} bits", bits_le.len()),
+            ),
         }
     }
 
@@ -31,7 +34,10 @@ impl<E: Environment> FromBits for Boolean<E> {
         // Ensure there is exactly one boolean in the list of booleans.
         match bits_be.len() == 1 {
             true => bits_be[0].clone(),
-            false => E::halt(format!("Attempted to instantiate a boolean with {} bits", bits_be.len())),
+            false => E::halt_with(
+                HaltCode::ParseFailure,
+                format!("Attempted to instantiate a boolean with {} bits", bits_be.len()),
+            ),
         }
     }
 }
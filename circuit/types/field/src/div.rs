@@ -59,7 +59,9 @@ impl<E: Environment> DivAssign<&Self> for Field<E> {
     fn div_assign(&mut self, other: &Self) {
         match other.is_constant() {
             // If `other` is a constant and zero, halt since the inverse of zero is undefined.
-            true if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            true if other.eject_value().is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, "Attempted to divide by zero.")
+            }
             // If `other` is a constant and non-zero, we can perform multiplication and inversion for 0 constraints.
             // If `self` is a constant, we can perform multiplication and inversion for 1 constraint.
             // Otherwise, we can perform multiplication and inversion for 2 constraints.
@@ -23,7 +23,9 @@ impl<E: Environment> DivUnchecked<Field<E>> for Field<E> {
     fn div_unchecked(&self, other: &Field<E>) -> Self::Output {
         match (self.is_constant(), other.is_constant()) {
             // If `other` is a constant and zero, halt since the inverse of zero is undefined.
-            (_, true) if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            (_, true) if other.eject_value().is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, "Attempted to divide by zero.")
+            }
             // If `other` is a constant and non-zero, we can perform the multiplication and inversion
             // without paying for any private variables or constraints.
             // If `self` is a constant, we can perform the multiplication and inversion for 1 constraint.
@@ -63,6 +63,43 @@ impl<E: Environment> Field<E> {
     }
 }
 
+impl<E: Environment> Field<E> {
+    /// Returns `true` if `self` is a square (including zero).
+    ///
+    /// This witnesses whether `self` is a nonzero square, and a square root of either `self` (if it is),
+    /// or of `g * self` (if it is not), where `g` is a fixed non-square. As exactly one of `self` and `g * self`
+    /// is a square when `self` is nonzero, the witnessed flag can only be set correctly.
+    pub fn is_square(&self) -> Boolean<E> {
+        // Retrieve the multiplicative generator, which is a non-square.
+        let non_square = <E::BaseField as snarkvm_fields::FftField>::multiplicative_generator();
+
+        // Compute the witnesses.
+        let value = self.eject_value();
+        let is_nonzero_square_witness = !value.is_zero() && value.square_root().is_ok();
+        let root_witness = match is_nonzero_square_witness {
+            true => value.square_root(),
+            false => (value * console::Field::new(non_square)).square_root(),
+        }
+        .unwrap_or_else(|_| console::Field::zero());
+
+        // Initialize the flag and the root.
+        let mode = if self.eject_mode() == Mode::Constant { Mode::Constant } else { Mode::Private };
+        let is_nonzero_square = Boolean::new(mode, is_nonzero_square_witness);
+        let root = Field::new(mode, root_witness);
+
+        // Compute the square, which is `self` if the flag is set, and `g * self` otherwise.
+        let product = self * Field::from_boolean(&is_nonzero_square);
+        let square: Field<E> = (&self.linear_combination * non_square
+            + &product.linear_combination * (E::BaseField::one() - non_square))
+            .into();
+        // Enforce that the root squared is equal to the square.
+        E::enforce(|| (&root, &root, &square));
+
+        // Note: If `self` is zero, both values of the flag satisfy the constraint, so zero is checked separately.
+        is_nonzero_square | self.is_zero()
+    }
+}
+
 impl<E: Environment> Field<E> {
     /// Returns both square roots of `self` and a `Boolean` flag, which is set iff `self` is not a square.
     ///
@@ -229,6 +266,25 @@ mod tests {
         }
     }
 
+    fn check_is_square(
+        name: &str,
+        given: console::Field<<Circuit as Environment>::Network>,
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let expected = given.square_root().is_ok();
+        let input = Field::<Circuit>::new(mode, given);
+        Circuit::scope(name, || {
+            let candidate = input.is_square();
+            assert_eq!(expected, candidate.eject_value(), "is_square({given})");
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
     #[test]
     fn test_square_root() {
         let mut rng = TestRng::default();
@@ -255,4 +311,32 @@ mod tests {
         check_square_roots_flagged_nondeterministic("Public", Mode::Public, &mut rng, 254, 0, 344, 344);
         check_square_roots_flagged_nondeterministic("Private", Mode::Private, &mut rng, 254, 0, 344, 344);
     }
+
+    #[test]
+    fn test_is_square() {
+        let mut rng = TestRng::default();
+
+        // Initialize a non-square.
+        let generator = <<Circuit as Environment>::BaseField as snarkvm_fields::FftField>::multiplicative_generator();
+        let non_square = console::Field::new(generator);
+        assert!(non_square.square_root().is_err());
+
+        let cases = [(Mode::Constant, (3, 0, 0, 0)), (Mode::Public, (0, 0, 6, 6)), (Mode::Private, (0, 0, 6, 6))];
+        for (mode, (num_constants, num_public, num_private, num_constraints)) in cases {
+            let check = |name: &str, given| {
+                check_is_square(name, given, mode, num_constants, num_public, num_private, num_constraints)
+            };
+
+            // Check zero, one, and a non-square.
+            check("Zero", console::Field::zero());
+            check("One", console::Field::one());
+            check("NonSquare", non_square);
+            // Check random squares and non-squares.
+            for _ in 0..ITERATIONS {
+                let given: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                check("Square", given.square());
+                check("Random", given);
+            }
+        }
+    }
 }
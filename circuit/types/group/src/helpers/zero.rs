@@ -28,6 +28,17 @@ impl<E: Environment> Zero for Group<E> {
     }
 }
 
+impl<E: Environment> Group<E> {
+    /// Enforces that `self` is not the zero (identity) element, assuming `self` is in the group.
+    ///
+    /// The only points on the curve with an x-coordinate of zero are the identity `(0, 1)`
+    /// and the point of order two `(0, -1)`, which is not in the prime-order subgroup.
+    /// Thus, for points in the group, it suffices to enforce that the x-coordinate is nonzero.
+    pub fn enforce_not_zero(&self) {
+        E::assert_neq(&self.x, Field::<E>::zero());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,4 +64,39 @@ mod tests {
         // Should equal 0.
         assert!(candidate.is_zero().eject_value());
     }
+
+    fn check_enforce_not_zero(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        // Ensure a nonzero point is accepted.
+        let point = Group::<Circuit>::new(mode, Uniform::rand(&mut rng));
+        Circuit::scope(format!("{mode}"), || {
+            point.enforce_not_zero();
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure the zero point is rejected.
+        if !mode.is_constant() {
+            Group::<Circuit>::new(mode, console::Group::zero()).enforce_not_zero();
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_enforce_not_zero_constant() {
+        check_enforce_not_zero(Mode::Constant, 1, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_enforce_not_zero_public() {
+        check_enforce_not_zero(Mode::Public, 0, 0, 1, 1);
+    }
+
+    #[test]
+    fn test_enforce_not_zero_private() {
+        check_enforce_not_zero(Mode::Private, 0, 0, 1, 1);
+    }
 }
@@ -445,4 +445,35 @@ mod tests {
         let candidate_b = base * scalar;
         assert_eq!(expected, candidate_b.eject_value());
     }
+
+    #[test]
+    fn test_mul_boundary_scalars() {
+        let mut rng = TestRng::default();
+
+        let one = console::Scalar::<<Circuit as Environment>::Network>::one();
+        let scalars = [console::Scalar::zero(), one, -one];
+
+        const MODES: [(Mode, Mode); 3] =
+            [(Mode::Constant, Mode::Constant), (Mode::Private, Mode::Constant), (Mode::Private, Mode::Private)];
+
+        for i in 0..ITERATIONS {
+            let base: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+
+            for scalar in scalars {
+                let expected = base * scalar;
+
+                for (mode_a, mode_b) in MODES {
+                    let a = Group::<Circuit>::new(mode_a, base);
+                    let b = Scalar::<Circuit>::new(mode_b, scalar);
+
+                    Circuit::scope(format!("Mul: {mode_a} * {mode_b} ({scalar}) {i}"), || {
+                        let candidate = a * b;
+                        assert_eq!(expected, candidate.eject_value());
+                        assert!(Circuit::is_satisfied_in_scope());
+                    });
+                    Circuit::reset();
+                }
+            }
+        }
+    }
 }
@@ -71,7 +71,7 @@ impl<E: Environment, I: IntegerType> AddChecked<Self> for Integer<E, I> {
             // Compute the sum and return the new constant.
             match self.eject_value().checked_add(&other.eject_value()) {
                 Some(value) => Integer::constant(console::Integer::new(value)),
-                None => E::halt("Integer overflow on addition of two constants"),
+                None => E::halt_with(HaltCode::Overflow, "Integer overflow on addition of two constants"),
             }
         } else if I::is_signed() {
             // Instead of adding the bits of `self` and `other` directly, the integers are
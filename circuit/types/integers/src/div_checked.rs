@@ -68,11 +68,13 @@ impl<E: Environment, I: IntegerType> DivChecked<Self> for Integer<E, I> {
     fn div_checked(&self, other: &Integer<E, I>) -> Self::Output {
         match (self.is_constant(), other.is_constant()) {
             // If `other` is a constant and is zero, then halt.
-            (_, true) if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            (_, true) if other.eject_value().is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, "Attempted to divide by zero.")
+            }
             // If `self` and `other` are constants, and other is not zero, then directly return the value of the division.
             (true, true) => match self.eject_value().checked_div(&other.eject_value()) {
                 Some(value) => Integer::constant(console::Integer::new(value)),
-                None => E::halt("Overflow on division of two integer constants"),
+                None => E::halt_with(HaltCode::Overflow, "Overflow on division of two integer constants"),
             },
             // Handle the remaining cases.
             // Note that `other` is either a constant and non-zero, or not a constant.
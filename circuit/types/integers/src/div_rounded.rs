@@ -0,0 +1,307 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the `quotient` of `self` and `other`, rounded towards positive infinity.
+    ///
+    /// This halts (or is unsatisfiable) if `other` is zero, or if the quotient overflows (i.e. `MIN / -1`).
+    pub fn div_ceil(&self, other: &Integer<E, I>) -> Self {
+        match (self.is_constant(), other.is_constant()) {
+            // If `other` is a constant and is zero, then halt.
+            (_, true) if other.eject_value().is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, "Attempted to divide by zero.")
+            }
+            // If `self` and `other` are constants, and other is not zero, then directly return the value.
+            (true, true) => Integer::constant(self.eject_value().div_ceil(&other.eject_value())),
+            // Handle the remaining cases.
+            _ => {
+                let (quotient, is_exact, operands_same_sign) = self.div_checked_with_flags(other);
+                // Round up if the division is inexact and the exact quotient is positive.
+                // Note: This never overflows, as an inexact division implies that `|other| >= 2`.
+                let round_up = !is_exact & operands_same_sign;
+                quotient.add_wrapped(&Self::from_bits_le(&[round_up]))
+            }
+        }
+    }
+
+    /// Returns the `quotient` of `self` and `other`, rounded towards negative infinity.
+    ///
+    /// For unsigned integers, this is equivalent to `self / other`.
+    /// For signed integers, this differs from `self / other` when the exact quotient is negative and inexact,
+    /// e.g. `-7 / 2` is `-3`, while `(-7).div_floor(2)` is `-4`.
+    ///
+    /// This halts (or is unsatisfiable) if `other` is zero, or if the quotient overflows (i.e. `MIN / -1`).
+    pub fn div_floor(&self, other: &Integer<E, I>) -> Self {
+        match (self.is_constant(), other.is_constant()) {
+            // If `other` is a constant and is zero, then halt.
+            (_, true) if other.eject_value().is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, "Attempted to divide by zero.")
+            }
+            // If `self` and `other` are constants, and other is not zero, then directly return the value.
+            (true, true) => Integer::constant(self.eject_value().div_floor(&other.eject_value())),
+            // Handle the remaining cases.
+            _ => match I::is_signed() {
+                true => {
+                    let (quotient, is_exact, operands_same_sign) = self.div_checked_with_flags(other);
+                    // Round down if the division is inexact and the exact quotient is negative.
+                    // Note: This never overflows, as an inexact division implies that `|other| >= 2`.
+                    let round_down = !is_exact & !operands_same_sign;
+                    quotient.sub_wrapped(&Self::from_bits_le(&[round_down]))
+                }
+                // Note that this call to `div_wrapped` checks that `other` is not zero.
+                false => self.div_wrapped(other),
+            },
+        }
+    }
+
+    /// Returns `true` if `self` is a multiple of `other`.
+    ///
+    /// Zero is a multiple of every integer, and only zero is a multiple of zero.
+    pub fn is_multiple_of(&self, other: &Integer<E, I>) -> Boolean<E> {
+        match (self.is_constant(), other.is_constant()) {
+            // If `self` and `other` are constants, then directly return the result.
+            (true, true) => Boolean::constant(*self.eject_value().is_multiple_of(&other.eject_value())),
+            // If `other` is a constant and is zero, then return whether `self` is zero.
+            (_, true) if other.eject_value().is_zero() => self.is_zero(),
+            // Handle the remaining cases.
+            _ => {
+                // Substitute a divisor of one if `other` is zero, so that the division is always satisfiable.
+                let other_is_zero = other.is_zero();
+                let divisor = Self::ternary(&other_is_zero, &Self::one(), other);
+                // Compute the remainder of the absolute values.
+                // Note: This handles `MIN % -1` without overflow, as `|MIN|` is representable in the dual type.
+                let remainder_is_zero = match I::is_signed() {
+                    true => {
+                        let unsigned_dividend = self.abs_wrapped().cast_as_dual();
+                        let unsigned_divisor = divisor.abs_wrapped().cast_as_dual();
+                        unsigned_dividend.unsigned_division_via_witness(&unsigned_divisor).1.is_zero()
+                    }
+                    false => self.unsigned_division_via_witness(&divisor).1.is_zero(),
+                };
+                Boolean::ternary(&other_is_zero, &self.is_zero(), &remainder_is_zero)
+            }
+        }
+    }
+
+    /// Returns the truncated `quotient` of `self` and `other`, along with a `Boolean` indicating
+    /// whether the division is exact, and a `Boolean` indicating whether the operands have the same sign.
+    /// This method checks that `other` is not zero, and that the quotient does not overflow.
+    fn div_checked_with_flags(&self, other: &Self) -> (Self, Boolean<E>, Boolean<E>) {
+        match I::is_signed() {
+            true => {
+                // Ensure that overflow cannot occur in this division.
+                // Signed integer division wraps when the dividend is Integer::MIN and the divisor is -1.
+                let min = Integer::constant(console::Integer::MIN);
+                let neg_one = Integer::constant(-console::Integer::one());
+                let overflows = self.is_equal(&min) & other.is_equal(&neg_one);
+                E::assert(!overflows);
+
+                // Divide the absolute value of `self` and `other` in the base field.
+                // Note that it is safe to use `abs_wrapped`, since the case for console::Integer::MIN is handled above.
+                let unsigned_dividend = self.abs_wrapped().cast_as_dual();
+                // Note that `unsigned_divisor` is zero iff `other` is zero.
+                let unsigned_divisor = other.abs_wrapped().cast_as_dual();
+                // Note that the remainder check in this call ensures that `unsigned_divisor` is not zero.
+                let (unsigned_quotient, unsigned_remainder) =
+                    unsigned_dividend.unsigned_division_via_witness(&unsigned_divisor);
+
+                // Note that quotient <= |console::Integer::MIN|,
+                // since the dividend <= |console::Integer::MIN| and 0 <= quotient <= dividend.
+                let signed_quotient = Integer { bits_le: unsigned_quotient.bits_le, phantom: Default::default() };
+                let operands_same_sign = self.msb().is_equal(other.msb());
+                let quotient =
+                    Self::ternary(&operands_same_sign, &signed_quotient, &Self::zero().sub_wrapped(&signed_quotient));
+
+                (quotient, unsigned_remainder.is_zero(), operands_same_sign)
+            }
+            false => {
+                // Note that the remainder check in this call ensures that `other` is not zero.
+                let (quotient, remainder) = self.unsigned_division_via_witness(other);
+                (quotient, remainder.is_zero(), Boolean::constant(true))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use std::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    /// The number of private variables and constraints incurred by `is_multiple_of`, `div_ceil`, and `div_floor`.
+    type Counts = [(u64, u64); 3];
+
+    /// Returns the number of private variables and constraints in the current scope.
+    fn counts_in_scope() -> (u64, u64) {
+        (Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope())
+    }
+
+    /// Ensures the counts of the given operands match the counts of all previous operands.
+    /// Note: For non-constant operands, the circuit must not depend on the values of the operands.
+    fn check_counts(expected: &mut Option<Counts>, candidate: Counts, mode_a: Mode, mode_b: Mode) {
+        if mode_a.is_constant() || mode_b.is_constant() {
+            return;
+        }
+        match expected {
+            Some(expected) => assert_eq!(*expected, candidate, "The constraint counts depend on the operands"),
+            None => *expected = Some(candidate),
+        }
+    }
+
+    fn check_div_rounded<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) -> Counts {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        // Check `is_multiple_of`, which is defined for all operands.
+        let is_multiple_of_counts = Circuit::scope(format!("{name} is_multiple_of"), || {
+            let candidate = a.is_multiple_of(&b);
+            assert_eq!(first.is_multiple_of(&second), candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+            // Ensure constant operands incur no constraints.
+            if mode_a.is_constant() && mode_b.is_constant() {
+                assert_eq!(0, Circuit::num_constraints_in_scope());
+            }
+            counts_in_scope()
+        });
+        Circuit::reset();
+
+        // Check `div_ceil` and `div_floor`, which are undefined for a zero divisor and on overflow.
+        let is_undefined = second.is_zero() || first.checked_div(&second).is_none();
+        let expected_ceil = (!is_undefined).then(|| first.div_ceil(&second));
+        let div_ceil_counts = check_rounding(name, &a, &b, expected_ceil, Integer::div_ceil);
+        let expected_floor = (!is_undefined).then(|| first.div_floor(&second));
+        let div_floor_counts = check_rounding(name, &a, &b, expected_floor, Integer::div_floor);
+
+        [is_multiple_of_counts, div_ceil_counts, div_floor_counts]
+    }
+
+    fn check_rounding<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        a: &Integer<Circuit, I>,
+        b: &Integer<Circuit, I>,
+        expected: Option<console::Integer<<Circuit as Environment>::Network, I>>,
+        operation: fn(&Integer<Circuit, I>, &Integer<Circuit, I>) -> Integer<Circuit, I>,
+    ) -> (u64, u64) {
+        let counts = match expected {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = operation(a, b);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                // Ensure constant operands incur no constraints.
+                if a.is_constant() && b.is_constant() {
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+                counts_in_scope()
+            }),
+            // If both operands are constant, or the divisor is a constant zero, then the operation halts.
+            None if b.is_constant() && (a.is_constant() || b.eject_value().is_zero()) => {
+                check_operation_halts(a, b, operation);
+                (0, 0)
+            }
+            // Otherwise, the circuit is unsatisfiable.
+            None => Circuit::scope(name, || {
+                let _candidate = operation(a, b);
+                assert!(!Circuit::is_satisfied_in_scope());
+                counts_in_scope()
+            }),
+        };
+        Circuit::reset();
+        counts
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+        let mut counts = None;
+
+        for _ in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("DivRounded: {first} / {second}");
+            let candidate = check_div_rounded::<I>(&name, first, second, mode_a, mode_b);
+            check_counts(&mut counts, candidate, mode_a, mode_b);
+
+            let name = format!("DivRounded by Self: {first} / {first}");
+            let candidate = check_div_rounded::<I>(&name, first, first, mode_a, mode_b);
+            check_counts(&mut counts, candidate, mode_a, mode_b);
+
+            let name = format!("DivRounded by Zero: {first} / 0");
+            let candidate = check_div_rounded::<I>(&name, first, console::Integer::zero(), mode_a, mode_b);
+            check_counts(&mut counts, candidate, mode_a, mode_b);
+        }
+
+        // Check the corner cases.
+        let two = console::Integer::one() + console::Integer::one();
+        let mut corner_cases = vec![
+            ("MAX / 2", console::Integer::MAX, two),
+            ("MIN / 2", console::Integer::MIN, two),
+            ("0 / 0", console::Integer::zero(), console::Integer::zero()),
+        ];
+        if I::is_signed() {
+            corner_cases.push(("MAX / -2", console::Integer::MAX, -two));
+            corner_cases.push(("MIN / -1", console::Integer::MIN, -console::Integer::one()));
+            corner_cases.push(("-1 / 2", -console::Integer::one(), two));
+        }
+        for (name, first, second) in corner_cases {
+            let candidate = check_div_rounded::<I>(name, first, second, mode_a, mode_b);
+            check_counts(&mut counts, candidate, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        let mut counts = None;
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("DivRounded: ({first} / {second})");
+                let candidate = check_div_rounded::<I>(&name, first, second, mode_a, mode_b);
+                check_counts(&mut counts, candidate, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, div_rounded);
+    test_integer_binary!(run_test, i16, div_rounded);
+    test_integer_binary!(run_test, i32, div_rounded);
+    test_integer_binary!(run_test, i64, div_rounded);
+    test_integer_binary!(run_test, i128, div_rounded);
+
+    test_integer_binary!(run_test, u8, div_rounded);
+    test_integer_binary!(run_test, u16, div_rounded);
+    test_integer_binary!(run_test, u32, div_rounded);
+    test_integer_binary!(run_test, u64, div_rounded);
+    test_integer_binary!(run_test, u128, div_rounded);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, div_rounded, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, div_rounded, exhaustive);
+}
@@ -21,7 +21,9 @@ impl<E: Environment, I: IntegerType> DivWrapped<Self> for Integer<E, I> {
     fn div_wrapped(&self, other: &Integer<E, I>) -> Self::Output {
         match (self.is_constant(), other.is_constant()) {
             // If `other` is a constant and is zero, then halt.
-            (_, true) if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            (_, true) if other.eject_value().is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, "Attempted to divide by zero.")
+            }
             // If `self` and `other` are constants, and other is not zero, then directly return the value of the division.
             (true, true) => witness!(|self, other| console::Integer::new(self.wrapping_div(&other))),
             // Handle the remaining cases.
@@ -20,7 +20,7 @@ impl<E: Environment, I: IntegerType> Modulo<Self> for Integer<E, I> {
     #[inline]
     fn modulo(&self, other: &Integer<E, I>) -> Self::Output {
         match I::is_signed() {
-            true => E::halt("Attempted to take the modulus of a signed integer."),
+            true => E::halt_with(HaltCode::TypeMismatch, "Attempted to take the modulus of a signed integer."),
             // For unsigned integers, the modulo operation is equivalent to the remainder operation.
             false => self.rem_wrapped(other),
         }
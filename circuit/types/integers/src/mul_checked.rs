@@ -87,7 +87,7 @@ impl<E: Environment, I: IntegerType> MulChecked<Self> for Integer<E, I> {
             // Compute the product and return the new constant.
             match self.eject_value().checked_mul(&other.eject_value()) {
                 Some(value) => Integer::new(Mode::Constant, console::Integer::new(value)),
-                None => E::halt("Integer overflow on multiplication of two constants"),
+                None => E::halt_with(HaltCode::Overflow, "Integer overflow on multiplication of two constants"),
             }
         } else if I::is_signed() {
             // Compute the product of `abs(self)` and `abs(other)`, while checking for an overflow.
@@ -32,7 +32,7 @@ impl<E: Environment, I: IntegerType> Neg for &Integer<E, I> {
             // Note: This addition must be checked as `-Integer::MIN` is an invalid operation.
             true => Integer::one().add_checked(&!self),
             // Note: `halt` is necessary since negation is not defined for unsigned integers.
-            false => E::halt("Attempted to negate an unsigned integer"),
+            false => E::halt_with(HaltCode::TypeMismatch, "Attempted to negate an unsigned integer"),
         }
     }
 }
@@ -42,7 +42,7 @@ impl<E: Environment, I: IntegerType> Metrics<dyn Neg<Output = Integer<E, I>>> fo
 
     fn count(case: &Self::Case) -> Count {
         match I::is_signed() {
-            false => E::halt("Unsigned integers cannot be negated"),
+            false => E::halt_with(HaltCode::TypeMismatch, "Unsigned integers cannot be negated"),
             true => match case {
                 Mode::Constant => Count::is(2 * I::BITS, 0, 0, 0),
                 _ => Count::is(I::BITS, 0, I::BITS + 2, I::BITS + 4),
@@ -115,7 +115,7 @@ mod tests {
     fn assert_unsigned_neg_halts<I: IntegerType + UnwindSafe>(mode: Mode) {
         let candidate = Integer::<Circuit, I>::new(mode, Uniform::rand(&mut TestRng::default()));
         let operation = std::panic::catch_unwind(|| candidate.neg());
-        assert!(operation.is_err());
+        assert_eq!(HaltError::from_panic(operation.unwrap_err()).code(), HaltCode::TypeMismatch);
     }
 
     fn run_exhaustive_test<I: IntegerType + UnwindSafe + Neg<Output = I>>(mode: Mode)
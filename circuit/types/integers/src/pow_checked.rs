@@ -46,7 +46,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> PowChecked<Integer<E, M>> for
             // This cast is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
             match self.eject_value().checked_pow(&other.eject_value().to_u32().unwrap()) {
                 Some(value) => Integer::new(Mode::Constant, console::Integer::new(value)),
-                None => E::halt("Integer overflow on exponentiation of two constants"),
+                None => E::halt_with(HaltCode::Overflow, "Integer overflow on exponentiation of two constants"),
             }
         } else {
             let mut result = Self::one();
@@ -21,7 +21,9 @@ impl<E: Environment, I: IntegerType> RemWrapped<Self> for Integer<E, I> {
     fn rem_wrapped(&self, other: &Integer<E, I>) -> Self::Output {
         match (self.is_constant(), other.is_constant()) {
             // If `other` is a constant and is zero, then halt.
-            (_, true) if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            (_, true) if other.eject_value().is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, "Attempted to divide by zero.")
+            }
             // If `self` and `other` are constants, and other is not zero, then directly return the remainder.
             (true, true) => witness!(|self, other| console::Integer::new(self.wrapping_rem(&other))),
             // Handle the remaining cases.
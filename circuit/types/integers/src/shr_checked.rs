@@ -71,7 +71,9 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrChecked<Integer<E, M>> for
             // This cast is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
             match self.eject_value().checked_shr(rhs.eject_value().to_u32().unwrap()) {
                 Some(value) => Integer::new(Mode::Constant, console::Integer::new(value)),
-                None => E::halt("Constant shifted by constant exceeds the allowed bitwidth."),
+                None => {
+                    E::halt_with(HaltCode::RangeViolation, "Constant shifted by constant exceeds the allowed bitwidth.")
+                }
             }
         } else {
             // Determine the index where the first upper bit of the RHS must be zero.
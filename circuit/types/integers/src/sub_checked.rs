@@ -71,7 +71,7 @@ impl<E: Environment, I: IntegerType> SubChecked<Self> for Integer<E, I> {
             // Compute the difference and return the new constant.
             match self.eject_value().checked_sub(&other.eject_value()) {
                 Some(value) => Integer::constant(console::Integer::new(value)),
-                None => E::halt("Integer underflow on subtraction of two constants"),
+                None => E::halt_with(HaltCode::Overflow, "Integer underflow on subtraction of two constants"),
             }
         } else {
             // Instead of subtracting the bits of `self` and `other` directly, the integers are
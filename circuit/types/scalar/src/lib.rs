@@ -21,6 +21,7 @@ mod helpers;
 pub mod add;
 pub mod compare;
 pub mod equal;
+pub mod sub;
 pub mod ternary;
 
 #[cfg(test)]
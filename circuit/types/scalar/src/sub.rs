@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Sub<Scalar<E>> for Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn sub(self, other: Scalar<E>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment> Sub<Scalar<E>> for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn sub(self, other: Scalar<E>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment> Sub<&Scalar<E>> for Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn sub(self, other: &Scalar<E>) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment> Sub<&Scalar<E>> for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn sub(self, other: &Scalar<E>) -> Self::Output {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<E: Environment> SubAssign<Scalar<E>> for Scalar<E> {
+    fn sub_assign(&mut self, other: Scalar<E>) {
+        *self -= &other;
+    }
+}
+
+impl<E: Environment> SubAssign<&Scalar<E>> for Scalar<E> {
+    fn sub_assign(&mut self, other: &Scalar<E>) {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the difference and set the new constant in `self`.
+            *self = witness!(|self, other| self - other);
+        } else {
+            // Initialize the scalar field modulus as a constant base field variable.
+            //
+            // Note: We are reconstituting the scalar field into a base field here in order to
+            // compute the difference on the base field. This is safe as the scalar field modulus
+            // is less that the base field modulus, and thus will always fit in a base field element.
+            let modulus =
+                Field::constant(match console::FromBits::from_bits_le(&E::ScalarField::modulus().to_bits_le()) {
+                    Ok(modulus) => modulus,
+                    Err(error) => E::halt(format!("Failed to retrieve the scalar modulus as bytes: {error}")),
+                });
+
+            // Instead of subtracting the bits of `self` and `other` directly, the scalars are
+            // converted into a field elements, and the difference is offset by the scalar modulus.
+            // Note: As both scalars are less than the modulus, `self - other + modulus` lies in
+            // the range [1, 2 * modulus - 1], and thus never wraps on the base field.
+            let difference = self.to_field() - other.to_field() + &modulus;
+
+            // Extract the scalar field bits from the field element, with a carry bit.
+            let bits_le = difference.to_lower_bits_le(E::ScalarField::size_in_bits() + 1);
+
+            // Recover the sanitized (truncated) difference on the base field.
+            let difference = Field::from_bits_le(&bits_le);
+
+            // Determine the wrapping difference, by subtracting the modulus, if `difference` >= `modulus`.
+            let wrapping_difference =
+                Ternary::ternary(&difference.is_less_than(&modulus), &difference, &(&difference - &modulus));
+
+            // Retrieve the bits of the wrapping difference.
+            let bits_le = wrapping_difference.to_lower_bits_le(console::Scalar::<E::Network>::size_in_bits());
+
+            // Set the difference of `self` and `other`, in `self`.
+            *self = Scalar { field: wrapping_difference, bits_le: OnceCell::with_value(bits_le) };
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn Sub<Scalar<E>, Output = Scalar<E>>> for Scalar<E> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(1, 0, 0, 0),
+            (_, _) => Count::is(1, 0, 755, 757),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Sub<Scalar<E>, Output = Scalar<E>>> for Scalar<E> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    #[rustfmt::skip]
+    fn check_sub(
+        name: &str,
+        first: console::Scalar<<Circuit as Environment>::Network>,
+        second: console::Scalar<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Scalar::<Circuit>::new(mode_a, first);
+        let b = Scalar::<Circuit>::new(mode_b, second);
+        let case = format!("({} - {})", a.eject_value(), b.eject_value());
+        let expected = first - second;
+
+        Circuit::scope(name, || {
+            let candidate = a - b;
+            assert_eq!(expected, candidate.eject_value(), "{case}");
+            assert_count!(Sub(Scalar, Scalar) => Scalar, &(mode_a, mode_b));
+            assert_output_mode!(Sub(Scalar, Scalar) => Scalar, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    #[rustfmt::skip]
+    fn run_test(
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Sub: {mode_a} - {mode_b} {i}");
+            check_sub(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Sub: {mode_a} - {mode_b} {i} (reverse)");
+            check_sub(&name, second, first, mode_a, mode_b);
+        }
+
+        // Check the boundary cases.
+        let zero = console::Scalar::zero();
+        let one = console::Scalar::one();
+        let modulus_minus_one = -one;
+        let boundaries = [(zero, zero), (zero, one), (one, zero), (zero, modulus_minus_one), (modulus_minus_one, one)];
+        for (first, second) in boundaries {
+            let name = format!("Sub: {mode_a} - {mode_b} ({first} - {second})");
+            check_sub(&name, first, second, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_scalar_constant_minus_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_scalar_constant_minus_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_scalar_constant_minus_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_scalar_public_minus_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_scalar_private_minus_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_scalar_public_minus_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_scalar_public_minus_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_scalar_private_minus_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_scalar_private_minus_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
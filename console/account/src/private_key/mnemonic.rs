@@ -0,0 +1,326 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use sha2::{Digest, Sha256};
+
+/// The BIP39 English wordlist, with one word per line in ascending order.
+static BIP39_WORDLIST: &str = include_str!("resources/bip39_english.txt");
+
+static ACCOUNT_MNEMONIC_PASSPHRASE_DOMAIN: &str = "AleoAccountMnemonicPassphrase0";
+static ACCOUNT_CHILD_SEED_DOMAIN: &str = "AleoAccountChildSeed0";
+
+/// The number of words in a mnemonic.
+const MNEMONIC_NUM_WORDS: usize = 24;
+/// The number of bits encoded by each word in a mnemonic.
+const MNEMONIC_BITS_PER_WORD: usize = 11;
+/// The number of bytes of entropy encoded by a mnemonic.
+const MNEMONIC_ENTROPY_SIZE_IN_BYTES: usize = 32;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the account private key from a 24-word BIP39 mnemonic and an optional passphrase.
+    ///
+    /// The 256 bits of entropy in the mnemonic are read as the little-endian account seed.
+    /// If the passphrase is non-empty, the account seed is the domain-separated hash of
+    /// the entropy and the passphrase instead.
+    ///
+    /// As the account seed is a field element, a mnemonic whose entropy is not less than the field
+    /// modulus is rejected. In particular, most mnemonics generated by other BIP39 wallets are rejected.
+    ///
+    /// Note: This follows the BIP39 encoding and checksum, but not its PBKDF2 seed stretching,
+    /// so that `to_mnemonic` can recover the mnemonic of any private key.
+    ///
+    /// Warning: The passphrase is hashed once with Poseidon, without any key stretching. Guessing
+    /// a passphrase is therefore cheap, and the passphrase does not protect a leaked mnemonic.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self> {
+        // Retrieve the index of each word in the wordlist.
+        let indices = phrase
+            .split_whitespace()
+            .map(|word| {
+                BIP39_WORDLIST.lines().position(|entry| entry == word).ok_or_else(|| anyhow!("Invalid word '{word}'"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(
+            indices.len() == MNEMONIC_NUM_WORDS,
+            "Invalid mnemonic length: found {} words, expected {MNEMONIC_NUM_WORDS}",
+            indices.len()
+        );
+
+        // Unpack the indices into the entropy, followed by the checksum byte.
+        let bits = indices
+            .iter()
+            .flat_map(|index| (0..MNEMONIC_BITS_PER_WORD).rev().map(move |i| (index >> i) & 1 == 1))
+            .collect::<Vec<_>>();
+        let bytes = bits.chunks(8).map(|byte| byte.iter().fold(0u8, |acc, bit| (acc << 1) | u8::from(*bit)));
+        let bytes = bytes.collect::<Vec<_>>();
+        let (entropy, checksum) = bytes.split_at(MNEMONIC_ENTROPY_SIZE_IN_BYTES);
+        // Ensure the checksum is correct.
+        ensure!(checksum == [Sha256::digest(entropy)[0]], "Invalid mnemonic checksum");
+
+        // Compute the account seed.
+        let entropy = Field::<N>::from_bytes_le(entropy)
+            .map_err(|_| anyhow!("Invalid mnemonic: its entropy is not less than the field modulus"))?;
+        let seed = match passphrase.is_empty() {
+            true => entropy,
+            false => {
+                // Construct the passphrase domain separator.
+                let domain = Field::new_domain_separator(ACCOUNT_MNEMONIC_PASSPHRASE_DOMAIN);
+                // Pack the passphrase into field elements, prefixed with its length in bytes.
+                let mut preimage = vec![domain, entropy, Field::from_u64(u64::try_from(passphrase.len())?)];
+                preimage.extend(
+                    passphrase
+                        .as_bytes()
+                        .chunks(Field::<N>::SIZE_IN_DATA_BITS / 8)
+                        .map(|chunk| Field::new(N::Field::from_bytes_le_mod_order(chunk))),
+                );
+                N::hash_psd2(&preimage)?
+            }
+        };
+        Self::try_from(seed)
+    }
+
+    /// Returns the 24-word BIP39 mnemonic of the account seed.
+    ///
+    /// Note: For a private key that was recovered with a passphrase, this is the mnemonic
+    /// of its derived account seed, which is recovered without a passphrase.
+    pub fn to_mnemonic(&self) -> Result<String> {
+        // Use the little-endian account seed as the entropy.
+        let entropy = self.seed.to_bytes_le()?;
+        ensure!(entropy.len() == MNEMONIC_ENTROPY_SIZE_IN_BYTES, "Invalid account seed size for a mnemonic");
+        // Append the checksum byte.
+        let checksum = Sha256::digest(&entropy)[0];
+        let bits = entropy
+            .iter()
+            .chain([&checksum])
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .collect::<Vec<_>>();
+
+        // Look up the word for each index.
+        let words = BIP39_WORDLIST.lines().collect::<Vec<_>>();
+        let phrase = bits
+            .chunks(MNEMONIC_BITS_PER_WORD)
+            .map(|index| words[index.iter().fold(0usize, |acc, bit| (acc << 1) | usize::from(*bit))])
+            .collect::<Vec<_>>();
+        Ok(phrase.join(" "))
+    }
+
+    /// Returns the child private key at the given index.
+    ///
+    /// The child account seed is the Poseidon hash of a domain separator, the account seed, and the index.
+    /// As the derivation requires the account seed, it is hardened, i.e. a child cannot be derived from
+    /// the view key or the address, and a child does not reveal its parent.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Construct the child seed domain separator.
+        let domain = Field::new_domain_separator(ACCOUNT_CHILD_SEED_DOMAIN);
+        // Derive the child private key.
+        Self::try_from(N::hash_psd2(&[domain, self.seed, Field::from_u32(index)])?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    use std::collections::HashSet;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    /// The private keys and their mnemonics, for the seeds `0`, `1`, and `p - 1`.
+    const VECTORS: [(&str, &str); 3] = [
+        (
+            "APrivateKey1zkp1rEPW7jqSRWMCc8ASnN4JLAJrs6Hm2ebXQY9hUpXmAJ3",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+             abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+        ),
+        (
+            "APrivateKey1zkp1v8o9cTvLG14cJF8xHdWCFuY7sf4TykW7Gr3Cg8XUxxP",
+            "absurd abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+             abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon ability",
+        ),
+        (
+            "APrivateKey1zkp1rEPW7k8kLKny9ibJ5pAW87BDchq1ye63w2JXdiVg4KT",
+            "abandon abandon abandon absurd absurd lunar advice abandon special victory pride rare \
+             brand brick idea situate refuse actor power float planet crazy proof fiscal",
+        ),
+    ];
+
+    /// The private keys recovered from the mnemonic of the seed `1`, for each passphrase repeated the given times.
+    /// Note: The second passphrase spans several field elements.
+    const PASSPHRASE_VECTORS: [(&str, usize, &str); 2] = [
+        ("passphrase", 1, "APrivateKey1zkp3jXQV2wsahMiwt3vruzov4SB6sn5mJPXvheBvPv9518q"),
+        ("correct horse battery staple ", 10, "APrivateKey1zkpAarGAJMYhe7Vm6SHfdnDvznhNBcAPaXcN6kidn6Vu4BT"),
+    ];
+
+    /// The children of the private key with the seed `1`, for the indices `0`, `1`, and `u32::MAX`.
+    const CHILD_VECTORS: [(u32, &str); 3] = [
+        (0, "APrivateKey1zkpCYHPrXda4MdDmSPqRme2vRfmVQki72yUBoJCce23Fz6k"),
+        (1, "APrivateKey1zkp6YAXwnGvg1Pgfe63buNwTD6kNqbN67DBPfKQ6ZbP7naf"),
+        (u32::MAX, "APrivateKey1zkpBB1V9BSqhtU4F1DRB57M7zG2qsgU92D48paojySyAeL8"),
+    ];
+
+    #[test]
+    fn test_wordlist() {
+        let words = BIP39_WORDLIST.lines().collect::<Vec<_>>();
+        assert_eq!(words.len(), 2048);
+        // Ensure the words are sorted, and are unique in their first four letters.
+        assert!(words.windows(2).all(|pair| pair[0] < pair[1]));
+        let prefixes = words.iter().map(|word| word.chars().take(4).collect::<String>()).collect::<HashSet<_>>();
+        assert_eq!(prefixes.len(), 2048);
+    }
+
+    #[test]
+    fn test_mnemonic_vectors() -> Result<()> {
+        for (expected_private_key, expected_mnemonic) in VECTORS {
+            let private_key = PrivateKey::<CurrentNetwork>::from_str(expected_private_key)?;
+            assert_eq!(expected_mnemonic, private_key.to_mnemonic()?);
+            assert_eq!(private_key, PrivateKey::from_mnemonic(expected_mnemonic, "")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_exceeds_modulus() {
+        // The entropy of these mnemonics is the field modulus, and all ones, respectively.
+        let mnemonics = [
+            "absurd abandon abandon absurd absurd lunar advice abandon special victory pride rare \
+             brand brick idea situate refuse actor power float planet crazy proof flame"
+                .to_string(),
+            format!("{} vote", ["zoo"; 23].join(" ")),
+        ];
+        for mnemonic in mnemonics {
+            // Ensure the mnemonic is rejected, rather than reduced modulo the field order.
+            let error = PrivateKey::<CurrentNetwork>::from_mnemonic(&mnemonic, "").unwrap_err();
+            assert_eq!(error.to_string(), "Invalid mnemonic: its entropy is not less than the field modulus");
+            // Ensure the mnemonic is rejected with a passphrase as well.
+            assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&mnemonic, "passphrase").is_err());
+        }
+    }
+
+    #[test]
+    fn test_mnemonic_round_trip() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let mnemonic = private_key.to_mnemonic()?;
+            assert_eq!(mnemonic.split(' ').count(), MNEMONIC_NUM_WORDS);
+            assert_eq!(private_key, PrivateKey::from_mnemonic(&mnemonic, "")?);
+            // Ensure surrounding whitespace is ignored.
+            assert_eq!(private_key, PrivateKey::from_mnemonic(&format!("  {}\n", mnemonic.replace(' ', "\t")), "")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_passphrase() -> Result<()> {
+        let (_, mnemonic) = VECTORS[1];
+
+        // Ensure the passphrase derives the expected private keys.
+        for (passphrase, repetitions, expected_private_key) in PASSPHRASE_VECTORS {
+            let private_key = PrivateKey::<CurrentNetwork>::from_mnemonic(mnemonic, &passphrase.repeat(repetitions))?;
+            assert_eq!(expected_private_key, private_key.to_string());
+        }
+
+        let private_key = PrivateKey::<CurrentNetwork>::from_mnemonic(mnemonic, "")?;
+        let with_passphrase = PrivateKey::<CurrentNetwork>::from_mnemonic(mnemonic, "passphrase")?;
+        // Ensure the passphrase derives a different private key.
+        assert_ne!(private_key, with_passphrase);
+        // Ensure different passphrases derive different private keys.
+        assert_ne!(with_passphrase, PrivateKey::from_mnemonic(mnemonic, "passphrase\0")?);
+        assert_ne!(with_passphrase, PrivateKey::from_mnemonic(mnemonic, "Passphrase")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_invalid_checksum() {
+        // The checksum of this mnemonic must be 0x66, i.e. the last word must be 'art'.
+        let mnemonic = ["abandon"; 24].join(" ");
+        let error = PrivateKey::<CurrentNetwork>::from_mnemonic(&mnemonic, "").unwrap_err();
+        assert_eq!(error.to_string(), "Invalid mnemonic checksum");
+
+        // Ensure swapping two words invalidates the checksum.
+        let (_, mnemonic) = VECTORS[2];
+        let mut words = mnemonic.split(' ').collect::<Vec<_>>();
+        words.swap(0, 3);
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&words.join(" "), "").is_err());
+    }
+
+    #[test]
+    fn test_mnemonic_invalid_word() {
+        let (_, mnemonic) = VECTORS[0];
+
+        // Ensure a word outside of the wordlist is rejected.
+        let error = PrivateKey::<CurrentNetwork>::from_mnemonic(&mnemonic.replace("art", "aleo"), "").unwrap_err();
+        assert_eq!(error.to_string(), "Invalid word 'aleo'");
+        // Ensure the words are case-sensitive.
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&mnemonic.replace("art", "Art"), "").is_err());
+        // Ensure a truncated word is rejected.
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&mnemonic.replace("art", "ar"), "").is_err());
+    }
+
+    #[test]
+    fn test_mnemonic_invalid_length() {
+        // Ensure a 12-word mnemonic is rejected.
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(mnemonic, "").is_err());
+        // Ensure an empty mnemonic is rejected.
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic("", "").is_err());
+        // Ensure a mnemonic with an extra word is rejected.
+        let (_, mnemonic) = VECTORS[0];
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&format!("{mnemonic} abandon"), "").is_err());
+    }
+
+    #[test]
+    fn test_derive_child_vectors() -> Result<()> {
+        let (parent, _) = VECTORS[1];
+        let parent = PrivateKey::<CurrentNetwork>::from_str(parent)?;
+
+        // Ensure the children are derived as expected.
+        for (index, expected_child) in CHILD_VECTORS {
+            assert_eq!(expected_child, parent.derive_child(index)?.to_string());
+        }
+        // Ensure the grandchild at index `0` of the child at index `0` is derived as expected.
+        let grandchild = parent.derive_child(0)?.derive_child(0)?;
+        assert_eq!("APrivateKey1zkpHnopnfDWa17SrNqvcDTSpmg9PTR6dzGcWiQz4DyTtiKN", grandchild.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+        let mut children = HashSet::new();
+        for index in 0..ITERATIONS {
+            let index = u32::try_from(index)?;
+            let child = private_key.derive_child(index)?;
+            // Ensure the derivation is deterministic.
+            assert_eq!(child, private_key.derive_child(index)?);
+            // Ensure the child differs from its parent, and from its siblings.
+            assert_ne!(child, private_key);
+            assert!(children.insert(child));
+        }
+
+        // Ensure a grandchild differs from the child at the same index.
+        let child = private_key.derive_child(0)?;
+        assert_ne!(child.derive_child(0)?, child);
+        assert!(!children.contains(&child.derive_child(0)?));
+        Ok(())
+    }
+}
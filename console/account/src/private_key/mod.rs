@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod bytes;
+mod mnemonic;
 mod serialize;
 mod string;
 mod try_from;
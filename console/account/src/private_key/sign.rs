@@ -26,6 +26,11 @@ impl<N: Network> PrivateKey<N> {
         Signature::sign_bytes(self, message, rng)
     }
 
+    /// Returns a signature for the given off-chain message (as bytes) using the private key.
+    pub fn sign_message<R: Rng + CryptoRng>(&self, message: &[u8], rng: &mut R) -> Result<Signature<N>> {
+        Signature::sign_message(self, message, rng)
+    }
+
     /// Returns a signature for the given message (as bits) using the private key.
     pub fn sign_bits<R: Rng + CryptoRng>(&self, message: &[bool], rng: &mut R) -> Result<Signature<N>> {
         Signature::sign_bits(self, message, rng)
@@ -88,6 +93,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sign_and_verify_message() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the signature is valid for the message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = private_key.sign_message(&message, rng)?;
+            assert!(signature.verify_message(&address, &message));
+
+            // Check that the signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            if message != failure_message {
+                assert!(!signature.verify_message(&address, &failure_message));
+            }
+            // Check that the signature is invalid for a message with a trailing zero byte.
+            let extended_message = [message.as_slice(), &[0u8]].concat();
+            assert!(!signature.verify_message(&address, &extended_message));
+
+            // Check that the signature is invalid for an incorrect address.
+            let failure_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!signature.verify_message(&failure_address, &message));
+
+            // Check that message signatures and byte signatures are not interchangeable.
+            assert!(!signature.verify_bytes(&address, &message));
+            assert!(!private_key.sign_bytes(&message, rng)?.verify_message(&address, &message));
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_sign_and_verify_bits() -> Result<()> {
         let rng = &mut TestRng::default();
@@ -35,6 +35,8 @@ use crate::address::Address;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Scalar};
 
+static SIGNED_MESSAGE_DOMAIN: &str = "AleoSignedMessage0";
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
     /// The verifier challenge to check against.
@@ -79,6 +81,22 @@ impl<N: Network> Signature<N> {
     pub fn to_address(&self) -> Address<N> {
         self.compute_key.to_address()
     }
+
+    /// Returns the domain separator for signed messages, which prefixes their challenge preimage,
+    /// so that a message signature is never valid for any other signed payload.
+    fn message_domain() -> Field<N> {
+        Field::new_domain_separator(SIGNED_MESSAGE_DOMAIN)
+    }
+
+    /// Returns the given message (as bytes) as field elements, which are the length of the message
+    /// in bytes, followed by the message bits packed into field elements.
+    fn message_to_fields(message: &[u8]) -> Result<Vec<Field<N>>> {
+        // Pack the bits into field elements.
+        let bits = message.to_bits_le();
+        let fields = bits.chunks(Field::<N>::size_in_data_bits()).map(Field::from_bits_le);
+        // Prefix the length, so that trailing zero bytes are not ambiguous.
+        [Ok(Field::from_u64(u64::try_from(message.len())?))].into_iter().chain(fields).collect()
+    }
 }
 
 impl<N: Network> TypeName for Signature<N> {
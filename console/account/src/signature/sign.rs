@@ -19,6 +19,16 @@ impl<N: Network> Signature<N> {
     ///     challenge := HashToScalar(nonce * G, pk_sig, pr_sig, address, message)
     ///     response := nonce - challenge * private_key.sk_sig()
     pub fn sign<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, message: &[Field<N>], rng: &mut R) -> Result<Self> {
+        Self::sign_with_domain(private_key, None, message, rng)
+    }
+
+    /// Returns a signature for the given message, where the challenge preimage is prefixed with the domain, if any.
+    fn sign_with_domain<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        domain: Option<Field<N>>,
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<Self> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
             bail!("Cannot sign the message: the message exceeds maximum allowed size")
@@ -39,8 +49,9 @@ impl<N: Network> Signature<N> {
         // Derive the address from the compute key.
         let address = Address::try_from(compute_key)?;
 
-        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
-        let mut preimage = Vec::with_capacity(4 + message.len());
+        // Construct the hash input as (domain, r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(5 + message.len());
+        preimage.extend(domain);
         preimage.extend([g_r, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
         preimage.extend(message);
 
@@ -63,6 +74,19 @@ impl<N: Network> Signature<N> {
         Self::sign_bits(private_key, &message.to_bits_le(), rng)
     }
 
+    /// Returns a signature for the given off-chain message (as bytes) using the private key.
+    ///
+    /// Note: Unlike `sign_bytes`, the challenge is domain-separated, so the signature must be verified
+    /// with `verify_message`, and no signature over any other payload verifies as a message signature.
+    pub fn sign_message<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Signature<N>> {
+        // Sign the message under the signed message domain.
+        Self::sign_with_domain(private_key, Some(Self::message_domain()), &Self::message_to_fields(message)?, rng)
+    }
+
     /// Returns a signature for the given message (as bits) using the private key.
     pub fn sign_bits<R: Rng + CryptoRng>(
         private_key: &PrivateKey<N>,
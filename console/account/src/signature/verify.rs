@@ -18,6 +18,11 @@ impl<N: Network> Signature<N> {
     /// Verifies (challenge == challenge') && (address == address') where:
     ///     challenge' := HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message)
     pub fn verify(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
+        self.verify_with_domain(address, None, message)
+    }
+
+    /// Verifies a signature for the given message, where the challenge preimage is prefixed with the domain, if any.
+    fn verify_with_domain(&self, address: &Address<N>, domain: Option<Field<N>>, message: &[Field<N>]) -> bool {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
             eprintln!("Cannot sign the signature: the signed message exceeds maximum allowed size");
@@ -32,8 +37,9 @@ impl<N: Network> Signature<N> {
         // Compute `g_r` := (response * G) + (challenge * pk_sig).
         let g_r = N::g_scalar_multiply(&self.response) + (pk_sig * self.challenge);
 
-        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
-        let mut preimage = Vec::with_capacity(4 + message.len());
+        // Construct the hash input as (domain, r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(5 + message.len());
+        preimage.extend(domain);
         preimage.extend([g_r, pk_sig, pr_sig, **address].map(|point| point.to_x_coordinate()));
         preimage.extend(message);
 
@@ -63,6 +69,19 @@ impl<N: Network> Signature<N> {
         self.verify_bits(address, &message.to_bits_le())
     }
 
+    /// Verifies a signature for the given address and off-chain message (as bytes),
+    /// which was signed with `sign_message`.
+    pub fn verify_message(&self, address: &Address<N>, message: &[u8]) -> bool {
+        // Verify the message under the signed message domain.
+        match Self::message_to_fields(message) {
+            Ok(fields) => self.verify_with_domain(address, Some(Self::message_domain()), &fields),
+            Err(error) => {
+                eprintln!("Failed to verify signature: {error}");
+                false
+            }
+        }
+    }
+
     /// Verifies a signature for the given address and message (as bits).
     pub fn verify_bits(&self, address: &Address<N>, message: &[bool]) -> bool {
         // Pack the bits into field elements.
@@ -154,4 +173,94 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_message_signature_is_domain_separated() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Construct the bits that pack into the fields (domain, length, message).
+            let message: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let fields = [vec![Signature::<CurrentNetwork>::message_domain()], Signature::message_to_fields(&message)?];
+            let fields = fields.concat();
+            let bits = fields
+                .iter()
+                .flat_map(|field| field.to_bits_le().into_iter().take(Field::<CurrentNetwork>::size_in_data_bits()))
+                .collect::<Vec<_>>();
+            // Ensure the colliding bits are a valid `sign_bits` payload, which packs into exactly these fields.
+            let signature = Signature::sign_bits(&private_key, &bits, rng)?;
+            assert!(signature.verify_bits(&address, &bits));
+            assert!(signature.verify(&address, &fields));
+
+            // Ensure the colliding signature is not a valid message signature.
+            assert!(!signature.verify_message(&address, &message));
+            // Ensure a message signature is not a valid signature for its fields, with or without the domain.
+            let signature = Signature::sign_message(&private_key, &message, rng)?;
+            assert!(!signature.verify(&address, &fields));
+            assert!(!signature.verify(&address, &Signature::message_to_fields(&message)?));
+            assert!(signature.verify_message(&address, &message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_message_is_deterministic_for_a_fixed_rng() -> Result<()> {
+        let sign = |seed| -> Result<Signature<CurrentNetwork>> {
+            let rng = &mut TestRng::fixed(seed);
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            Signature::sign_message(&private_key, b"Hello, Aleo!", rng)
+        };
+        // Ensure the signature only depends on the RNG seed.
+        assert_eq!(sign(1)?, sign(1)?);
+        assert_ne!(sign(1)?, sign(2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_message_fixed_vector() -> Result<()> {
+        // The expected private key, sampled from `TestRng::fixed(123456789)`.
+        const PRIVATE_KEY: &str = "APrivateKey1zkpGXMfqLPbrcrgJzPbv8Pf69BydvsoqwxHpVhakqDgMjRf";
+        // The expected address of the private key.
+        const ADDRESS: &str = "aleo12tksdptp7hvxly8tkm3um08fvf53qpehsgdgqfvy9pe3sewcq5ysjg5myy";
+        // The expected message, sampled after the private key.
+        const MESSAGE: [u8; 32] = [
+            29, 104, 154, 75, 249, 7, 203, 160, 148, 180, 79, 79, 242, 99, 131, 172, 47, 138, 169, 226, 12, 46, 17, 35,
+            225, 204, 19, 113, 204, 157, 115, 148,
+        ];
+        // The expected signature bytes, as (challenge, response, pk_sig, pr_sig), with the nonce sampled last.
+        const SIGNATURE_BYTES: [u8; 128] = [
+            33, 238, 189, 178, 193, 108, 134, 97, 107, 202, 229, 248, 43, 56, 46, 149, 45, 128, 250, 152, 159, 231, 116,
+            165, 227, 195, 12, 11, 71, 252, 134, 2, 37, 172, 113, 64, 73, 192, 171, 221, 240, 91, 70, 60, 29, 197, 68,
+            54, 78, 165, 108, 152, 185, 190, 46, 82, 13, 22, 119, 48, 255, 100, 51, 3, 26, 107, 222, 114, 142, 252, 202,
+            109, 163, 36, 109, 99, 180, 1, 177, 212, 164, 111, 57, 4, 123, 147, 131, 95, 215, 28, 191, 152, 27, 180, 35,
+            8, 10, 178, 51, 133, 56, 221, 93, 141, 2, 231, 202, 213, 166, 250, 178, 214, 175, 52, 46, 86, 186, 251, 171,
+            173, 196, 4, 188, 36, 158, 27, 138, 14,
+        ];
+        // The expected signature, as a bech32m string of the signature bytes.
+        const SIGNATURE: &str = "sign1y8htmvkpdjrxz672uhuzkwpwj5kcp75cnlnhff0rcvxqk3luscpzttr3gpyup27a7pd5v0qac4zrvn49djvtn03w2gx3vaeslajrxqc6d0089rhuefk6xfrdvw6qrvw553hnjprmjwp4l4cuh7vphdprpq9tyvu98rw4mrgzul9dtfh6ktt27dpw26a0h2adcsztcfy7rw9qu803wm8";
+
+        let rng = &mut TestRng::fixed(123456789);
+
+        // Sample the private key, the message, and the signature, in this order.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let message: Vec<u8> = (0..MESSAGE.len()).map(|_| Uniform::rand(rng)).collect();
+        let signature = Signature::sign_message(&private_key, &message, rng)?;
+
+        // Check the vector byte for byte, so that other implementations can interoperate.
+        assert_eq!(PRIVATE_KEY, private_key.to_string());
+        assert_eq!(ADDRESS, address.to_string());
+        assert_eq!(MESSAGE, message[..]);
+        assert_eq!(SIGNATURE_BYTES, signature.to_bytes_le()?[..]);
+        assert_eq!(SIGNATURE, signature.to_string());
+
+        // Check the signature is valid for the message, and only as a message signature.
+        assert!(signature.verify_message(&address, &MESSAGE));
+        assert!(!signature.verify_bytes(&address, &MESSAGE));
+        Ok(())
+    }
 }
@@ -18,13 +18,33 @@ use snarkvm_console_types::prelude::*;
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The rule used to fill the positions of a Merkle tree that are not occupied by a leaf.
+///
+/// Given `n` leaves, the leaf hashes are padded with the empty hash up to `n.next_power_of_two()`,
+/// and the internal nodes of this subtree are computed with `hash_children` as usual.
+/// Each remaining level up to `DEPTH` is then computed as `hash_children(root, empty_hash)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PaddingMode {
+    /// Padding nodes are set to `hash_children(0, 0)`.
+    EmptyHash,
+    /// Padding nodes are set to the zero element.
+    Zero,
+}
+
 /// A trait for a Merkle path hash function.
 pub trait PathHash: Clone + Send + Sync {
     type Hash: FieldTrait;
 
-    /// Returns the empty hash.
+    /// The padding rule for the Merkle tree.
+    /// Note: This is consensus-critical, as it determines the Merkle root of any tree that is not full.
+    const PADDING_MODE: PaddingMode = PaddingMode::EmptyHash;
+
+    /// Returns the empty hash, as defined by the padding mode.
     fn hash_empty(&self) -> Result<Self::Hash> {
-        self.hash_children(&Self::Hash::zero(), &Self::Hash::zero())
+        match Self::PADDING_MODE {
+            PaddingMode::EmptyHash => self.hash_children(&Self::Hash::zero(), &Self::Hash::zero()),
+            PaddingMode::Zero => Ok(Self::Hash::zero()),
+        }
     }
 
     /// Returns the hash of the given child nodes.
@@ -15,6 +15,9 @@
 mod helpers;
 pub use helpers::*;
 
+mod multi_path;
+pub use multi_path::*;
+
 mod path;
 pub use path::*;
 
@@ -119,6 +122,81 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         })
     }
 
+    #[inline]
+    /// Initializes a Merkle tree from its internal hashes (i.e. the output of `tree()`) and number of leaves.
+    ///
+    /// The internal nodes and the root are recomputed from the given leaf hashes, and must match the given nodes,
+    /// however the leaves are not rehashed. Callers must check the leaf hashes (see `check_leaves`).
+    pub fn from_tree(leaf_hasher: &LH, path_hasher: &PH, tree: Vec<PH::Hash>, number_of_leaves: usize) -> Result<Self> {
+        let timer = timer!("MerkleTree::from_tree");
+
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the maximum number of leaves.
+        let max_leaves = match number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves,
+            None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
+        };
+        // Compute the number of nodes.
+        let num_nodes = max_leaves - 1;
+        // Compute the tree size as the maximum number of leaves plus the number of nodes.
+        let tree_size = max_leaves + num_nodes;
+        // Ensure the tree is of the expected size.
+        ensure!(tree.len() == tree_size, "Expected a Merkle tree of size {tree_size}, found {}", tree.len());
+        // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
+        let tree_depth = tree_depth::<DEPTH>(tree_size)?;
+        // Compute the number of padded levels.
+        let padding_depth = DEPTH - tree_depth;
+
+        // Compute the empty hash.
+        let empty_hash = path_hasher.hash_empty()?;
+        // Ensure the leaves after the last leaf are empty.
+        ensure!(
+            tree[num_nodes + number_of_leaves..].iter().all(|hash| *hash == empty_hash),
+            "Expected the padding leaves of the Merkle tree to be empty"
+        );
+
+        // Ensure the hashes for each level match, iterating from the penultimate level to the root level.
+        let mut start_index = num_nodes;
+        // Compute the start index of the current level.
+        while let Some(start) = parent(start_index) {
+            // Compute the end index of the current level.
+            let end = left_child(start);
+            // Construct the children for each node in the current level.
+            let tuples = (start..end).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
+            // Ensure the hashes for each node in the current level match.
+            ensure!(
+                path_hasher.hash_all_children(&tuples)? == tree[start..end],
+                "Expected the internal hashes of the Merkle tree to match its leaf hashes"
+            );
+            // Update the start index for the next level.
+            start_index = start;
+        }
+        lap!(timer, "Checked {} levels", tree_depth);
+
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        let mut root_hash = tree[0];
+        for _ in 0..padding_depth {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = path_hasher.hash_children(&root_hash, &empty_hash)?;
+        }
+        lap!(timer, "Hashed {} padding levels", padding_depth);
+
+        finish!(timer);
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: root_hash,
+            tree,
+            empty_hash,
+            number_of_leaves,
+        })
+    }
+
     #[inline]
     /// Returns a new Merkle tree with the given new leaves appended to it.
     pub fn prepare_append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
@@ -570,11 +648,91 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         MerklePath::try_from((U64::new(leaf_index as u64), path))
     }
 
+    #[inline]
+    /// Returns the Merkle multi-path for the given leaf indices and leaves.
+    /// The leaf indices must be unique and in ascending order.
+    pub fn prove_many(&self, leaves: &[(usize, LH::Leaf)]) -> Result<MerkleMultiPath<E, DEPTH>> {
+        // Ensure the leaf indices are unique and in ascending order.
+        ensure!(
+            leaves.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            "The given Merkle leaf indices must be unique and in ascending order"
+        );
+
+        // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
+        let start = match self.number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves - 1,
+            None => bail!("Integer overflow when computing the Merkle tree start index"),
+        };
+
+        // Initialize the current level with the leaf indices.
+        let mut level = Vec::with_capacity(leaves.len());
+        for (leaf_index, leaf) in leaves {
+            // Ensure the leaf index is valid.
+            ensure!(*leaf_index < self.number_of_leaves, "The given Merkle leaf index is out of bounds");
+            // Ensure the leaf hash matches the one in the tree.
+            ensure!(
+                self.tree[start + leaf_index] == self.leaf_hasher.hash_leaf(leaf)?,
+                "The given Merkle leaf does not match the one in the Merkle tree"
+            );
+            level.push(*leaf_index);
+        }
+        let leaf_indices = level.iter().map(|leaf_index| U64::new(*leaf_index as u64)).collect();
+
+        // Initialize a vector for the Merkle multi-path.
+        let mut siblings = Vec::new();
+        // Initialize the number of nodes in the current level of the Merkle tree.
+        let mut num_nodes = start + 1;
+
+        // Iterate from the leaf level to the root level, storing the sibling hashes not computed from the leaves.
+        for _ in 0..DEPTH {
+            let mut parents = Vec::with_capacity(level.len());
+            let mut i = 0;
+            while i < level.len() {
+                let index = level[i];
+                // If the sibling is the next node on this level, it is computed from the leaves.
+                if index % 2 == 0 && level.get(i + 1) == Some(&(index + 1)) {
+                    i += 2;
+                } else {
+                    // Append the sibling hash to the path, or the empty hash if the sibling is in the padding.
+                    let sibling = index ^ 1;
+                    match sibling < num_nodes {
+                        true => siblings.push(self.tree[num_nodes - 1 + sibling]),
+                        false => siblings.push(self.empty_hash),
+                    }
+                    i += 1;
+                }
+                parents.push(index / 2);
+            }
+            level = parents;
+            num_nodes = (num_nodes / 2).max(1);
+        }
+
+        // Return the Merkle multi-path.
+        MerkleMultiPath::try_from((leaf_indices, siblings))
+    }
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    /// Note: Paths that target a padding position (i.e. a leaf index that is not occupied) are rejected.
     pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
+        // Ensure the leaf index corresponds to a real leaf.
+        if (*path.leaf_index() as u128) >= (self.number_of_leaves() as u128) {
+            eprintln!("Found a Merkle path for a padding position");
+            return false;
+        }
         path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
     }
 
+    /// Returns `true` if the given Merkle multi-path is valid for the given root and leaves.
+    /// Note: Paths that target a padding position (i.e. a leaf index that is not occupied) are rejected.
+    pub fn verify_many(&self, path: &MerkleMultiPath<E, DEPTH>, root: &PH::Hash, leaves: &[LH::Leaf]) -> bool {
+        // Ensure the leaf indices correspond to real leaves.
+        if path.leaf_indices().iter().any(|leaf_index| (**leaf_index as u128) >= (self.number_of_leaves() as u128)) {
+            eprintln!("Found a Merkle multi-path for a padding position");
+            return false;
+        }
+        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaves)
+    }
+
     /// Returns the Merkle root of the tree.
     pub const fn root(&self) -> &PH::Hash {
         &self.root
@@ -603,11 +761,35 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         Ok(&self.tree[start..end])
     }
 
+    /// Ensures the leaf hashes of the Merkle tree are the hashes of the given leaves.
+    pub fn check_leaves(&self, leaves: &[LH::Leaf]) -> Result<()> {
+        // Ensure the number of leaves matches.
+        ensure!(
+            leaves.len() == self.number_of_leaves,
+            "Expected {} leaves in the Merkle tree, found {}",
+            self.number_of_leaves,
+            leaves.len()
+        );
+        // Ensure the leaf hashes match.
+        ensure!(
+            self.leaf_hasher.hash_leaves(leaves)? == self.leaf_hashes()?,
+            "Expected the leaf hashes of the Merkle tree to match the given leaves"
+        );
+        Ok(())
+    }
+
     /// Returns the number of leaves in the Merkle tree.
     pub const fn number_of_leaves(&self) -> usize {
         self.number_of_leaves
     }
 
+    /// Returns the number of real (non-padding) leaves in the Merkle tree.
+    /// The remaining `2^DEPTH - num_real_leaves()` positions are filled according to `PH::PADDING_MODE`.
+    /// Note: This is an alias of `number_of_leaves`, as the tree only stores its real leaves.
+    pub const fn num_real_leaves(&self) -> usize {
+        self.number_of_leaves()
+    }
+
     /// Compute and store the hashes for each level, iterating from the penultimate level to the root level.
     ///
     /// ```ignore
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle path for multiple leaves of the same Merkle tree.
+///
+/// The siblings are deduplicated across the individual paths: a sibling is only included
+/// if the verifier cannot compute it from the given leaves (or their ancestors).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerkleMultiPath<E: Environment, const DEPTH: u8> {
+    /// The leaf indices for the path, in ascending order.
+    leaf_indices: Vec<U64<E>>,
+    /// The `siblings` contains the sibling hashes in the order they are consumed,
+    /// level by level from the leaves to the root, and from left to right within each level.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(Vec<U64<E>>, Vec<Field<E>>)> for MerkleMultiPath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle multi-path.
+    fn try_from((leaf_indices, siblings): (Vec<U64<E>>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure there is at least one leaf index.
+        ensure!(!leaf_indices.is_empty(), "Found a Merkle multi-path without any leaf indices");
+        // Ensure the leaf indices are unique and in ascending order.
+        ensure!(
+            leaf_indices.windows(2).all(|pair| *pair[0] < *pair[1]),
+            "Found Merkle leaf indices that are not unique and in ascending order"
+        );
+        // Ensure the leaf indices are within the tree depth.
+        ensure!(
+            leaf_indices.iter().all(|leaf_index| (**leaf_index as u128) < (1u128 << DEPTH)),
+            "Found an out of bounds Merkle leaf index"
+        );
+        // Ensure there are no more siblings than in the individual Merkle paths.
+        ensure!(
+            siblings.len() <= leaf_indices.len().saturating_mul(DEPTH as usize),
+            "Found too many siblings in the Merkle multi-path"
+        );
+        // Return the Merkle multi-path.
+        Ok(Self { leaf_indices, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiPath<E, DEPTH> {
+    /// Returns the leaf indices for the path.
+    pub fn leaf_indices(&self) -> &[U64<E>] {
+        &self.leaf_indices
+    }
+
+    /// Returns the siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the Merkle multi-path is valid for the given root and leaves.
+    /// The leaves must be given in the same order as the leaf indices.
+    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[LH::Leaf],
+    ) -> bool {
+        // Ensure there is one leaf for each leaf index.
+        if leaves.len() != self.leaf_indices.len() {
+            eprintln!("Found an incorrect number of leaves for the Merkle multi-path");
+            return false;
+        }
+
+        // Initialize the current level, by computing the leaf hashes to start.
+        let mut level = match self
+            .leaf_indices
+            .iter()
+            .zip_eq(leaves)
+            .map(|(leaf_index, leaf)| Ok((**leaf_index, leaf_hasher.hash_leaf(leaf)?)))
+            .collect::<Result<Vec<_>>>()
+        {
+            Ok(level) => level,
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle leaves during verification: {error}");
+                return false;
+            }
+        };
+
+        // Initialize an iterator over the siblings.
+        let mut siblings = self.siblings.iter();
+
+        // Compute each level between the leaf level and the root.
+        for _ in 0..DEPTH {
+            let mut parents = Vec::with_capacity(level.len());
+            let mut i = 0;
+            while i < level.len() {
+                let (index, hash) = level[i];
+                // Construct the ordering of the left & right child hash for this node.
+                let (left, right) = match level.get(i + 1) {
+                    // If the sibling is the next node on this level, then it is not in the path.
+                    Some((next_index, next_hash)) if index % 2 == 0 && *next_index == index + 1 => {
+                        i += 2;
+                        (hash, *next_hash)
+                    }
+                    // Otherwise, the sibling is the next one in the path.
+                    _ => {
+                        let Some(sibling_hash) = siblings.next() else {
+                            eprintln!("Found too few siblings in the Merkle multi-path");
+                            return false;
+                        };
+                        i += 1;
+                        match index % 2 == 0 {
+                            true => (hash, *sibling_hash),
+                            false => (*sibling_hash, hash),
+                        }
+                    }
+                };
+                // Compute the parent hash for the next level.
+                match path_hasher.hash_children(&left, &right) {
+                    Ok(parent_hash) => parents.push((index / 2, parent_hash)),
+                    Err(error) => {
+                        eprintln!("Failed to hash the Merkle multi-path during verification: {error}");
+                        return false;
+                    }
+                }
+            }
+            level = parents;
+        }
+
+        // Ensure all of the siblings were used.
+        if siblings.next().is_some() {
+            eprintln!("Found unused siblings in the Merkle multi-path");
+            return false;
+        }
+
+        // Ensure the final hash matches the given root.
+        matches!(level.as_slice(), [(0, hash)] if hash == root)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for MerkleMultiPath<E, DEPTH> {
+    /// Reads in a Merkle multi-path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the leaf indices.
+        let num_leaf_indices = u32::read_le(&mut reader)?;
+        let leaf_indices = (0..num_leaf_indices)
+            .map(|_| Ok(U64::new(FromBytes::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        // Read the Merkle multi-path siblings.
+        let num_siblings = u32::read_le(&mut reader)?;
+        if num_siblings as usize > leaf_indices.len().saturating_mul(DEPTH as usize) {
+            return Err(error("Found too many siblings in the Merkle multi-path"));
+        }
+        let siblings = (0..num_siblings)
+            .map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle multi-path.
+        Self::try_from((leaf_indices, siblings)).map_err(error)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for MerkleMultiPath<E, DEPTH> {
+    /// Writes the Merkle multi-path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the leaf indices.
+        u32::try_from(self.leaf_indices.len()).map_err(error)?.write_le(&mut writer)?;
+        self.leaf_indices.iter().try_for_each(|leaf_index| leaf_index.write_le(&mut writer))?;
+        // Write the Merkle multi-path siblings.
+        u32::try_from(self.siblings.len()).map_err(error)?.write_le(&mut writer)?;
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
@@ -52,6 +52,11 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
     }
 
     /// Returns `true` if the Merkle path is valid for the given root and leaf.
+    ///
+    /// Note: The path does not know the number of leaves in the tree, so it does not check the leaf index
+    /// against it. A path for a padding position can only verify if `hash_leaf(leaf)` equals the padding node,
+    /// which requires a preimage of the path hash, as leaves and internal nodes are hashed with distinct prefixes.
+    /// Use `MerkleTree::verify` to reject padding positions explicitly.
     pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
         &self,
         leaf_hasher: &LH,
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Reconstruct the Merkle tree from its internal hashes.
+/// 3. Check that the two Merkle trees are equal.
+/// 4. Check that corrupted internal hashes are rejected.
+fn check_merkle_tree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Reconstruct the Merkle tree from its internal hashes.
+    let candidate =
+        MerkleTree::<E, LH, PH, DEPTH>::from_tree(leaf_hasher, path_hasher, merkle_tree.tree().to_vec(), leaves.len())?;
+    assert_eq!(candidate.number_of_leaves(), merkle_tree.number_of_leaves());
+    assert_eq!(candidate.root(), merkle_tree.root());
+    assert_eq!(candidate.tree(), merkle_tree.tree());
+
+    // Ensure each leaf in the reconstructed Merkle tree has the same proof.
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        assert_eq!(candidate.prove(leaf_index, leaf)?, merkle_tree.prove(leaf_index, leaf)?);
+    }
+
+    // Ensure a tree of the wrong size is rejected.
+    let mut tree = merkle_tree.tree().to_vec();
+    tree.push(*merkle_tree.empty_hash());
+    assert!(MerkleTree::<E, LH, PH, DEPTH>::from_tree(leaf_hasher, path_hasher, tree, leaves.len()).is_err());
+
+    // Ensure a tree with a nonempty padding leaf is rejected.
+    if !leaves.len().is_power_of_two() && !leaves.is_empty() {
+        let mut tree = merkle_tree.tree().to_vec();
+        *tree.last_mut().unwrap() = tree[0];
+        assert!(MerkleTree::<E, LH, PH, DEPTH>::from_tree(leaf_hasher, path_hasher, tree, leaves.len()).is_err());
+    }
+
+    // Ensure the reconstructed Merkle tree has the hashes of the leaves.
+    candidate.check_leaves(leaves)?;
+    // Ensure other leaves are rejected.
+    if !leaves.is_empty() {
+        assert!(candidate.check_leaves(&leaves[1..]).is_err());
+    }
+    if leaves.len() > 1 {
+        let mut other_leaves = leaves.to_vec();
+        other_leaves.swap(0, 1);
+        assert!(candidate.check_leaves(&other_leaves).is_err());
+    }
+
+    // Ensure a tree with a corrupted node is rejected, for the root, an internal node, and a leaf.
+    if leaves.len() > 1 {
+        let num_nodes = leaves.len().next_power_of_two() - 1;
+        for index in [0, num_nodes / 2, num_nodes] {
+            let mut tree = merkle_tree.tree().to_vec();
+            tree[index] = *merkle_tree.empty_hash();
+            assert!(MerkleTree::<E, LH, PH, DEPTH>::from_tree(leaf_hasher, path_hasher, tree, leaves.len()).is_err());
+        }
+    }
+
+    // Ensure a tree with a corrupted leaf hash is loaded with leaves that do not match, if it is the only leaf.
+    if leaves.len() == 1 {
+        let mut tree = merkle_tree.tree().to_vec();
+        tree[0] = *merkle_tree.empty_hash();
+        let candidate = MerkleTree::<E, LH, PH, DEPTH>::from_tree(leaf_hasher, path_hasher, tree, leaves.len())?;
+        assert!(candidate.check_leaves(leaves).is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_from_tree() -> Result<()> {
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    for num_leaves in 0..ITERATIONS {
+        // Sample the leaves.
+        let leaves = (0..num_leaves)
+            .map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le())
+            .collect::<Vec<Vec<bool>>>();
+        // Check the Merkle tree.
+        check_merkle_tree::<CurrentEnvironment, LH, PH, 32>(&leaf_hasher, &path_hasher, &leaves)?;
+    }
+    Ok(())
+}
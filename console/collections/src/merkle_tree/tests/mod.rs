@@ -15,6 +15,9 @@
 use super::*;
 
 mod append;
+mod from_tree;
+mod padding;
+mod prove_many;
 mod remove;
 mod update;
 mod update_many;
@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const DEPTH: u8 = 3;
+
+/// A path hasher that pads the Merkle tree with the zero element.
+#[derive(Clone)]
+struct ZeroPadded(BHP512<CurrentEnvironment>);
+
+impl PathHash for ZeroPadded {
+    type Hash = Field<CurrentEnvironment>;
+
+    const PADDING_MODE: PaddingMode = PaddingMode::Zero;
+
+    fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Result<Self::Hash> {
+        PathHash::hash_children(&self.0, left, right)
+    }
+}
+
+/// Returns the Merkle root for the given leaves, by applying the padding rule documented on `PaddingMode`.
+fn expected_root<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<Field<E>> {
+    let empty_hash = path_hasher.hash_empty()?;
+
+    // Pad the leaf hashes with the empty hash, up to the next power of two.
+    let width = leaves.len().next_power_of_two();
+    let mut level = leaf_hasher.hash_leaves(leaves)?;
+    level.resize(width, empty_hash);
+
+    // Hash each level of the subtree.
+    while level.len() > 1 {
+        level = level.chunks(2).map(|pair| path_hasher.hash_children(&pair[0], &pair[1])).collect::<Result<_>>()?;
+    }
+
+    // Hash the remaining levels up to `DEPTH` with the empty hash.
+    let mut root = level[0];
+    for _ in width.trailing_zeros()..DEPTH as u32 {
+        root = path_hasher.hash_children(&root, &empty_hash)?;
+    }
+    Ok(root)
+}
+
+fn check_padding<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    assert_eq!(leaves.len(), merkle_tree.number_of_leaves());
+    assert_eq!(leaves.len(), merkle_tree.num_real_leaves());
+    assert_eq!(expected_root(leaf_hasher, path_hasher, leaves)?, *merkle_tree.root());
+
+    // Ensure the padding positions are filled with the empty hash.
+    let start = leaves.len().next_power_of_two() - 1;
+    for index in start + leaves.len()..merkle_tree.tree().len() {
+        assert_eq!(*merkle_tree.empty_hash(), merkle_tree.tree()[index]);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_padding_mode() -> Result<()> {
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+    assert_eq!(PaddingMode::EmptyHash, <BHP512<CurrentEnvironment> as PathHash>::PADDING_MODE);
+    assert_eq!(PaddingMode::EmptyHash, <Poseidon2<CurrentEnvironment> as PathHash>::PADDING_MODE);
+    assert_eq!(PathHash::hash_children(&path_hasher, &Field::zero(), &Field::zero())?, path_hasher.hash_empty()?);
+
+    let zero_padded = ZeroPadded(path_hasher);
+    assert_eq!(Field::zero(), zero_padded.hash_empty()?);
+    Ok(())
+}
+
+#[test]
+fn test_padding_bhp() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+
+    for num_leaves in [1, 2, 3, 5, 1 << DEPTH] {
+        let leaves = (0..num_leaves)
+            .map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le())
+            .collect::<Vec<Vec<bool>>>();
+        check_padding(&leaf_hasher, &path_hasher, &leaves)?;
+        check_padding(&leaf_hasher, &ZeroPadded(path_hasher.clone()), &leaves)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_padding_poseidon() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = Poseidon4::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = Poseidon2::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+
+    for num_leaves in [1, 2, 3, 5, 1 << DEPTH] {
+        let leaves = (0..num_leaves)
+            .map(|_| vec![Field::<CurrentEnvironment>::rand(&mut rng)])
+            .collect::<Vec<Vec<Field<CurrentEnvironment>>>>();
+        check_padding(&leaf_hasher, &path_hasher, &leaves)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_padding_golden_roots_bhp() -> Result<()> {
+    // These roots lock the canonical `PaddingMode::EmptyHash` rule for the BHP Merkle tree.
+    const GOLDEN_ROOTS: [(usize, &str); 5] = [
+        (1, "791654177001646879864383083518492738294059955372118797837286931003986596216field"),
+        (2, "3807350025558719857163819293639510402188866041487805209598755108139503763452field"),
+        (3, "1283471812179249108597696046858005066954923753695689515053359284838977951035field"),
+        (5, "1689337288722374219768365022038814101362250725956914691958827075409745417125field"),
+        (8, "7109354579681467742701206448366184994502541006220665242802401692194585799503field"),
+    ];
+
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+
+    for (num_leaves, expected_root) in GOLDEN_ROOTS {
+        let leaves =
+            (0..num_leaves as u64).map(|i| Field::<CurrentEnvironment>::from_u64(i).to_bits_le()).collect::<Vec<_>>();
+        let merkle_tree = MerkleTree::<_, _, _, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+        assert_eq!(num_leaves, merkle_tree.num_real_leaves());
+        assert_eq!(Field::<CurrentEnvironment>::from_str(expected_root)?, *merkle_tree.root());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_padding_golden_roots_poseidon() -> Result<()> {
+    // These roots lock the canonical `PaddingMode::EmptyHash` rule for the Poseidon Merkle tree.
+    const GOLDEN_ROOTS: [(usize, &str); 5] = [
+        (1, "7159181014475924759691542093592959427158888965476196836404493472280454211042field"),
+        (2, "2553518316659335115080336865882497120878533627903564847005718819012598611112field"),
+        (3, "1696605301551333872117924756983864236941749632209927585679641261666560763610field"),
+        (5, "7732539477365619628964976691366637115382646292116268820019291457482416440534field"),
+        (8, "3136104417603361196767258731537159265878272023060163646156278054133806408068field"),
+    ];
+
+    let leaf_hasher = Poseidon4::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = Poseidon2::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+
+    for (num_leaves, expected_root) in GOLDEN_ROOTS {
+        let leaves = (0..num_leaves as u64).map(|i| vec![Field::from_u64(i)]).collect::<Vec<_>>();
+        let merkle_tree = MerkleTree::<_, _, _, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+        assert_eq!(Field::<CurrentEnvironment>::from_str(expected_root)?, *merkle_tree.root());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_padding_modes_produce_different_roots() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..5).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+    let empty_hash_tree = MerkleTree::<_, _, _, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let zero_tree = MerkleTree::<_, _, _, DEPTH>::new(&leaf_hasher, &ZeroPadded(path_hasher), &leaves)?;
+    assert_ne!(empty_hash_tree.root(), zero_tree.root());
+    Ok(())
+}
+
+#[test]
+fn test_verify_rejects_padding_index() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..5).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<_, _, _, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Ensure the path for a real leaf verifies.
+    let path = merkle_tree.prove(4, &leaves[4])?;
+    assert!(merkle_tree.verify(&path, merkle_tree.root(), &leaves[4]));
+
+    // Ensure a path cannot be generated for a padding position.
+    assert!(merkle_tree.prove(5, &leaves[4]).is_err());
+
+    // Construct a path for the padding position, reusing the siblings of its neighbour.
+    let padding_path = MerklePath::try_from((U64::new(5), path.siblings().to_vec()))?;
+    assert!(!merkle_tree.verify(&padding_path, merkle_tree.root(), &leaves[4]));
+    Ok(())
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Construct the Merkle multi-path for the given leaf indices.
+/// 3. Check that the Merkle multi-path is valid, and that it round-trips through bytes.
+fn check_merkle_multi_path<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    leaf_indices: &[usize],
+) -> Result<MerkleMultiPath<E, DEPTH>>
+where
+    LH::Leaf: Clone,
+{
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Construct the Merkle multi-path.
+    let selected = leaf_indices.iter().map(|i| (*i, leaves[*i].clone())).collect::<Vec<_>>();
+    let selected_leaves = selected.iter().map(|(_, leaf)| leaf.clone()).collect::<Vec<_>>();
+    let multi_path = merkle_tree.prove_many(&selected)?;
+
+    // Ensure the Merkle multi-path is valid.
+    assert!(merkle_tree.verify_many(&multi_path, merkle_tree.root(), &selected_leaves));
+    // Ensure the Merkle multi-path is invalid for a different root.
+    assert!(!merkle_tree.verify_many(&multi_path, &Field::zero(), &selected_leaves));
+    // Ensure the Merkle multi-path is invalid for a missing leaf.
+    assert!(!merkle_tree.verify_many(&multi_path, merkle_tree.root(), &selected_leaves[1..]));
+    // Ensure the Merkle multi-path is invalid for reordered leaves.
+    if selected_leaves.len() > 1 {
+        let mut wrong_leaves = selected_leaves.clone();
+        wrong_leaves.swap(0, selected_leaves.len() - 1);
+        assert!(!merkle_tree.verify_many(&multi_path, merkle_tree.root(), &wrong_leaves));
+    }
+
+    // Ensure the Merkle multi-path never has more siblings than the individual Merkle paths.
+    assert!(multi_path.siblings().len() <= leaf_indices.len() * DEPTH as usize);
+
+    // Ensure the Merkle multi-path round-trips through bytes.
+    let bytes = multi_path.to_bytes_le()?;
+    assert_eq!(multi_path, MerkleMultiPath::read_le(&bytes[..])?);
+
+    Ok(multi_path)
+}
+
+#[test]
+fn test_merkle_tree_bhp_prove_many() -> Result<()> {
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Sample the leaves.
+    let leaves = (0..ITERATIONS * 2)
+        .map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le())
+        .collect::<Vec<Vec<bool>>>();
+
+    // Check adjacent leaves, leaves in different subtrees, and every leaf.
+    let all_indices = (0..leaves.len()).collect::<Vec<_>>();
+    for leaf_indices in [&[2, 3][..], &[1, 2], &[0, 9, 19], &all_indices[..]] {
+        check_merkle_multi_path::<CurrentEnvironment, LH, PH, 32>(&leaf_hasher, &path_hasher, &leaves, leaf_indices)?;
+    }
+
+    // Check that a single leaf matches the existing Merkle path.
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 32>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    for leaf_index in 0..leaves.len() {
+        let multi_path = check_merkle_multi_path::<CurrentEnvironment, LH, PH, 32>(
+            &leaf_hasher,
+            &path_hasher,
+            &leaves,
+            &[leaf_index],
+        )?;
+        assert_eq!(multi_path.siblings(), merkle_tree.prove(leaf_index, &leaves[leaf_index])?.siblings());
+    }
+
+    // Check that adjacent leaves share their siblings above the leaf level.
+    let multi_path = merkle_tree.prove_many(&[(2, leaves[2].clone()), (3, leaves[3].clone())])?;
+    assert_eq!(multi_path.siblings().len(), 31);
+    assert!(multi_path.to_bytes_le()?.len() < 2 * merkle_tree.prove(2, &leaves[2])?.to_bytes_le()?.len());
+
+    // Ensure duplicate, unordered, and out of bounds leaf indices are rejected.
+    assert!(merkle_tree.prove_many(&[(2, leaves[2].clone()), (2, leaves[2].clone())]).is_err());
+    assert!(merkle_tree.prove_many(&[(3, leaves[3].clone()), (2, leaves[2].clone())]).is_err());
+    assert!(merkle_tree.prove_many(&[(leaves.len(), leaves[0].clone())]).is_err());
+    assert!(merkle_tree.prove_many(&[]).is_err());
+    Ok(())
+}
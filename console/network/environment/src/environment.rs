@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::prelude::{Deserialize, DeserializeOwned, Serialize};
+use crate::{
+    prelude::{Deserialize, DeserializeOwned, Serialize},
+    HaltCode,
+    HaltError,
+};
 use snarkvm_curves::{
     bls12_377::Bls12_377,
     edwards_bls12::{EdwardsAffine, EdwardsParameters},
@@ -58,7 +62,13 @@ pub trait Environment:
 
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
-        panic!("{}", message.into())
+        Self::halt_with(HaltCode::Custom, message)
+    }
+
+    /// Halts the program from further synthesis, evaluation, and execution in the current environment,
+    /// with the given halt code (see `HaltError::from_panic`).
+    fn halt_with<S: Into<String>, T>(code: HaltCode, message: S) -> T {
+        HaltError::new(code, message).halt()
     }
 }
 
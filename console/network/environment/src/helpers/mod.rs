@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use snarkvm_utilities::{HaltCode, HaltError};
+
 mod or_halt;
 pub use or_halt::OrHalt;
 
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::Environment;
+use crate::{Environment, HaltCode};
 
 /// A trait to unwrap a `Result` or `Halt`.
 pub trait OrHalt<T> {
@@ -21,6 +21,9 @@ pub trait OrHalt<T> {
 
     /// Returns the result if it is successful, otherwise halts with the message.
     fn or_halt_with<E: Environment>(self, msg: &str) -> T;
+
+    /// Returns the result if it is successful, otherwise halts with the code and message.
+    fn or_halt_with_code<E: Environment>(self, code: HaltCode, msg: &str) -> T;
 }
 
 impl<T, Error: core::fmt::Display> OrHalt<T> for Result<T, Error> {
@@ -39,4 +42,12 @@ impl<T, Error: core::fmt::Display> OrHalt<T> for Result<T, Error> {
             Err(error) => E::halt(format!("{msg}: {error}")),
         }
     }
+
+    /// Returns the result if it is successful, otherwise halts with the code and message.
+    fn or_halt_with_code<E: Environment>(self, code: HaltCode, msg: &str) -> T {
+        match self {
+            Ok(result) => result,
+            Err(error) => E::halt_with(code, format!("{msg}: {error}")),
+        }
+    }
 }
@@ -49,8 +49,10 @@ use once_cell::sync::OnceCell;
 use std::sync::Arc;
 
 /// A helper type for the BHP Merkle tree.
+/// Note: The consensus trees (transactions, transitions, blocks, etc.) use `PaddingMode::EmptyHash`.
 pub type BHPMerkleTree<N, const DEPTH: u8> = MerkleTree<N, BHP1024<N>, BHP512<N>, DEPTH>;
 /// A helper type for the Poseidon Merkle tree.
+/// Note: The consensus trees use `PaddingMode::EmptyHash`.
 pub type PoseidonMerkleTree<N, const DEPTH: u8> = MerkleTree<N, Poseidon4<N>, Poseidon2<N>, DEPTH>;
 
 /// Helper types for the Varuna parameters.
@@ -107,6 +109,29 @@ pub trait Network:
     const MAX_SOLUTIONS_AS_POWER_OF_TWO: u8 = 2; // 4 solutions
     /// The maximum number of solutions that can be included per block.
     const MAX_SOLUTIONS: usize = 1 << Self::MAX_SOLUTIONS_AS_POWER_OF_TWO; // 4 solutions
+    /// The maximum number of bytes in a serialized solution, i.e. an epoch hash, an address, and a counter.
+    const MAX_SOLUTION_SIZE_IN_BYTES: usize = 32 + 32 + 8; // 72 bytes
+    /// The maximum number of transactions that can be included per block, i.e. the capacity of the transactions tree.
+    /// Note: This must match `Transactions::MAX_TRANSACTIONS`, which is derived from the `TRANSACTIONS_DEPTH` of 20.
+    const MAX_TRANSACTIONS_PER_BLOCK: usize = usize::pow(2, 20).saturating_sub(1); // 1,048,575 transactions
+    /// The maximum number of transitions in a transaction, i.e. the capacity of the transaction tree.
+    /// Note: This must match `Transaction::MAX_TRANSITIONS`, which is derived from the `TRANSACTION_DEPTH` of 5.
+    const MAX_TRANSITIONS_PER_TRANSACTION: usize = usize::pow(2, 5); // 32 transitions
+    /// The maximum number of bytes in a serialized transaction.
+    /// This bounds an execution of `MAX_TRANSITIONS_PER_TRANSACTION` transitions, each with `MAX_INPUTS` inputs
+    /// and `MAX_OUTPUTS` outputs of at most `MAX_DATA_SIZE_IN_FIELDS` field elements, with 1 KiB for each entry
+    /// and each transition to cover the IDs, keys, fee, and proof. A deployment of `MAX_PROGRAM_SIZE` bytes with
+    /// the verifying keys and certificates of `MAX_FUNCTIONS` functions is well within this bound.
+    const MAX_TRANSACTION_SIZE_IN_BYTES: usize = Self::MAX_TRANSITIONS_PER_TRANSACTION
+        * (Self::MAX_INPUTS + Self::MAX_OUTPUTS + 1)
+        * (Self::MAX_DATA_SIZE_IN_FIELDS as usize * 32 + 1024); // ~135 MiB
+    /// The maximum number of bytes in a serialized block, excluding its solutions and transactions.
+    /// This covers the header, the authority, the ratifications, and the aborted IDs.
+    const MAX_BLOCK_OVERHEAD_IN_BYTES: usize = 1 << 24; // 16 MiB
+    /// The maximum number of bytes in a serialized block.
+    /// Note: This must fit the block overhead, the maximum number of solutions, and a transaction of
+    /// `MAX_TRANSACTION_SIZE_IN_BYTES`, so that any valid transaction can be included in a block.
+    const MAX_BLOCK_SIZE_IN_BYTES: usize = 1 << 28; // 256 MiB
 
     /// The starting supply of Aleo credits.
     const STARTING_SUPPLY: u64 = 1_500_000_000_000_000; // 1.5B credits
@@ -236,6 +261,9 @@ pub trait Network:
     /// Returns the serial number domain as a constant field element.
     fn serial_number_domain() -> Field<Self>;
 
+    /// Returns the PRF domain as a constant field element.
+    fn prf_domain() -> Field<Self>;
+
     /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
     fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>>;
 
@@ -365,6 +393,13 @@ pub trait Network:
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>>;
 
+    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits,
+    /// from the given internal hashes and number of leaves.
+    fn merkle_tree_bhp_from_tree<const DEPTH: u8>(
+        tree: Vec<Field<Self>>,
+        number_of_leaves: usize,
+    ) -> Result<BHPMerkleTree<Self, DEPTH>>;
+
     /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
     fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>>;
 
@@ -45,6 +45,8 @@ lazy_static! {
     pub static ref GRAPH_KEY_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator("AleoGraphKey0");
     /// The serial number domain as a constant field element.
     pub static ref SERIAL_NUMBER_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator("AleoSerialNumber0");
+    /// The PRF domain as a constant field element.
+    pub static ref PRF_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator("AleoPrf0");
 
     /// The BHP hash function, which can take an input of up to 256 bits.
     pub static ref BHP_256: BHP256<MainnetV0> = BHP256::<MainnetV0>::setup("AleoBHP256").expect("Failed to setup BHP256");
@@ -242,6 +244,11 @@ impl Network for MainnetV0 {
         *SERIAL_NUMBER_DOMAIN
     }
 
+    /// Returns the PRF domain as a constant field element.
+    fn prf_domain() -> Field<Self> {
+        *PRF_DOMAIN
+    }
+
     /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
     fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
         BHP_256.commit(input, randomizer)
@@ -457,6 +464,15 @@ impl Network for MainnetV0 {
         MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
     }
 
+    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits,
+    /// from the given internal hashes and number of leaves.
+    fn merkle_tree_bhp_from_tree<const DEPTH: u8>(
+        tree: Vec<Field<Self>>,
+        number_of_leaves: usize,
+    ) -> Result<BHPMerkleTree<Self, DEPTH>> {
+        MerkleTree::from_tree(&*BHP_1024, &*BHP_512, tree, number_of_leaves)
+    }
+
     /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
     fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
         MerkleTree::new(&*POSEIDON_4, &*POSEIDON_2, leaves)
@@ -494,4 +510,13 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_max_block_size_fits_a_max_transaction() {
+        // Ensure a block of the maximum size fits its overhead, its solutions, and a transaction of the maximum size.
+        let min_block_size_in_bytes = CurrentNetwork::MAX_BLOCK_OVERHEAD_IN_BYTES
+            + CurrentNetwork::MAX_SOLUTIONS * CurrentNetwork::MAX_SOLUTION_SIZE_IN_BYTES
+            + CurrentNetwork::MAX_TRANSACTION_SIZE_IN_BYTES;
+        assert!(CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES >= min_block_size_in_bytes);
+    }
 }
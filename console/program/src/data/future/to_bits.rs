@@ -20,25 +20,31 @@ impl<N: Network> ToBits for Future<N> {
     fn write_bits_le(&self, vec: &mut Vec<bool>) {
         // Write the bits for the program ID.
         let program_id_bits = self.program_id.to_bits_le();
-        u16::try_from(program_id_bits.len()).or_halt_with::<N>("Program ID exceeds u16::MAX bits").write_bits_le(vec);
+        u16::try_from(program_id_bits.len())
+            .or_halt_with_code::<N>(HaltCode::RangeViolation, "Program ID exceeds u16::MAX bits")
+            .write_bits_le(vec);
         vec.extend_from_slice(&program_id_bits);
 
         // Write the bits for the function name.
         let function_name_bits = self.function_name.to_bits_le();
         u16::try_from(function_name_bits.len())
-            .or_halt_with::<N>("Function name exceeds u16::MAX bits")
+            .or_halt_with_code::<N>(HaltCode::RangeViolation, "Function name exceeds u16::MAX bits")
             .write_bits_le(vec);
         vec.extend_from_slice(&function_name_bits);
 
         // Write the number of arguments.
-        u8::try_from(self.arguments.len()).or_halt_with::<N>("arguments exceed u8::MAX").write_bits_le(vec);
+        u8::try_from(self.arguments.len())
+            .or_halt_with_code::<N>(HaltCode::RangeViolation, "arguments exceed u8::MAX")
+            .write_bits_le(vec);
 
         // Write the arguments.
         for argument in &self.arguments {
             let argument_bits = argument.to_bits_le();
 
             // Write the size of the argument.
-            u16::try_from(argument_bits.len()).or_halt_with::<N>("argument exceeds u16::MAX bits").write_bits_le(vec);
+            u16::try_from(argument_bits.len())
+                .or_halt_with_code::<N>(HaltCode::RangeViolation, "argument exceeds u16::MAX bits")
+                .write_bits_le(vec);
 
             // Write the argument.
             vec.extend_from_slice(&argument_bits);
@@ -50,25 +56,31 @@ impl<N: Network> ToBits for Future<N> {
     fn write_bits_be(&self, vec: &mut Vec<bool>) {
         // Write the bits for the program ID.
         let program_id_bits = self.program_id.to_bits_be();
-        u16::try_from(program_id_bits.len()).or_halt_with::<N>("Program ID exceeds u16::MAX bits").write_bits_be(vec);
+        u16::try_from(program_id_bits.len())
+            .or_halt_with_code::<N>(HaltCode::RangeViolation, "Program ID exceeds u16::MAX bits")
+            .write_bits_be(vec);
         vec.extend_from_slice(&program_id_bits);
 
         // Write the bits for the function name.
         let function_name_bits = self.function_name.to_bits_be();
         u16::try_from(function_name_bits.len())
-            .or_halt_with::<N>("Function name exceeds u16::MAX bits")
+            .or_halt_with_code::<N>(HaltCode::RangeViolation, "Function name exceeds u16::MAX bits")
             .write_bits_be(vec);
         vec.extend_from_slice(&function_name_bits);
 
         // Write the number of arguments.
-        u8::try_from(self.arguments.len()).or_halt_with::<N>("arguments exceed u8::MAX").write_bits_be(vec);
+        u8::try_from(self.arguments.len())
+            .or_halt_with_code::<N>(HaltCode::RangeViolation, "arguments exceed u8::MAX")
+            .write_bits_be(vec);
 
         // Write the arguments.
         for argument in &self.arguments {
             let argument_bits = argument.to_bits_be();
 
             // Write the size of the argument.
-            u16::try_from(argument_bits.len()).or_halt_with::<N>("argument exceeds u16::MAX bits").write_bits_be(vec);
+            u16::try_from(argument_bits.len())
+                .or_halt_with_code::<N>(HaltCode::RangeViolation, "argument exceeds u16::MAX bits")
+                .write_bits_be(vec);
 
             // Write the argument.
             vec.extend_from_slice(&argument_bits);
@@ -22,7 +22,7 @@ impl<N: Network> Visibility for Plaintext<N> {
         // Compute the number of bits.
         let num_bits = self.to_bits_le().len() + 1; // 1 extra bit for the terminus indicator.
         // Compute the ceiling division of the number of bits by the number of bits in a field element.
-        let num_fields = (num_bits + Field::<N>::size_in_data_bits() - 1) / Field::<N>::size_in_data_bits();
+        let num_fields = num_bits.div_ceil(Field::<N>::size_in_data_bits());
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match num_fields <= N::MAX_DATA_SIZE_IN_FIELDS as usize {
             // Return the number of field elements.
@@ -26,7 +26,9 @@ impl<N: Network> ToBits for Record<N, Plaintext<N>> {
 
         // Construct the record bits.
         self.owner.write_bits_le(vec);
-        u32::try_from(data_bits_le.len()).or_halt_with::<N>("Record data exceeds u32::MAX bits").write_bits_le(vec);
+        u32::try_from(data_bits_le.len())
+            .or_halt_with_code::<N>(HaltCode::RangeViolation, "Record data exceeds u32::MAX bits")
+            .write_bits_le(vec);
         vec.extend_from_slice(&data_bits_le);
         self.nonce.write_bits_le(vec);
     }
@@ -42,7 +44,9 @@ impl<N: Network> ToBits for Record<N, Plaintext<N>> {
 
         // Construct the record bits.
         self.owner.write_bits_be(vec);
-        u32::try_from(data_bits_be.len()).or_halt_with::<N>("Record data exceeds u32::MAX bits").write_bits_be(vec);
+        u32::try_from(data_bits_be.len())
+            .or_halt_with_code::<N>(HaltCode::RangeViolation, "Record data exceeds u32::MAX bits")
+            .write_bits_be(vec);
         vec.extend_from_slice(&data_bits_be);
         self.nonce.write_bits_be(vec);
     }
@@ -60,7 +64,9 @@ impl<N: Network> ToBits for Record<N, Ciphertext<N>> {
 
         // Construct the record bits.
         self.owner.write_bits_le(vec);
-        u32::try_from(data_bits_le.len()).or_halt_with::<N>("Record data exceeds u32::MAX bits").write_bits_le(vec);
+        u32::try_from(data_bits_le.len())
+            .or_halt_with_code::<N>(HaltCode::RangeViolation, "Record data exceeds u32::MAX bits")
+            .write_bits_le(vec);
         vec.extend_from_slice(&data_bits_le);
         self.nonce.write_bits_le(vec);
     }
@@ -76,7 +82,9 @@ impl<N: Network> ToBits for Record<N, Ciphertext<N>> {
 
         // Construct the record bits.
         self.owner.write_bits_be(vec);
-        u32::try_from(data_bits_be.len()).or_halt_with::<N>("Record data exceeds u32::MAX bits").write_bits_be(vec);
+        u32::try_from(data_bits_be.len())
+            .or_halt_with_code::<N>(HaltCode::RangeViolation, "Record data exceeds u32::MAX bits")
+            .write_bits_be(vec);
         vec.extend_from_slice(&data_bits_be);
         self.nonce.write_bits_be(vec);
     }
@@ -42,6 +42,9 @@ pub use locator::*;
 mod owner;
 pub use owner::*;
 
+mod prf;
+pub use prf::*;
+
 mod request;
 pub use request::*;
 
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console_network::Network;
+use snarkvm_console_types::{integers::Integer, prelude::*, Field, U64};
+
+/// The number of low-order bits of the PRF output that are scaled into a range.
+pub(crate) const PRF_RANGE_BITS: usize = 128;
+
+/// Computes the pseudorandom field element for the given seed and counter,
+/// as `Hash(prf_domain, seed, counter)`.
+pub fn prf_psd2<N: Network>(seed: &Field<N>, counter: u64) -> Result<Field<N>> {
+    N::hash_psd2(&[N::prf_domain(), *seed, Field::from_u64(counter)])
+}
+
+/// Computes the pseudorandom integer for the given seed and counter,
+/// by truncating the PRF output to the lower `I::BITS` bits.
+///
+/// As the PRF output is uniform over the base field, the statistical distance of the
+/// integer from uniform is at most `2^I::BITS / MODULUS`, which is below `2^-124`.
+pub fn prf_to_integer<N: Network, I: IntegerType>(seed: &Field<N>, counter: u64) -> Result<Integer<N, I>> {
+    let bits_le = prf_psd2(seed, counter)?.to_bits_le();
+    Integer::from_bits_le(&bits_le[..usize::try_from(I::BITS)?])
+}
+
+/// Computes the pseudorandom value in `[0, n)` for the given seed and counter,
+/// as `(x * n) >> 128`, where `x` is the lower 128 bits of the PRF output.
+///
+/// This scaled reduction never rejects, and the probability of any output differs
+/// from `1 / n` by at most `n / 2^128`.
+pub fn prf_to_range<N: Network>(seed: &Field<N>, counter: u64, n: u64) -> Result<U64<N>> {
+    // Ensure the range is not empty.
+    ensure!(n > 0, "The PRF range must be nonempty");
+
+    // Retrieve the lower 128 bits of the PRF output.
+    let bits_le = prf_psd2(seed, counter)?.to_bits_le();
+    let x = Field::<N>::from_bits_le(&bits_le[..PRF_RANGE_BITS])?;
+    // Compute `x * n`, which is less than 2^192 and thus does not wrap around the modulus.
+    let product = x * Field::from_u64(n);
+    // Return the upper 64 bits of the product.
+    U64::from_bits_le(&product.to_bits_le()[PRF_RANGE_BITS..PRF_RANGE_BITS + 64])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_prf_psd2() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let seed = Field::<CurrentNetwork>::rand(&mut rng);
+            let counter = u64::rand(&mut rng);
+
+            // Ensure the PRF is deterministic.
+            let output = prf_psd2(&seed, counter)?;
+            assert_eq!(output, prf_psd2(&seed, counter)?);
+            // Ensure the PRF depends on the counter.
+            assert_ne!(output, prf_psd2(&seed, counter.wrapping_add(1))?);
+            // Ensure the PRF is domain-separated from a plain Poseidon hash.
+            assert_ne!(output, CurrentNetwork::hash_psd2(&[seed, Field::from_u64(counter)])?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_prf_to_integer() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let seed = Field::<CurrentNetwork>::rand(&mut rng);
+            let counter = u64::rand(&mut rng);
+
+            // Ensure the integer is the truncation of the PRF output.
+            let bits_le = prf_psd2(&seed, counter)?.to_bits_le();
+            let candidate = prf_to_integer::<CurrentNetwork, u32>(&seed, counter)?;
+            assert_eq!(candidate.to_bits_le(), bits_le[..32]);
+            let candidate = prf_to_integer::<CurrentNetwork, i64>(&seed, counter)?;
+            assert_eq!(candidate.to_bits_le(), bits_le[..64]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_prf_to_range() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let seed = Field::<CurrentNetwork>::rand(&mut rng);
+            let counter = u64::rand(&mut rng);
+
+            // Ensure the output is within range.
+            for n in [1, 2, 7, 1000, u64::MAX] {
+                assert!(*prf_to_range(&seed, counter, n)? < n);
+            }
+            // Ensure the empty range is rejected.
+            assert!(prf_to_range(&seed, counter, 0).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_prf_to_range_chi_square() -> Result<()> {
+        // The number of samples and buckets.
+        const NUM_SAMPLES: u64 = 4000;
+        const NUM_BUCKETS: usize = 8;
+        // The chi-square critical value for 7 degrees of freedom at a significance level of 0.001.
+        const CRITICAL_VALUE: f64 = 24.322;
+
+        // Use a fixed seed, so that the test is deterministic and cannot fail by chance.
+        let mut rng = TestRng::fixed(123456789);
+        let seed = Field::<CurrentNetwork>::rand(&mut rng);
+
+        // Tally the outputs into buckets.
+        let mut buckets = [0u64; NUM_BUCKETS];
+        for counter in 0..NUM_SAMPLES {
+            buckets[usize::try_from(*prf_to_range(&seed, counter, NUM_BUCKETS as u64)?)?] += 1;
+        }
+
+        // Compute the chi-square statistic.
+        let expected = (NUM_SAMPLES / NUM_BUCKETS as u64) as f64;
+        let statistic = buckets.iter().map(|&count| (count as f64 - expected).powi(2) / expected).sum::<f64>();
+        assert!(statistic < CRITICAL_VALUE, "Chi-square statistic {statistic} exceeds {CRITICAL_VALUE}: {buckets:?}");
+        Ok(())
+    }
+}
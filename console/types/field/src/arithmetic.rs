@@ -139,7 +139,7 @@ impl<E: Environment> Div<Field<E>> for Field<E> {
     #[inline]
     fn div(self, other: Field<E>) -> Self::Output {
         match other.is_zero() {
-            true => E::halt(format!("Field division by zero: {self} / {other}")),
+            true => E::halt_with(HaltCode::DivisionByZero, format!("Field division by zero: {self} / {other}")),
             false => Field::new(self.field / other.field),
         }
     }
@@ -152,7 +152,7 @@ impl<E: Environment> Div<&Field<E>> for Field<E> {
     #[inline]
     fn div(self, other: &Field<E>) -> Self::Output {
         match other.is_zero() {
-            true => E::halt(format!("Field division by zero: {self} / {other}")),
+            true => E::halt_with(HaltCode::DivisionByZero, format!("Field division by zero: {self} / {other}")),
             false => Field::new(self.field / other.field),
         }
     }
@@ -163,7 +163,7 @@ impl<E: Environment> DivAssign<Field<E>> for Field<E> {
     #[inline]
     fn div_assign(&mut self, other: Field<E>) {
         match other.is_zero() {
-            true => E::halt(format!("Field division by zero: {self} / {other}")),
+            true => E::halt_with(HaltCode::DivisionByZero, format!("Field division by zero: {self} / {other}")),
             false => self.field /= other.field,
         }
     }
@@ -174,7 +174,7 @@ impl<E: Environment> DivAssign<&Field<E>> for Field<E> {
     #[inline]
     fn div_assign(&mut self, other: &Field<E>) {
         match other.is_zero() {
-            true => E::halt(format!("Field division by zero: {self} / {other}")),
+            true => E::halt_with(HaltCode::DivisionByZero, format!("Field division by zero: {self} / {other}")),
             false => self.field /= other.field,
         }
     }
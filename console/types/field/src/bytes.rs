@@ -54,4 +54,31 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_rejects_non_canonical() -> Result<()> {
+        // Retrieve the modulus in little-endian bytes.
+        let modulus = <CurrentEnvironment as Environment>::Field::modulus().to_bytes_le()?;
+
+        // Compute `modulus + 1` in little-endian bytes.
+        let mut modulus_plus_one = modulus.clone();
+        for byte in modulus_plus_one.iter_mut() {
+            let (sum, carry) = byte.overflowing_add(1);
+            *byte = sum;
+            if !carry {
+                break;
+            }
+        }
+
+        // Ensure the non-reduced encodings are rejected.
+        assert!(Field::<CurrentEnvironment>::read_le(&modulus[..]).is_err());
+        assert!(Field::<CurrentEnvironment>::read_le(&modulus_plus_one[..]).is_err());
+        // Ensure the largest field element is accepted.
+        let modulus_minus_one = -Field::<CurrentEnvironment>::one();
+        assert_eq!(modulus_minus_one, Field::read_le(&modulus_minus_one.to_bytes_le()?[..])?);
+        // Ensure the explicit reduction still reduces the non-reduced encoding.
+        let reduced = <CurrentEnvironment as Environment>::Field::from_bytes_le_mod_order(&modulus_plus_one);
+        assert_eq!(Field::<CurrentEnvironment>::one(), Field::new(reduced));
+        Ok(())
+    }
 }
@@ -19,7 +19,10 @@ impl<E: Environment> Group<E> {
     pub fn from_xy_coordinates(x: Field<E>, y: Field<E>) -> Self {
         match E::Affine::from_coordinates((*x, *y)) {
             Some(point) => Self { group: point.into() },
-            None => E::halt("Attempted to recover an invalid group element from (x, y) coordinates"),
+            None => E::halt_with(
+                HaltCode::RangeViolation,
+                "Attempted to recover an invalid group element from (x, y) coordinates",
+            ),
         }
     }
 
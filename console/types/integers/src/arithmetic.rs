@@ -23,9 +23,9 @@ impl<E: Environment, I: IntegerType> Neg for Integer<E, I> {
         match I::is_signed() {
             true => match self.integer.checked_neg() {
                 Some(integer) => Integer::new(integer),
-                None => E::halt(format!("Integer negation failed on: {}", self.integer)),
+                None => E::halt_with(HaltCode::Overflow, format!("Integer negation failed on: {}", self.integer)),
             },
-            false => E::halt("Negation of unsigned integers is not supported."),
+            false => E::halt_with(HaltCode::TypeMismatch, "Negation of unsigned integers is not supported."),
         }
     }
 }
@@ -39,7 +39,7 @@ impl<E: Environment, I: IntegerType> AbsChecked for Integer<E, I> {
         match I::is_signed() {
             true => match self.integer.checked_abs() {
                 Some(integer) => Integer::new(integer),
-                None => E::halt(format!("Integer absolute value failed on: {}", self.integer)),
+                None => E::halt_with(HaltCode::Overflow, format!("Integer absolute value failed on: {}", self.integer)),
             },
             false => self,
         }
@@ -67,7 +67,7 @@ impl<E: Environment, I: IntegerType> Add<Integer<E, I>> for Integer<E, I> {
     fn add(self, other: Integer<E, I>) -> Self::Output {
         match self.integer.checked_add(&other.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer addition failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer addition failed on: {self} and {other}")),
         }
     }
 }
@@ -80,7 +80,7 @@ impl<E: Environment, I: IntegerType> Add<&Integer<E, I>> for Integer<E, I> {
     fn add(self, other: &Integer<E, I>) -> Self::Output {
         match self.integer.checked_add(&other.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer addition failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer addition failed on: {self} and {other}")),
         }
     }
 }
@@ -101,7 +101,7 @@ impl<E: Environment, I: IntegerType> AddAssign<Integer<E, I>> for Integer<E, I>
     fn add_assign(&mut self, other: Integer<E, I>) {
         match self.integer.checked_add(&other.integer) {
             Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer addition failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer addition failed on: {self} and {other}")),
         }
     }
 }
@@ -112,7 +112,7 @@ impl<E: Environment, I: IntegerType> AddAssign<&Integer<E, I>> for Integer<E, I>
     fn add_assign(&mut self, other: &Integer<E, I>) {
         match self.integer.checked_add(&other.integer) {
             Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer addition failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer addition failed on: {self} and {other}")),
         }
     }
 }
@@ -125,7 +125,7 @@ impl<E: Environment, I: IntegerType> Sub<Integer<E, I>> for Integer<E, I> {
     fn sub(self, other: Integer<E, I>) -> Self::Output {
         match self.integer.checked_sub(&other.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer subtraction failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer subtraction failed on: {self} and {other}")),
         }
     }
 }
@@ -138,7 +138,7 @@ impl<E: Environment, I: IntegerType> Sub<&Integer<E, I>> for Integer<E, I> {
     fn sub(self, other: &Integer<E, I>) -> Self::Output {
         match self.integer.checked_sub(&other.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer subtraction failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer subtraction failed on: {self} and {other}")),
         }
     }
 }
@@ -159,7 +159,7 @@ impl<E: Environment, I: IntegerType> SubAssign<Integer<E, I>> for Integer<E, I>
     fn sub_assign(&mut self, other: Integer<E, I>) {
         match self.integer.checked_sub(&other.integer) {
             Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer subtraction failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer subtraction failed on: {self} and {other}")),
         }
     }
 }
@@ -170,7 +170,7 @@ impl<E: Environment, I: IntegerType> SubAssign<&Integer<E, I>> for Integer<E, I>
     fn sub_assign(&mut self, other: &Integer<E, I>) {
         match self.integer.checked_sub(&other.integer) {
             Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer subtraction failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer subtraction failed on: {self} and {other}")),
         }
     }
 }
@@ -183,7 +183,7 @@ impl<E: Environment, I: IntegerType> Mul<Integer<E, I>> for Integer<E, I> {
     fn mul(self, other: Integer<E, I>) -> Self::Output {
         match self.integer.checked_mul(&other.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer multiplication failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer multiplication failed on: {self} and {other}")),
         }
     }
 }
@@ -196,7 +196,7 @@ impl<E: Environment, I: IntegerType> Mul<&Integer<E, I>> for Integer<E, I> {
     fn mul(self, other: &Integer<E, I>) -> Self::Output {
         match self.integer.checked_mul(&other.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer multiplication failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer multiplication failed on: {self} and {other}")),
         }
     }
 }
@@ -217,7 +217,7 @@ impl<E: Environment, I: IntegerType> MulAssign<Integer<E, I>> for Integer<E, I>
     fn mul_assign(&mut self, other: Integer<E, I>) {
         match self.integer.checked_mul(&other.integer) {
             Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer multiplication failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer multiplication failed on: {self} and {other}")),
         }
     }
 }
@@ -228,7 +228,7 @@ impl<E: Environment, I: IntegerType> MulAssign<&Integer<E, I>> for Integer<E, I>
     fn mul_assign(&mut self, other: &Integer<E, I>) {
         match self.integer.checked_mul(&other.integer) {
             Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer multiplication failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer multiplication failed on: {self} and {other}")),
         }
     }
 }
@@ -241,7 +241,10 @@ impl<E: Environment, I: IntegerType> Div<Integer<E, I>> for Integer<E, I> {
     fn div(self, other: Integer<E, I>) -> Self::Output {
         match self.integer.checked_div(&other.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer division failed on: {self} and {other}")),
+            None if other.is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, format!("Integer division failed on: {self} and {other}"))
+            }
+            None => E::halt_with(HaltCode::Overflow, format!("Integer division failed on: {self} and {other}")),
         }
     }
 }
@@ -254,7 +257,10 @@ impl<E: Environment, I: IntegerType> Div<&Integer<E, I>> for Integer<E, I> {
     fn div(self, other: &Integer<E, I>) -> Self::Output {
         match self.integer.checked_div(&other.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer division failed on: {self} and {other}")),
+            None if other.is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, format!("Integer division failed on: {self} and {other}"))
+            }
+            None => E::halt_with(HaltCode::Overflow, format!("Integer division failed on: {self} and {other}")),
         }
     }
 }
@@ -266,7 +272,7 @@ impl<E: Environment, I: IntegerType> DivWrapped<Integer<E, I>> for Integer<E, I>
     #[inline]
     fn div_wrapped(&self, other: &Integer<E, I>) -> Self::Output {
         match other.is_zero() {
-            true => E::halt(format!("Integer division by zero: {self} / {other}")),
+            true => E::halt_with(HaltCode::DivisionByZero, format!("Integer division by zero: {self} / {other}")),
             false => Integer::new(self.integer.wrapping_div(&other.integer)),
         }
     }
@@ -278,7 +284,10 @@ impl<E: Environment, I: IntegerType> DivAssign<Integer<E, I>> for Integer<E, I>
     fn div_assign(&mut self, other: Integer<E, I>) {
         match self.integer.checked_div(&other.integer) {
             Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer division failed on: {self} and {other}")),
+            None if other.is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, format!("Integer division failed on: {self} and {other}"))
+            }
+            None => E::halt_with(HaltCode::Overflow, format!("Integer division failed on: {self} and {other}")),
         }
     }
 }
@@ -289,7 +298,10 @@ impl<E: Environment, I: IntegerType> DivAssign<&Integer<E, I>> for Integer<E, I>
     fn div_assign(&mut self, other: &Integer<E, I>) {
         match self.integer.checked_div(&other.integer) {
             Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer division failed on: {self} and {other}")),
+            None if other.is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, format!("Integer division failed on: {self} and {other}"))
+            }
+            None => E::halt_with(HaltCode::Overflow, format!("Integer division failed on: {self} and {other}")),
         }
     }
 }
@@ -301,9 +313,9 @@ impl<E: Environment, I: IntegerType> Modulo<Integer<E, I>> for Integer<E, I> {
     #[inline]
     fn modulo(&self, other: &Integer<E, I>) -> Self {
         match I::is_signed() {
-            true => E::halt("Taking the modulus of signed integers is not supported"),
+            true => E::halt_with(HaltCode::TypeMismatch, "Taking the modulus of signed integers is not supported"),
             false => match other.is_zero() {
-                true => E::halt(format!("Integer modulus by zero: {self} % {other}")),
+                true => E::halt_with(HaltCode::DivisionByZero, format!("Integer modulus by zero: {self} % {other}")),
                 false => Integer::new(self.integer.modulo(&other.integer)),
             },
         }
@@ -318,7 +330,10 @@ impl<E: Environment, I: IntegerType> Rem<Integer<E, I>> for Integer<E, I> {
     fn rem(self, other: Integer<E, I>) -> Self {
         match self.integer.checked_rem(&other.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer remainder failed on: {self} and {other}")),
+            None if other.is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, format!("Integer remainder failed on: {self} and {other}"))
+            }
+            None => E::halt_with(HaltCode::Overflow, format!("Integer remainder failed on: {self} and {other}")),
         }
     }
 }
@@ -331,7 +346,10 @@ impl<E: Environment, I: IntegerType> Rem<&Integer<E, I>> for Integer<E, I> {
     fn rem(self, other: &Integer<E, I>) -> Self {
         match self.integer.checked_rem(&other.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer remainder failed on: {self} and {other}")),
+            None if other.is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, format!("Integer remainder failed on: {self} and {other}"))
+            }
+            None => E::halt_with(HaltCode::Overflow, format!("Integer remainder failed on: {self} and {other}")),
         }
     }
 }
@@ -343,7 +361,7 @@ impl<E: Environment, I: IntegerType> RemWrapped<Integer<E, I>> for Integer<E, I>
     #[inline]
     fn rem_wrapped(&self, other: &Integer<E, I>) -> Self::Output {
         match other.is_zero() {
-            true => E::halt(format!("Integer remainder by zero: {self} % {other}")),
+            true => E::halt_with(HaltCode::DivisionByZero, format!("Integer remainder by zero: {self} % {other}")),
             false => Integer::new(self.integer.wrapping_rem(&other.integer)),
         }
     }
@@ -355,7 +373,10 @@ impl<E: Environment, I: IntegerType> RemAssign<Integer<E, I>> for Integer<E, I>
     fn rem_assign(&mut self, other: Integer<E, I>) {
         match self.integer.checked_rem(&other.integer) {
             Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer remainder failed on: {self} and {other}")),
+            None if other.is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, format!("Integer remainder failed on: {self} and {other}"))
+            }
+            None => E::halt_with(HaltCode::Overflow, format!("Integer remainder failed on: {self} and {other}")),
         }
     }
 }
@@ -366,7 +387,10 @@ impl<E: Environment, I: IntegerType> RemAssign<&Integer<E, I>> for Integer<E, I>
     fn rem_assign(&mut self, other: &Integer<E, I>) {
         match self.integer.checked_rem(&other.integer) {
             Some(integer) => self.integer = integer,
-            None => E::halt(format!("Integer remainder failed on: {self} and {other}")),
+            None if other.is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, format!("Integer remainder failed on: {self} and {other}"))
+            }
+            None => E::halt_with(HaltCode::Overflow, format!("Integer remainder failed on: {self} and {other}")),
         }
     }
 }
@@ -380,7 +404,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Pow<Integer<E, M>> for Intege
         match self.integer.checked_pow(&other.integer.to_u32().unwrap()) {
             // Unwrap is safe as we only cast up.
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer power failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer power failed on: {self} and {other}")),
         }
     }
 }
@@ -394,7 +418,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Pow<&Integer<E, M>> for Integ
         match self.integer.checked_pow(&other.integer.to_u32().unwrap()) {
             // Unwrap is safe as we only cast up.
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer power failed on: {self} and {other}")),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer power failed on: {self} and {other}")),
         }
     }
 }
@@ -417,7 +441,35 @@ impl<E: Environment, I: IntegerType> Square for Integer<E, I> {
     fn square(&self) -> Self::Output {
         match self.integer.checked_mul(&self.integer) {
             Some(integer) => Integer::new(integer),
-            None => E::halt(format!("Integer square failed on: {}", self.integer)),
+            None => E::halt_with(HaltCode::Overflow, format!("Integer square failed on: {}", self.integer)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    /// Returns the halt code of the given operation, which is expected to halt.
+    fn halt_code<T>(operation: impl FnOnce() -> T + std::panic::UnwindSafe) -> HaltCode {
+        HaltError::from_panic(std::panic::catch_unwind(operation).unwrap_err()).code()
+    }
+
+    #[test]
+    fn test_halt_codes() {
+        let zero = Integer::<CurrentEnvironment, u8>::zero();
+        let one = Integer::<CurrentEnvironment, u8>::one();
+        let max = Integer::<CurrentEnvironment, u8>::MAX;
+        let min = Integer::<CurrentEnvironment, i8>::MIN;
+
+        assert_eq!(halt_code(|| max + one), HaltCode::Overflow);
+        assert_eq!(halt_code(|| zero - one), HaltCode::Overflow);
+        assert_eq!(halt_code(|| min / -Integer::one()), HaltCode::Overflow);
+        assert_eq!(halt_code(|| one / zero), HaltCode::DivisionByZero);
+        assert_eq!(halt_code(|| one % zero), HaltCode::DivisionByZero);
+        assert_eq!(halt_code(|| -one), HaltCode::TypeMismatch);
+    }
+}
@@ -155,7 +155,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Shl<Integer<E, M>> for Intege
         // Unwrap is safe as we only cast up.
         match self.integer.checked_shl(&n.integer.to_u32().unwrap()) {
             Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} left by {n} bits")),
+            None => E::halt_with(HaltCode::RangeViolation, format!("Failed to shift {self} left by {n} bits")),
         }
     }
 }
@@ -169,7 +169,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Shl<&Integer<E, M>> for Integ
         // Unwrap is safe as we only cast up.
         match self.integer.checked_shl(&n.integer.to_u32().unwrap()) {
             Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} left by {n} bits")),
+            None => E::halt_with(HaltCode::RangeViolation, format!("Failed to shift {self} left by {n} bits")),
         }
     }
 }
@@ -183,7 +183,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlChecked<Integer<E, M>> for
         // Unwrap is safe as we only cast up.
         match self.integer.checked_shl(&n.integer.to_u32().unwrap()) {
             Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} left by {n} bits")),
+            None => E::halt_with(HaltCode::RangeViolation, format!("Failed to shift {self} left by {n} bits")),
         }
     }
 }
@@ -207,7 +207,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlAssign<Integer<E, M>> for
             Some(shifted) => {
                 self.integer = shifted;
             }
-            None => E::halt(format!("Failed to shift {self} left by {n} bits")),
+            None => E::halt_with(HaltCode::RangeViolation, format!("Failed to shift {self} left by {n} bits")),
         }
     }
 }
@@ -221,7 +221,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Shr<Integer<E, M>> for Intege
         match self.integer.checked_shr(n.integer.to_u32().unwrap()) {
             // Unwrap is safe as we only cast up.
             Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} right by {n} bits")),
+            None => E::halt_with(HaltCode::RangeViolation, format!("Failed to shift {self} right by {n} bits")),
         }
     }
 }
@@ -235,7 +235,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Shr<&Integer<E, M>> for Integ
         match self.integer.checked_shr(n.integer.to_u32().unwrap()) {
             // Unwrap is safe as we only cast up.
             Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} right by {n} bits")),
+            None => E::halt_with(HaltCode::RangeViolation, format!("Failed to shift {self} right by {n} bits")),
         }
     }
 }
@@ -248,7 +248,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrChecked<Integer<E, M>> for
     fn shr_checked(&self, n: &Integer<E, M>) -> Self::Output {
         match self.integer.checked_shr(n.integer.to_u32().unwrap()) {
             Some(shifted) => Integer::new(shifted),
-            None => E::halt(format!("Failed to shift {self} right by {n} bits")),
+            None => E::halt_with(HaltCode::RangeViolation, format!("Failed to shift {self} right by {n} bits")),
         }
     }
 }
@@ -272,7 +272,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrAssign<Integer<E, M>> for
             Some(shifted) => {
                 self.integer = shifted;
             }
-            None => E::halt(format!("Failed to shift {self} right by {n} bits")),
+            None => E::halt_with(HaltCode::RangeViolation, format!("Failed to shift {self} right by {n} bits")),
         }
     }
 }
@@ -289,3 +289,42 @@ impl<E: Environment, I: IntegerType> Ternary for Integer<E, I> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    /// Returns the halt code of the given operation, which is expected to halt.
+    fn halt_code<T>(operation: impl FnOnce() -> T + std::panic::UnwindSafe) -> HaltCode {
+        HaltError::from_panic(std::panic::catch_unwind(operation).unwrap_err()).code()
+    }
+
+    #[test]
+    fn test_shift_halt_codes() {
+        let one = Integer::<CurrentEnvironment, u8>::one();
+        let eight = Integer::<CurrentEnvironment, u8>::new(8);
+
+        // Ensure shifting by at least the bit width halts with a range violation, in both directions.
+        assert_eq!(halt_code(|| one << eight), HaltCode::RangeViolation);
+        assert_eq!(halt_code(|| one.shl_checked(&eight)), HaltCode::RangeViolation);
+        assert_eq!(halt_code(|| one >> eight), HaltCode::RangeViolation);
+        assert_eq!(halt_code(|| one.shr_checked(&eight)), HaltCode::RangeViolation);
+        assert_eq!(
+            halt_code(|| {
+                let mut integer = one;
+                integer <<= eight;
+            }),
+            HaltCode::RangeViolation
+        );
+        assert_eq!(
+            halt_code(|| {
+                let mut integer = one;
+                integer >>= eight;
+            }),
+            HaltCode::RangeViolation
+        );
+    }
+}
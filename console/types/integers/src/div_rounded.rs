@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the `quotient` of `self` and `other`, rounded towards positive infinity.
+    ///
+    /// This halts if `other` is zero, or if the quotient overflows (i.e. `MIN / -1`).
+    #[inline]
+    pub fn div_ceil(&self, other: &Integer<E, I>) -> Self {
+        let (quotient, remainder) = self.div_rem(other);
+        // The truncated quotient is rounded up if the remainder is nonzero and the exact quotient is positive,
+        // which is when the remainder (which takes the sign of `self`) and `other` have the same sign.
+        // Note: This never overflows, as a nonzero remainder implies that `|other| >= 2`.
+        match !remainder.is_zero() && (remainder < I::zero()) == (other.integer < I::zero()) {
+            true => Integer::new(quotient.wrapping_add(&I::one())),
+            false => Integer::new(quotient),
+        }
+    }
+
+    /// Returns the `quotient` of `self` and `other`, rounded towards negative infinity.
+    ///
+    /// For unsigned integers, this is equivalent to `self / other`.
+    /// For signed integers, this differs from `self / other` when the exact quotient is negative and inexact,
+    /// e.g. `-7 / 2` is `-3`, while `(-7).div_floor(2)` is `-4`.
+    ///
+    /// This halts if `other` is zero, or if the quotient overflows (i.e. `MIN / -1`).
+    #[inline]
+    pub fn div_floor(&self, other: &Integer<E, I>) -> Self {
+        let (quotient, remainder) = self.div_rem(other);
+        // The truncated quotient is rounded down if the remainder is nonzero and the exact quotient is negative,
+        // which is when the remainder (which takes the sign of `self`) and `other` have different signs.
+        // Note: This never overflows, as a nonzero remainder implies that `|other| >= 2`.
+        match !remainder.is_zero() && (remainder < I::zero()) != (other.integer < I::zero()) {
+            true => Integer::new(quotient.wrapping_sub(&I::one())),
+            false => Integer::new(quotient),
+        }
+    }
+
+    /// Returns `true` if `self` is a multiple of `other`.
+    ///
+    /// Zero is a multiple of every integer, and only zero is a multiple of zero.
+    #[inline]
+    pub fn is_multiple_of(&self, other: &Integer<E, I>) -> Boolean<E> {
+        match other.integer.is_zero() {
+            true => Boolean::new(self.integer.is_zero()),
+            // Note: `wrapping_rem` is used, as `MIN % -1` is zero, but overflows in `checked_rem`.
+            false => Boolean::new(self.integer.wrapping_rem(&other.integer).is_zero()),
+        }
+    }
+
+    /// Returns the truncated `quotient` and `remainder` of `self` and `other`.
+    #[inline]
+    fn div_rem(&self, other: &Integer<E, I>) -> (I, I) {
+        match (self.integer.checked_div(&other.integer), self.integer.checked_rem(&other.integer)) {
+            (Some(quotient), Some(remainder)) => (quotient, remainder),
+            _ if other.is_zero() => {
+                E::halt_with(HaltCode::DivisionByZero, format!("Integer division by zero: {self} / {other}"))
+            }
+            _ => E::halt_with(HaltCode::Overflow, format!("Integer division failed on: {self} and {other}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    /// Returns the reference floor and ceiling of `a / b`, computed over the rationals.
+    #[allow(clippy::cast_possible_truncation)]
+    fn reference(a: i32, b: i32) -> (i32, i32) {
+        let quotient = f64::from(a) / f64::from(b);
+        (quotient.floor() as i32, quotient.ceil() as i32)
+    }
+
+    #[test]
+    fn test_u8_exhaustive() {
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                let first = Integer::<CurrentEnvironment, u8>::new(a);
+                let second = Integer::<CurrentEnvironment, u8>::new(b);
+
+                assert_eq!(*first.is_multiple_of(&second), if b == 0 { a == 0 } else { a % b == 0 });
+                if b != 0 {
+                    let (floor, ceil) = reference(i32::from(a), i32::from(b));
+                    assert_eq!(i32::from(*first.div_floor(&second)), floor, "{a}.div_floor({b})");
+                    assert_eq!(i32::from(*first.div_ceil(&second)), ceil, "{a}.div_ceil({b})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_i8_exhaustive() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let first = Integer::<CurrentEnvironment, i8>::new(a);
+                let second = Integer::<CurrentEnvironment, i8>::new(b);
+
+                let expected = if b == 0 { a == 0 } else { i32::from(a) % i32::from(b) == 0 };
+                assert_eq!(*first.is_multiple_of(&second), expected);
+                // Skip the cases that halt.
+                if b != 0 && !(a == i8::MIN && b == -1) {
+                    let (floor, ceil) = reference(i32::from(a), i32::from(b));
+                    assert_eq!(i32::from(*first.div_floor(&second)), floor, "{a}.div_floor({b})");
+                    assert_eq!(i32::from(*first.div_ceil(&second)), ceil, "{a}.div_ceil({b})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_div_halts() {
+        let one = Integer::<CurrentEnvironment, i8>::one();
+        let zero = Integer::<CurrentEnvironment, i8>::zero();
+        let min = Integer::<CurrentEnvironment, i8>::MIN;
+
+        // Ensure division by zero halts.
+        assert!(std::panic::catch_unwind(|| one.div_ceil(&zero)).is_err());
+        assert!(std::panic::catch_unwind(|| one.div_floor(&zero)).is_err());
+        // Ensure the overflowing division halts.
+        assert!(std::panic::catch_unwind(|| min.div_ceil(&-one)).is_err());
+        assert!(std::panic::catch_unwind(|| min.div_floor(&-one)).is_err());
+    }
+}
@@ -19,6 +19,7 @@ mod arithmetic;
 mod bitwise;
 mod bytes;
 mod compare;
+mod div_rounded;
 mod from_bits;
 mod from_field;
 mod from_field_lossy;
@@ -139,7 +139,7 @@ impl<E: Environment> Div<Scalar<E>> for Scalar<E> {
     #[inline]
     fn div(self, other: Scalar<E>) -> Self::Output {
         match other.is_zero() {
-            true => E::halt(format!("Scalar division by zero: {self} / {other}")),
+            true => E::halt_with(HaltCode::DivisionByZero, format!("Scalar division by zero: {self} / {other}")),
             false => Scalar::new(self.scalar / other.scalar),
         }
     }
@@ -152,7 +152,7 @@ impl<E: Environment> Div<&Scalar<E>> for Scalar<E> {
     #[inline]
     fn div(self, other: &Scalar<E>) -> Self::Output {
         match other.is_zero() {
-            true => E::halt(format!("Scalar division by zero: {self} / {other}")),
+            true => E::halt_with(HaltCode::DivisionByZero, format!("Scalar division by zero: {self} / {other}")),
             false => Scalar::new(self.scalar / other.scalar),
         }
     }
@@ -163,7 +163,7 @@ impl<E: Environment> DivAssign<Scalar<E>> for Scalar<E> {
     #[inline]
     fn div_assign(&mut self, other: Scalar<E>) {
         match other.is_zero() {
-            true => E::halt(format!("Scalar division by zero: {self} / {other}")),
+            true => E::halt_with(HaltCode::DivisionByZero, format!("Scalar division by zero: {self} / {other}")),
             false => self.scalar /= other.scalar,
         }
     }
@@ -174,7 +174,7 @@ impl<E: Environment> DivAssign<&Scalar<E>> for Scalar<E> {
     #[inline]
     fn div_assign(&mut self, other: &Scalar<E>) {
         match other.is_zero() {
-            true => E::halt(format!("Scalar division by zero: {self} / {other}")),
+            true => E::halt_with(HaltCode::DivisionByZero, format!("Scalar division by zero: {self} / {other}")),
             false => self.scalar /= other.scalar,
         }
     }
@@ -20,11 +20,16 @@ impl<N: Network> FromBytes for Ratify<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        // Note: Version 2 appends a chain spec ID to the genesis ratification.
+        if version != 1 && version != 2 {
             return Err(error("Invalid ratify version"));
         }
 
         let variant = Variant::read_le(&mut reader)?;
+        // Ensure only the genesis ratification uses version 2.
+        if version == 2 && variant != 0 {
+            return Err(error(format!("Invalid ratify version for ratify object variant {variant}")));
+        }
         let ratify = match variant {
             0 => {
                 // Read the committee.
@@ -57,8 +62,13 @@ impl<N: Network> FromBytes for Ratify<N> {
                     // Insert the bonded balance.
                     bonded_balances.insert(address, (validator_address, withdrawal_address, amount));
                 }
+                // Read the chain spec ID, if any.
+                let spec_id = match version {
+                    2 => Some(Box::new(Field::read_le(&mut reader)?)),
+                    _ => None,
+                };
                 // Return the ratify object.
-                Self::Genesis(Box::new(committee), Box::new(public_balances), Box::new(bonded_balances))
+                Self::Genesis(Box::new(committee), Box::new(public_balances), Box::new(bonded_balances), spec_id)
             }
             1 => {
                 // Read the amount.
@@ -82,10 +92,15 @@ impl<N: Network> ToBytes for Ratify<N> {
     /// Writes the ratify object to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: The genesis ratification is written in version 2 only if it has a chain spec ID,
+        // so that a genesis ratification without one keeps its version 1 bytes.
+        match self {
+            Self::Genesis(_, _, _, Some(_)) => 2u8.write_le(&mut writer)?,
+            _ => 1u8.write_le(&mut writer)?,
+        }
 
         match self {
-            Self::Genesis(committee, public_balances, bonded_balances) => {
+            Self::Genesis(committee, public_balances, bonded_balances, spec_id) => {
                 (0 as Variant).write_le(&mut writer)?;
                 committee.write_le(&mut writer)?;
                 u16::try_from(public_balances.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
@@ -100,6 +115,9 @@ impl<N: Network> ToBytes for Ratify<N> {
                     withdrawal_address.write_le(&mut writer)?;
                     amount.write_le(&mut writer)?;
                 }
+                if let Some(spec_id) = spec_id {
+                    spec_id.write_le(&mut writer)?;
+                }
                 Ok(())
             }
             Self::BlockReward(amount) => {
@@ -128,4 +146,24 @@ mod tests {
             assert_eq!(expected, Ratify::read_le(&expected_bytes[..]).unwrap());
         }
     }
+
+    #[test]
+    fn test_genesis_version() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::ratify::test_helpers::sample_ratifications(rng) {
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            match expected {
+                // Ensure only a genesis ratification with a chain spec ID is written in version 2.
+                Ratify::Genesis(_, _, _, Some(_)) => assert_eq!(expected_bytes[0], 2),
+                _ => assert_eq!(expected_bytes[0], 1),
+            }
+            // Ensure a ratification that is not a genesis ratification is rejected in version 2.
+            if !matches!(expected, Ratify::Genesis(..)) {
+                let mut candidate_bytes = expected_bytes;
+                candidate_bytes[0] = 2;
+                assert!(Ratify::<console::network::MainnetV0>::read_le(&candidate_bytes[..]).is_err());
+            }
+        }
+    }
 }
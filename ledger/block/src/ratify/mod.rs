@@ -16,7 +16,10 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, types::Address};
+use console::{
+    network::prelude::*,
+    types::{Address, Field},
+};
 use ledger_committee::Committee;
 
 use indexmap::IndexMap;
@@ -28,11 +31,11 @@ type PublicBalances<N> = IndexMap<Address<N>, u64>;
 /// mapping of `staker_address` to `(validator_address, withdrawal_address, amount)`.
 type BondedBalances<N> = IndexMap<Address<N>, (Address<N>, Address<N>, u64)>;
 
-// Note: The size of the `Ratify` object is 32 bytes.
+// Note: The size of the `Ratify` object is 40 bytes.
 #[derive(Clone, PartialEq, Eq)]
 pub enum Ratify<N: Network> {
-    /// The genesis.
-    Genesis(Box<Committee<N>>, Box<PublicBalances<N>>, Box<BondedBalances<N>>),
+    /// The genesis, along with the ID of the chain spec it commits to, unless the chain uses the default chain spec.
+    Genesis(Box<Committee<N>>, Box<PublicBalances<N>>, Box<BondedBalances<N>>, Option<Box<Field<N>>>),
     /// The block reward.
     BlockReward(u64),
     /// The puzzle reward.
@@ -59,14 +62,25 @@ pub(crate) mod test_helpers {
         for (address, _) in committee.members().iter() {
             public_balances.insert(*address, rng.gen());
         }
-        let bonded_balances = committee
+        let bonded_balances: BondedBalances<CurrentNetwork> = committee
             .members()
             .iter()
             .map(|(address, (amount, _))| (*address, (*address, *address, *amount)))
             .collect();
 
         vec![
-            Ratify::Genesis(Box::new(committee), Box::new(public_balances), Box::new(bonded_balances)),
+            Ratify::Genesis(
+                Box::new(committee.clone()),
+                Box::new(public_balances.clone()),
+                Box::new(bonded_balances.clone()),
+                None,
+            ),
+            Ratify::Genesis(
+                Box::new(committee),
+                Box::new(public_balances),
+                Box::new(bonded_balances),
+                Some(Box::new(rng.gen())),
+            ),
             Ratify::BlockReward(rng.gen()),
             Ratify::PuzzleReward(rng.gen()),
         ]
@@ -79,6 +93,6 @@ mod tests {
 
     #[test]
     fn check_ratify_size() {
-        assert_eq!(std::mem::size_of::<Ratify<console::network::MainnetV0>>(), 32);
+        assert_eq!(std::mem::size_of::<Ratify<console::network::MainnetV0>>(), 40);
     }
 }
@@ -19,12 +19,15 @@ impl<N: Network> Serialize for Ratify<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => match self {
-                Self::Genesis(committee, public_balances, bonded_balances) => {
-                    let mut input = serializer.serialize_struct("Ratify", 4)?;
+                Self::Genesis(committee, public_balances, bonded_balances, spec_id) => {
+                    let mut input = serializer.serialize_struct("Ratify", 4 + spec_id.is_some() as usize)?;
                     input.serialize_field("type", "genesis")?;
                     input.serialize_field("committee", &committee)?;
                     input.serialize_field("public_balances", &public_balances)?;
                     input.serialize_field("bonded_balances", &bonded_balances)?;
+                    if let Some(spec_id) = spec_id {
+                        input.serialize_field("spec_id", &spec_id)?;
+                    }
                     input.end()
                 }
                 Self::BlockReward(amount) => {
@@ -64,8 +67,18 @@ impl<'de, N: Network> Deserialize<'de> for Ratify<N> {
                         // Retrieve the bonded balances.
                         let bonded_balances: BondedBalances<N> =
                             DeserializeExt::take_from_value::<D>(&mut object, "bonded_balances")?;
+                        // Retrieve the chain spec ID, if it exists.
+                        let spec_id: Option<Field<N>> = serde_json::from_value(
+                            object.get_mut("spec_id").unwrap_or(&mut serde_json::Value::Null).take(),
+                        )
+                        .map_err(de::Error::custom)?;
                         // Construct the ratify object.
-                        Ratify::Genesis(Box::new(committee), Box::new(public_balances), Box::new(bonded_balances))
+                        Ratify::Genesis(
+                            Box::new(committee),
+                            Box::new(public_balances),
+                            Box::new(bonded_balances),
+                            spec_id.map(Box::new),
+                        )
                     }
                     Some("block_reward") => {
                         // Retrieve the amount.
@@ -369,4 +369,11 @@ mod tests {
             "The maximum number of transmissions in a block is too large"
         );
     }
+
+    #[test]
+    fn test_max_transactions_matches_network() {
+        // Ensure the network limits match the capacities of the transactions tree and the transaction tree.
+        assert_eq!(Transactions::<CurrentNetwork>::MAX_TRANSACTIONS, CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK);
+        assert_eq!(Transaction::<CurrentNetwork>::MAX_TRANSITIONS, CurrentNetwork::MAX_TRANSITIONS_PER_TRANSACTION);
+    }
 }
@@ -25,7 +25,7 @@ use std::collections::HashSet;
 use rayon::prelude::*;
 
 impl<N: Network> Block<N> {
-    /// Ensures the block is correct.
+    /// Ensures the block is correct, for a chain with the given target block time (in seconds).
     pub fn verify(
         &self,
         previous_block: &Block<N>,
@@ -34,6 +34,7 @@ impl<N: Network> Block<N> {
         current_committee_lookback: &Committee<N>,
         current_puzzle: &Puzzle<N>,
         current_epoch_hash: N::BlockHash,
+        block_time: u16,
         current_timestamp: i64,
         ratified_finalize_operations: Vec<FinalizeOperation<N>>,
     ) -> Result<(Vec<SolutionID<N>>, Vec<N::TransactionID>)> {
@@ -64,7 +65,7 @@ impl<N: Network> Block<N> {
             expected_last_coinbase_timestamp,
             expected_block_reward,
             expected_puzzle_reward,
-        ) = self.verify_solutions(previous_block, current_puzzle, current_epoch_hash)?;
+        ) = self.verify_solutions(previous_block, current_puzzle, current_epoch_hash, block_time)?;
 
         // Ensure the block ratifications are correct.
         self.verify_ratifications(expected_block_reward, expected_puzzle_reward)?;
@@ -306,12 +307,13 @@ impl<N: Network> Block<N> {
         previous_block: &Block<N>,
         current_puzzle: &Puzzle<N>,
         current_epoch_hash: N::BlockHash,
+        block_time: u16,
     ) -> Result<(u128, u128, u64, u64, u64, i64, u64, u64)> {
         let height = self.height();
         let timestamp = self.timestamp();
 
         // Ensure the solutions are not accepted after the block height at year 10.
-        if !self.solutions.is_empty() && height > block_height_at_year(N::BLOCK_TIME, 10) {
+        if !self.solutions.is_empty() && height > block_height_at_year(block_time, 10) {
             bail!("Solutions are no longer accepted after the block height at year 10.");
         }
 
@@ -419,7 +421,7 @@ impl<N: Network> Block<N> {
             height,
             N::STARTING_SUPPLY,
             N::ANCHOR_HEIGHT,
-            N::BLOCK_TIME,
+            block_time,
             combined_proof_target,
             u64::try_from(previous_block.cumulative_proof_target())?,
             previous_block.coinbase_target(),
@@ -431,7 +433,7 @@ impl<N: Network> Block<N> {
 
         // Compute the expected block reward.
         let expected_block_reward =
-            block_reward(N::STARTING_SUPPLY, N::BLOCK_TIME, expected_coinbase_reward, expected_transaction_fees);
+            block_reward(N::STARTING_SUPPLY, block_time, expected_coinbase_reward, expected_transaction_fees);
         // Compute the expected puzzle reward.
         let expected_puzzle_reward = puzzle_reward(expected_coinbase_reward);
 
@@ -53,6 +53,8 @@ mod tests {
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Solution::read_le(&expected_bytes[..])?);
+        // Ensure the solution size matches the network limit, from which the maximum block size is derived.
+        assert_eq!(expected_bytes.len(), CurrentNetwork::MAX_SOLUTION_SIZE_IN_BYTES);
         assert!(Solution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
 
         Ok(())
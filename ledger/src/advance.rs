@@ -192,7 +192,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
                 let latest_proof_target = self.latest_proof_target();
                 // Separate the candidate solutions into valid and aborted solutions.
                 let (valid_candidate_solutions, aborted_candidate_solutions) =
-                    split_candidate_solutions(candidate_solutions, N::MAX_SOLUTIONS, |solution| {
+                    split_candidate_solutions(candidate_solutions, self.spec.max_solutions_per_block(), |solution| {
                         self.puzzle().check_solution(solution, latest_epoch_hash, latest_proof_target).is_ok()
                     });
 
@@ -292,7 +292,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             next_height,
             N::STARTING_SUPPLY,
             N::ANCHOR_HEIGHT,
-            N::BLOCK_TIME,
+            self.spec.block_time(),
             combined_proof_target,
             u64::try_from(latest_cumulative_proof_target)?,
             latest_coinbase_target,
@@ -306,15 +306,27 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             next_cumulative_proof_target,
             previous_block.hash(),
         )?;
+        // Separate the candidate transactions that do not satisfy the chain spec, as they must be aborted.
+        let (candidate_transactions, spec_aborted_transactions): (Vec<_>, Vec<_>) = candidate_transactions
+            .into_iter()
+            .partition(|transaction| self.spec.check_transaction(transaction).is_ok());
         // Speculate over the ratifications, solutions, and transactions.
-        let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = self.vm.speculate(
-            state,
-            Some(coinbase_reward),
-            candidate_ratifications,
-            &solutions,
-            candidate_transactions.iter(),
-            rng,
-        )?;
+        let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) =
+            self.vm.speculate_with_block_time(
+                state,
+                Some(coinbase_reward),
+                self.spec.block_time(),
+                candidate_ratifications,
+                &solutions,
+                candidate_transactions.iter(),
+                rng,
+            )?;
+        // Include the transactions that do not satisfy the chain spec in the aborted transaction IDs.
+        let aborted_transaction_ids = spec_aborted_transactions
+            .iter()
+            .map(|transaction| transaction.id())
+            .chain(aborted_transaction_ids)
+            .collect::<Vec<_>>();
 
         // Compute the ratifications root.
         let ratifications_root = ratifications.to_ratifications_root()?;
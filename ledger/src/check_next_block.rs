@@ -36,6 +36,42 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             }
         }
 
+        // Ensure the block does not exceed the maximum number of solutions in the chain spec.
+        if block.solutions().len() > self.spec.max_solutions_per_block() {
+            bail!(
+                "Block {height} contains {} solutions, exceeding the chain spec limit of {}",
+                block.solutions().len(),
+                self.spec.max_solutions_per_block()
+            );
+        }
+
+        // Ensure the block does not exceed the maximum number of transactions in the chain spec.
+        if block.transactions().len() > self.spec.max_transactions_per_block() {
+            bail!(
+                "Block {height} contains {} transactions, exceeding the chain spec limit of {}",
+                block.transactions().len(),
+                self.spec.max_transactions_per_block()
+            );
+        }
+
+        // Ensure the block does not exceed the maximum block size in the chain spec.
+        let block_size_in_bytes = crate::helpers::size_in_bytes(block)?;
+        if block_size_in_bytes > self.spec.max_block_size_in_bytes() {
+            bail!(
+                "Block {height} is {block_size_in_bytes} bytes, exceeding the chain spec limit of {} bytes",
+                self.spec.max_block_size_in_bytes()
+            );
+        }
+
+        // Ensure the transaction fees match the transaction fees claimed in the block reward.
+        // Note: The bounds of each fee are checked when the transactions are speculated.
+        self.check_claimed_transaction_fees(block)?;
+
+        // Ensure the transactions satisfy the chain spec.
+        for transaction in block.transactions().iter() {
+            self.spec.check_transaction(transaction.transaction())?;
+        }
+
         // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
         {
             // // Retrieve the latest total supply.
@@ -113,6 +149,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             &committee_lookback,
             self.puzzle(),
             self.latest_epoch_hash()?,
+            self.spec.block_time(),
             OffsetDateTime::now_utc().unix_timestamp(),
             ratified_finalize_operations,
         )?;
@@ -133,4 +170,63 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         Ok(())
     }
+
+    /// Ensures the priority fees of the block transactions sum to the transaction fees claimed in its block reward.
+    fn check_claimed_transaction_fees(&self, block: &Block<N>) -> Result<()> {
+        let height = block.height();
+        // Retrieve the previous block.
+        let previous_block = self.latest_block();
+
+        // Retrieve the block reward from the first block ratification.
+        let claimed_block_reward = match block.ratifications().iter().next() {
+            Some(Ratify::BlockReward(block_reward)) => *block_reward,
+            _ => bail!("Block {height} is invalid - the first ratification must be a block reward"),
+        };
+
+        // Compute the combined proof target.
+        let combined_proof_target = match block.solutions().deref() {
+            Some(solutions) => self.puzzle().get_combined_proof_target(solutions)?,
+            None => 0u128,
+        };
+        // Compute the coinbase reward.
+        let coinbase_reward = coinbase_reward(
+            height,
+            N::STARTING_SUPPLY,
+            N::ANCHOR_HEIGHT,
+            self.spec.block_time(),
+            combined_proof_target,
+            u64::try_from(previous_block.cumulative_proof_target())?,
+            previous_block.coinbase_target(),
+        )?;
+        // Compute the transaction fees claimed in the block reward, which is the sum of the base reward,
+        // a third of the coinbase reward, and the transaction fees.
+        let claimed_transaction_fees = claimed_block_reward
+            .checked_sub(block_reward(N::STARTING_SUPPLY, self.spec.block_time(), coinbase_reward, 0))
+            .ok_or_else(|| anyhow!("Block {height} claims a block reward below its base and coinbase rewards"))?;
+
+        // Sum the transaction fees, and ensure no transaction brings the sum above the claimed transaction fees.
+        // Note: The base fees are burnt, so only the priority fees are claimed in the block reward.
+        let mut transaction_fees = 0u64;
+        for transaction in block.transactions().iter() {
+            let transaction_id = transaction.id();
+            let priority_fee = *transaction
+                .priority_fee_amount()
+                .map_err(|error| anyhow!("Transaction '{transaction_id}' has a malformed fee - {error}"))?;
+            transaction_fees = match transaction_fees.checked_add(priority_fee) {
+                Some(transaction_fees) if transaction_fees <= claimed_transaction_fees => transaction_fees,
+                _ => bail!(
+                    "Transaction '{transaction_id}' pays a priority fee of {priority_fee} microcredits, exceeding the \
+                     {claimed_transaction_fees} microcredits in transaction fees claimed by block {height}"
+                ),
+            };
+        }
+
+        // Ensure the transaction fees match the claimed transaction fees.
+        ensure!(
+            transaction_fees == claimed_transaction_fees,
+            "Block {height} claims {claimed_transaction_fees} microcredits in transaction fees, \
+             but its transactions pay {transaction_fees} microcredits"
+        );
+        Ok(())
+    }
 }
@@ -22,6 +22,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         rejected_id: Option<Field<N>>,
         rng: &mut R,
     ) -> Result<()> {
+        // Ensure the transaction satisfies the chain spec.
+        self.spec.check_transaction(transaction)?;
         self.vm().check_transaction(transaction, rejected_id, rng)
     }
 
@@ -31,6 +33,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         transactions: &[(&Transaction<N>, Option<Field<N>>)],
         rng: &mut R,
     ) -> Result<()> {
+        // Ensure the transactions satisfy the chain spec.
+        for (transaction, _) in transactions {
+            self.spec.check_transaction(transaction)?;
+        }
         self.vm().check_transactions(transactions, rng)
     }
 }
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::Value as JsonValue;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Exports the given table to the writer in the given format.
+    ///
+    /// The output starts with the table name and `TableKind::VERSION`, as described on `ExportFormat`.
+    /// The rows are streamed one block at a time, so the whole table is never held in memory.
+    pub fn export_table<W: Write>(&self, table: TableKind, writer: W, format: ExportFormat) -> Result<()> {
+        let mut writer = TableWriter::new(writer, table, format)?;
+
+        for height in 0..=self.latest_height() {
+            // Retrieve the block.
+            let block = self.get_block(height)?;
+
+            match table {
+                TableKind::Blocks => {
+                    // Compute the total fees in the block.
+                    let mut total_fees = 0u64;
+                    for fee_amount in block.transactions().transaction_fee_amounts() {
+                        total_fees = total_fees
+                            .checked_add(*fee_amount?)
+                            .ok_or_else(|| anyhow!("Total fees overflowed in block {height}"))?;
+                    }
+                    writer.write_row(&[
+                        JsonValue::from(height),
+                        JsonValue::from(block.hash().to_string()),
+                        JsonValue::from(block.timestamp()),
+                        JsonValue::from(block.transactions().len()),
+                        JsonValue::from(total_fees),
+                    ])?;
+                }
+                TableKind::Transactions => {
+                    for confirmed in block.transactions().iter() {
+                        let transaction = confirmed.transaction();
+                        let kind = match transaction {
+                            Transaction::Deploy(..) => "deploy",
+                            Transaction::Execute(..) => "execute",
+                            Transaction::Fee(..) => "fee",
+                        };
+                        writer.write_row(&[
+                            JsonValue::from(transaction.id().to_string()),
+                            JsonValue::from(height),
+                            JsonValue::from(*transaction.fee_amount()?),
+                            JsonValue::from(kind),
+                        ])?;
+                    }
+                }
+                TableKind::Transitions => {
+                    for confirmed in block.transactions().iter() {
+                        let transaction = confirmed.transaction();
+                        // Retrieve the fee transition ID and amount, which are attributed to the fee transition.
+                        let fee = match transaction.fee_transition() {
+                            Some(fee) => Some((*fee.transition_id(), *fee.amount()?)),
+                            None => None,
+                        };
+                        for transition in transaction.transitions() {
+                            let fee_amount = match fee {
+                                Some((fee_id, fee_amount)) if fee_id == *transition.id() => fee_amount,
+                                _ => 0,
+                            };
+                            writer.write_row(&[
+                                JsonValue::from(transition.id().to_string()),
+                                JsonValue::from(transaction.id().to_string()),
+                                JsonValue::from(transition.program_id().to_string()),
+                                JsonValue::from(transition.function_name().to_string()),
+                                JsonValue::from(transition.inputs().len()),
+                                JsonValue::from(transition.outputs().len()),
+                                JsonValue::from(fee_amount),
+                            ])?;
+                        }
+                    }
+                }
+            }
+        }
+
+        writer.flush()
+    }
+}
@@ -43,6 +43,16 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transaction_store().find_transaction_id_from_transition_id(transition_id)
     }
 
+    /// Returns the transaction ID that contains the given `serial number`.
+    pub fn find_transaction_id_from_serial_number(&self, serial_number: &Field<N>) -> Result<Option<N::TransactionID>> {
+        self.vm.transaction_store().find_transaction_id_from_serial_number(serial_number)
+    }
+
+    /// Returns the transaction ID that contains the given `commitment`.
+    pub fn find_transaction_id_from_commitment(&self, commitment: &Field<N>) -> Result<Option<N::TransactionID>> {
+        self.vm.transaction_store().find_transaction_id_from_commitment(commitment)
+    }
+
     /// Returns the transition ID that contains the given `input ID` or `output ID`.
     pub fn find_transition_id(&self, id: &Field<N>) -> Result<N::TransitionID> {
         self.vm.transition_store().find_transition_id(id)
@@ -68,6 +68,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns the blocks in the given block range.
     /// The range is inclusive of the start and exclusive of the end.
     pub fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>> {
+        // Ensure the block range is valid.
+        self.check_block_range(&heights)?;
         cfg_into_iter!(heights).map(|height| self.get_block(height)).collect()
     }
 
@@ -130,6 +132,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the block headers in the given block range, without retrieving the block transactions.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn get_headers(&self, heights: Range<u32>) -> Result<Vec<Header<N>>> {
+        // Ensure the block range is valid.
+        self.check_block_range(&heights)?;
+        // Retrieve the block headers, in ascending block height order.
+        self.vm.block_store().headers_in_range(heights)
+    }
+
     /// Returns the block transactions for the given block height.
     pub fn get_transactions(&self, height: u32) -> Result<Transactions<N>> {
         // If the height is 0, return the genesis block transactions.
@@ -241,6 +252,31 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     pub fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<Option<BatchCertificate<N>>> {
         self.vm.block_store().get_batch_certificate(certificate_id)
     }
+
+    /// Ensures the given block range is at most `MAX_BLOCKS_PER_RANGE` blocks, and does not exceed the latest height.
+    fn check_block_range(&self, heights: &Range<u32>) -> Result<()> {
+        // Ensure the start of the range does not exceed the end.
+        ensure!(heights.start <= heights.end, "Invalid block range {}..{}", heights.start, heights.end);
+        // Ensure the range does not exceed the maximum number of blocks.
+        let num_blocks = heights.end - heights.start;
+        ensure!(
+            num_blocks <= MAX_BLOCKS_PER_RANGE,
+            "Block range {}..{} exceeds the maximum of {MAX_BLOCKS_PER_RANGE} blocks",
+            heights.start,
+            heights.end
+        );
+        // Ensure the range does not exceed the latest height.
+        if num_blocks > 0 {
+            let latest_height = self.latest_height();
+            ensure!(
+                heights.end - 1 <= latest_height,
+                "Block range {}..{} exceeds the latest height {latest_height}",
+                heights.start,
+                heights.end
+            );
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -263,4 +299,31 @@ mod tests {
         // Ensure the genesis block matches.
         assert_eq!(genesis, candidate);
     }
+
+    #[test]
+    fn test_get_blocks_and_headers() {
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(genesis.clone(), StorageMode::Production).unwrap();
+
+        // Ensure an empty range returns no blocks or headers.
+        assert!(ledger.get_blocks(0..0).unwrap().is_empty());
+        assert!(ledger.get_headers(0..0).unwrap().is_empty());
+        // Ensure the range of the genesis block returns the genesis block and header.
+        assert_eq!(ledger.get_blocks(0..1).unwrap(), vec![genesis.clone()]);
+        assert_eq!(ledger.get_headers(0..1).unwrap(), vec![*genesis.header()]);
+        // Ensure a range past the latest height fails.
+        assert!(ledger.get_blocks(0..2).is_err());
+        assert!(ledger.get_headers(1..2).is_err());
+        // Ensure an inverted range fails.
+        #[allow(clippy::reversed_empty_ranges)]
+        let inverted = 1..0;
+        assert!(ledger.get_headers(inverted).is_err());
+        // Ensure a range exceeding the maximum number of blocks fails, even before the latest height is checked.
+        let error = ledger.get_headers(0..MAX_BLOCKS_PER_RANGE + 1).unwrap_err();
+        assert!(error.to_string().contains("exceeds the maximum"));
+        assert!(ledger.get_headers(0..MAX_BLOCKS_PER_RANGE).unwrap_err().to_string().contains("latest height"));
+    }
 }
@@ -0,0 +1,487 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Field};
+use ledger_block::{Block, Ratify, Transaction};
+
+use core::marker::PhantomData;
+
+/// The chain spec captures the ledger policy constants that a devnet may tune without
+/// defining a new `Network`. The default spec reproduces the constants of the `Network`.
+///
+/// A genesis block commits to the spec ID of its chain spec, unless the chain spec is the default,
+/// so that ledgers with different chain specs have different genesis blocks.
+///
+/// Note: Constants that are baked into block metadata or cryptographic parameters
+/// (e.g. the anchor height, coinbase targets, epoch length, and starting supply) remain on the `Network`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, bound = "")]
+pub struct ChainSpec<N: Network> {
+    /// The maximum number of solutions that may be included in a block.
+    max_solutions_per_block: usize,
+    /// The maximum number of bytes in a serialized block.
+    /// Note: If omitted from a JSON document, this defaults to the limit of the `Network`.
+    #[serde(default = "ChainSpec::<N>::default_max_block_size_in_bytes")]
+    max_block_size_in_bytes: usize,
+    /// The maximum number of transactions that may be included in a block.
+    /// Note: If omitted from a JSON document, this defaults to the limit of the `Network`.
+    #[serde(default = "ChainSpec::<N>::default_max_transactions_per_block")]
+    max_transactions_per_block: usize,
+    /// The maximum number of bytes in a serialized transaction.
+    /// Note: If omitted from a JSON document, this defaults to the limit of the `Network`.
+    #[serde(default = "ChainSpec::<N>::default_max_transaction_size_in_bytes")]
+    max_transaction_size_in_bytes: usize,
+    /// The minimum fee (in microcredits) that each transaction in a block must pay.
+    minimum_transaction_fee: u64,
+    /// The target block time in seconds, from which the block and coinbase rewards are scheduled.
+    /// Note: If omitted from a JSON document, this defaults to the block time of the `Network`.
+    #[serde(default = "ChainSpec::<N>::default_block_time")]
+    block_time: u16,
+    /// PhantomData.
+    #[serde(skip)]
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> Default for ChainSpec<N> {
+    /// Returns the chain spec for the constants of the `Network`.
+    fn default() -> Self {
+        Self {
+            max_solutions_per_block: N::MAX_SOLUTIONS,
+            max_block_size_in_bytes: N::MAX_BLOCK_SIZE_IN_BYTES,
+            max_transactions_per_block: N::MAX_TRANSACTIONS_PER_BLOCK,
+            max_transaction_size_in_bytes: N::MAX_TRANSACTION_SIZE_IN_BYTES,
+            minimum_transaction_fee: 0,
+            block_time: N::BLOCK_TIME,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<N: Network> ChainSpec<N> {
+    /// The version of the chain spec, which is committed into the spec ID.
+    const VERSION: u8 = 3;
+
+    /// Initializes a new chain spec, ensuring the values are within range.
+    pub fn new(
+        max_solutions_per_block: usize,
+        max_block_size_in_bytes: usize,
+        max_transactions_per_block: usize,
+        max_transaction_size_in_bytes: usize,
+        minimum_transaction_fee: u64,
+        block_time: u16,
+    ) -> Result<Self> {
+        let spec = Self {
+            max_solutions_per_block,
+            max_block_size_in_bytes,
+            max_transactions_per_block,
+            max_transaction_size_in_bytes,
+            minimum_transaction_fee,
+            block_time,
+            _phantom: PhantomData,
+        };
+        spec.check()?;
+        Ok(spec)
+    }
+
+    /// Initializes a chain spec from the given JSON document, rejecting unknown fields and out-of-range values.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let spec: Self = serde_json::from_str(json).map_err(|e| anyhow!("Failed to parse the chain spec - {e}"))?;
+        spec.check()?;
+        Ok(spec)
+    }
+
+    /// Initializes a chain spec from the given TOML document, rejecting unknown fields and out-of-range values.
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        let spec: Self = toml::from_str(toml).map_err(|e| anyhow!("Failed to parse the chain spec - {e}"))?;
+        spec.check()?;
+        Ok(spec)
+    }
+
+    /// Returns the maximum number of solutions that may be included in a block.
+    pub const fn max_solutions_per_block(&self) -> usize {
+        self.max_solutions_per_block
+    }
+
+    /// Returns the maximum number of bytes in a serialized block.
+    pub const fn max_block_size_in_bytes(&self) -> usize {
+        self.max_block_size_in_bytes
+    }
+
+    /// Returns the maximum number of transactions that may be included in a block.
+    pub const fn max_transactions_per_block(&self) -> usize {
+        self.max_transactions_per_block
+    }
+
+    /// Returns the maximum number of bytes in a serialized transaction.
+    pub const fn max_transaction_size_in_bytes(&self) -> usize {
+        self.max_transaction_size_in_bytes
+    }
+
+    /// Returns the minimum fee (in microcredits) that each transaction in a block must pay.
+    pub const fn minimum_transaction_fee(&self) -> u64 {
+        self.minimum_transaction_fee
+    }
+
+    /// Returns the target block time in seconds.
+    pub const fn block_time(&self) -> u16 {
+        self.block_time
+    }
+
+    /// Returns the spec ID, which is the BHP hash of the canonical byte representation of the spec.
+    pub fn spec_id(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Returns the spec ID that a genesis block for this chain spec commits to,
+    /// or `None` for the default chain spec, whose genesis block commits to no spec ID.
+    pub fn genesis_spec_id(&self) -> Result<Option<Field<N>>> {
+        match *self == Self::default() {
+            true => Ok(None),
+            false => Ok(Some(self.spec_id()?)),
+        }
+    }
+
+    /// Ensures the given genesis block commits to the spec ID of this chain spec.
+    pub fn check_genesis_block(&self, genesis_block: &Block<N>) -> Result<()> {
+        // Retrieve the spec ID that the genesis block commits to.
+        let committed_spec_id = match genesis_block.ratifications().iter().next() {
+            Some(Ratify::Genesis(_, _, _, spec_id)) => spec_id.as_deref().copied(),
+            _ => bail!("Incorrect genesis block: the first ratification must be the genesis ratification"),
+        };
+        // Ensure the genesis block commits to the spec ID of this chain spec.
+        let expected_spec_id = self.genesis_spec_id()?;
+        ensure!(
+            committed_spec_id == expected_spec_id,
+            "Incorrect chain spec: the genesis block commits to {}, but the ledger expects {}",
+            Self::describe_spec_id(committed_spec_id),
+            Self::describe_spec_id(expected_spec_id)
+        );
+        Ok(())
+    }
+
+    /// Ensures the given transaction satisfies the chain spec.
+    ///
+    /// Note: Each limit is only checked when it is tighter than the `Network`, so the default spec
+    /// accepts and rejects exactly the transactions that the `Network` does.
+    pub fn check_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Ensure the transaction does not exceed the maximum transaction size.
+        if self.max_transaction_size_in_bytes < N::MAX_TRANSACTION_SIZE_IN_BYTES {
+            let transaction_size_in_bytes = super::size_in_bytes(transaction)?;
+            ensure!(
+                transaction_size_in_bytes <= self.max_transaction_size_in_bytes,
+                "Transaction '{}' is {transaction_size_in_bytes} bytes, exceeding the chain spec limit of {} bytes",
+                transaction.id(),
+                self.max_transaction_size_in_bytes
+            );
+        }
+        // Ensure the transaction pays at least the minimum fee.
+        if self.minimum_transaction_fee > 0 {
+            let fee_amount = *transaction.fee_amount()?;
+            ensure!(
+                fee_amount >= self.minimum_transaction_fee,
+                "Transaction '{}' pays {fee_amount} microcredits, below the minimum fee of {} microcredits",
+                transaction.id(),
+                self.minimum_transaction_fee
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the maximum number of bytes in a serialized block, for the `Network`.
+    const fn default_max_block_size_in_bytes() -> usize {
+        N::MAX_BLOCK_SIZE_IN_BYTES
+    }
+
+    /// Returns the maximum number of transactions per block, for the `Network`.
+    const fn default_max_transactions_per_block() -> usize {
+        N::MAX_TRANSACTIONS_PER_BLOCK
+    }
+
+    /// Returns the maximum number of bytes in a serialized transaction, for the `Network`.
+    const fn default_max_transaction_size_in_bytes() -> usize {
+        N::MAX_TRANSACTION_SIZE_IN_BYTES
+    }
+
+    /// Returns the block time, for the `Network`.
+    const fn default_block_time() -> u16 {
+        N::BLOCK_TIME
+    }
+
+    /// Returns a description of the given genesis spec ID, for error messages.
+    fn describe_spec_id(spec_id: Option<Field<N>>) -> String {
+        match spec_id {
+            Some(spec_id) => format!("spec ID '{spec_id}'"),
+            None => "the default chain spec".to_string(),
+        }
+    }
+
+    /// Ensures the values of the chain spec are within range.
+    fn check(&self) -> Result<()> {
+        // Ensure the maximum number of solutions does not exceed the network limit.
+        ensure!(
+            self.max_solutions_per_block <= N::MAX_SOLUTIONS,
+            "The maximum number of solutions per block ({}) exceeds the network limit ({})",
+            self.max_solutions_per_block,
+            N::MAX_SOLUTIONS
+        );
+        // Ensure the maximum block size is nonzero and does not exceed the network limit.
+        ensure!(
+            self.max_block_size_in_bytes > 0 && self.max_block_size_in_bytes <= N::MAX_BLOCK_SIZE_IN_BYTES,
+            "The maximum block size ({} bytes) must be nonzero and at most the network limit ({} bytes)",
+            self.max_block_size_in_bytes,
+            N::MAX_BLOCK_SIZE_IN_BYTES
+        );
+        // Ensure the maximum number of transactions does not exceed the network limit.
+        ensure!(
+            self.max_transactions_per_block <= N::MAX_TRANSACTIONS_PER_BLOCK,
+            "The maximum number of transactions per block ({}) exceeds the network limit ({})",
+            self.max_transactions_per_block,
+            N::MAX_TRANSACTIONS_PER_BLOCK
+        );
+        // Ensure the maximum transaction size is nonzero and does not exceed the network limit.
+        ensure!(
+            self.max_transaction_size_in_bytes > 0
+                && self.max_transaction_size_in_bytes <= N::MAX_TRANSACTION_SIZE_IN_BYTES,
+            "The maximum transaction size ({} bytes) must be nonzero and at most the network limit ({} bytes)",
+            self.max_transaction_size_in_bytes,
+            N::MAX_TRANSACTION_SIZE_IN_BYTES
+        );
+        // Ensure the minimum transaction fee does not exceed the maximum fee.
+        ensure!(
+            self.minimum_transaction_fee <= N::MAX_FEE,
+            "The minimum transaction fee ({}) exceeds the maximum fee ({})",
+            self.minimum_transaction_fee,
+            N::MAX_FEE
+        );
+        // Ensure the block time is nonzero and does not exceed the network block time.
+        // Note: A longer block time would raise the coinbase reward per block above the network bound.
+        ensure!(
+            self.block_time > 0 && self.block_time <= N::BLOCK_TIME,
+            "The block time ({} seconds) must be nonzero and at most the network block time ({} seconds)",
+            self.block_time,
+            N::BLOCK_TIME
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> ToBytes for ChainSpec<N> {
+    /// Writes the chain spec to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        Self::VERSION.write_le(&mut writer)?;
+        // Write the maximum number of solutions per block.
+        (self.max_solutions_per_block as u64).write_le(&mut writer)?;
+        // Write the maximum block size in bytes.
+        (self.max_block_size_in_bytes as u64).write_le(&mut writer)?;
+        // Write the maximum number of transactions per block.
+        (self.max_transactions_per_block as u64).write_le(&mut writer)?;
+        // Write the maximum transaction size in bytes.
+        (self.max_transaction_size_in_bytes as u64).write_le(&mut writer)?;
+        // Write the minimum transaction fee.
+        self.minimum_transaction_fee.write_le(&mut writer)?;
+        // Write the block time.
+        self.block_time.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_default_matches_network() {
+        let spec = ChainSpec::<CurrentNetwork>::default();
+        assert_eq!(spec.max_solutions_per_block(), CurrentNetwork::MAX_SOLUTIONS);
+        assert_eq!(spec.max_block_size_in_bytes(), CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES);
+        assert_eq!(spec.max_transactions_per_block(), CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK);
+        assert_eq!(spec.max_transaction_size_in_bytes(), CurrentNetwork::MAX_TRANSACTION_SIZE_IN_BYTES);
+        assert_eq!(spec.minimum_transaction_fee(), 0);
+        assert_eq!(spec.block_time(), CurrentNetwork::BLOCK_TIME);
+        assert_eq!(spec.genesis_spec_id().unwrap(), None);
+        assert_eq!(
+            spec,
+            ChainSpec::new(
+                CurrentNetwork::MAX_SOLUTIONS,
+                CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES,
+                CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK,
+                CurrentNetwork::MAX_TRANSACTION_SIZE_IN_BYTES,
+                0,
+                CurrentNetwork::BLOCK_TIME
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_json() {
+        let spec = ChainSpec::<CurrentNetwork>::from_json(
+            r#"{ "max_solutions_per_block": 2, "minimum_transaction_fee": 1000 }"#,
+        )
+        .unwrap();
+        assert_eq!(spec.max_solutions_per_block(), 2);
+        assert_eq!(spec.max_block_size_in_bytes(), CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES);
+        assert_eq!(spec.max_transactions_per_block(), CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK);
+        assert_eq!(spec.max_transaction_size_in_bytes(), CurrentNetwork::MAX_TRANSACTION_SIZE_IN_BYTES);
+        assert_eq!(spec.minimum_transaction_fee(), 1000);
+        assert_eq!(spec.block_time(), CurrentNetwork::BLOCK_TIME);
+
+        // Ensure the JSON round-trips.
+        let candidate = ChainSpec::<CurrentNetwork>::from_json(&serde_json::to_string(&spec).unwrap()).unwrap();
+        assert_eq!(spec, candidate);
+
+        // Ensure the maximum block size may be set.
+        let spec = ChainSpec::<CurrentNetwork>::from_json(
+            r#"{ "max_solutions_per_block": 2, "max_block_size_in_bytes": 4096, "minimum_transaction_fee": 0 }"#,
+        )
+        .unwrap();
+        assert_eq!(spec.max_block_size_in_bytes(), 4096);
+
+        // Ensure the block time may be set.
+        let spec = ChainSpec::<CurrentNetwork>::from_json(
+            r#"{ "max_solutions_per_block": 2, "minimum_transaction_fee": 0, "block_time": 5 }"#,
+        )
+        .unwrap();
+        assert_eq!(spec.block_time(), 5);
+    }
+
+    #[test]
+    fn test_from_toml() {
+        let spec = ChainSpec::<CurrentNetwork>::from_toml(
+            "max_solutions_per_block = 2\nmax_block_size_in_bytes = 4096\nminimum_transaction_fee = 1000\n",
+        )
+        .unwrap();
+        assert_eq!(spec.max_solutions_per_block(), 2);
+        assert_eq!(spec.max_block_size_in_bytes(), 4096);
+        assert_eq!(spec.max_transactions_per_block(), CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK);
+        assert_eq!(spec.minimum_transaction_fee(), 1000);
+
+        // Ensure the TOML round-trips, and agrees with the JSON.
+        let candidate = ChainSpec::<CurrentNetwork>::from_toml(&toml::to_string(&spec).unwrap()).unwrap();
+        assert_eq!(spec, candidate);
+        let candidate = ChainSpec::<CurrentNetwork>::from_json(&serde_json::to_string(&spec).unwrap()).unwrap();
+        assert_eq!(spec, candidate);
+
+        // Ensure unknown fields, missing fields, and out-of-range values are rejected.
+        assert!(ChainSpec::<CurrentNetwork>::from_toml("max_solutions_per_block = 2\nepoch_length = 5\n").is_err());
+        assert!(ChainSpec::<CurrentNetwork>::from_toml("max_solutions_per_block = 2\n").is_err());
+        let toml =
+            format!("max_solutions_per_block = {}\nminimum_transaction_fee = 0\n", CurrentNetwork::MAX_SOLUTIONS + 1);
+        assert!(ChainSpec::<CurrentNetwork>::from_toml(&toml).is_err());
+    }
+
+    #[test]
+    fn test_from_json_fails() {
+        // Ensure unknown fields are rejected.
+        assert!(
+            ChainSpec::<CurrentNetwork>::from_json(
+                r#"{ "max_solutions_per_block": 2, "minimum_transaction_fee": 0, "epoch_length": 5 }"#,
+            )
+            .is_err()
+        );
+        // Ensure missing fields are rejected.
+        assert!(ChainSpec::<CurrentNetwork>::from_json(r#"{ "max_solutions_per_block": 2 }"#).is_err());
+        // Ensure out-of-range values are rejected.
+        let json = format!(
+            r#"{{ "max_solutions_per_block": {}, "minimum_transaction_fee": 0 }}"#,
+            CurrentNetwork::MAX_SOLUTIONS + 1
+        );
+        assert!(ChainSpec::<CurrentNetwork>::from_json(&json).is_err());
+        let json = format!(
+            r#"{{ "max_solutions_per_block": 1, "minimum_transaction_fee": {} }}"#,
+            CurrentNetwork::MAX_FEE + 1
+        );
+        assert!(ChainSpec::<CurrentNetwork>::from_json(&json).is_err());
+        let json = format!(
+            r#"{{ "max_solutions_per_block": 1, "max_block_size_in_bytes": {}, "minimum_transaction_fee": 0 }}"#,
+            CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES + 1
+        );
+        assert!(ChainSpec::<CurrentNetwork>::from_json(&json).is_err());
+        assert!(
+            ChainSpec::<CurrentNetwork>::from_json(
+                r#"{ "max_solutions_per_block": 1, "max_block_size_in_bytes": 0, "minimum_transaction_fee": 0 }"#,
+            )
+            .is_err()
+        );
+        let json = format!(
+            r#"{{ "max_solutions_per_block": 1, "max_transactions_per_block": {}, "minimum_transaction_fee": 0 }}"#,
+            CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK + 1
+        );
+        assert!(ChainSpec::<CurrentNetwork>::from_json(&json).is_err());
+        let json = format!(
+            r#"{{ "max_solutions_per_block": 1, "max_transaction_size_in_bytes": {}, "minimum_transaction_fee": 0 }}"#,
+            CurrentNetwork::MAX_TRANSACTION_SIZE_IN_BYTES + 1
+        );
+        assert!(ChainSpec::<CurrentNetwork>::from_json(&json).is_err());
+        assert!(
+            ChainSpec::<CurrentNetwork>::from_json(
+                r#"{ "max_solutions_per_block": 1, "minimum_transaction_fee": 0, "block_time": 0 }"#,
+            )
+            .is_err()
+        );
+        let json = format!(
+            r#"{{ "max_solutions_per_block": 1, "minimum_transaction_fee": 0, "block_time": {} }}"#,
+            CurrentNetwork::BLOCK_TIME + 1
+        );
+        assert!(ChainSpec::<CurrentNetwork>::from_json(&json).is_err());
+    }
+
+    #[test]
+    fn test_default_bytes() {
+        // Ensure the canonical bytes of the default spec, from which the spec ID is derived, are unchanged.
+        let expected = [
+            // The version.
+            [3].as_slice(),
+            // The maximum number of solutions per block.
+            &[4, 0, 0, 0, 0, 0, 0, 0],
+            // The maximum block size in bytes (268,435,456 bytes).
+            &[0, 0, 0, 16, 0, 0, 0, 0],
+            // The maximum number of transactions per block (1,048,575).
+            &[255, 255, 15, 0, 0, 0, 0, 0],
+            // The maximum transaction size in bytes (141,689,856 bytes).
+            &[0, 4, 114, 8, 0, 0, 0, 0],
+            // The minimum transaction fee.
+            &[0, 0, 0, 0, 0, 0, 0, 0],
+            // The block time (10 seconds).
+            &[10, 0],
+        ]
+        .concat();
+        assert_eq!(ChainSpec::<CurrentNetwork>::default().to_bytes_le().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_spec_id() {
+        let default = ChainSpec::<CurrentNetwork>::default();
+        let custom = ChainSpec::<CurrentNetwork>::new(
+            CurrentNetwork::MAX_SOLUTIONS,
+            CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES,
+            CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK,
+            CurrentNetwork::MAX_TRANSACTION_SIZE_IN_BYTES,
+            1,
+            CurrentNetwork::BLOCK_TIME,
+        )
+        .unwrap();
+
+        // Ensure the spec ID is deterministic.
+        assert_eq!(default.spec_id().unwrap(), ChainSpec::<CurrentNetwork>::default().spec_id().unwrap());
+        // Ensure different specs have different spec IDs.
+        assert_ne!(default.spec_id().unwrap(), custom.spec_id().unwrap());
+        // Ensure only a custom spec is committed to by its genesis block.
+        assert_eq!(default.genesis_spec_id().unwrap(), None);
+        assert_eq!(custom.genesis_spec_id().unwrap(), Some(custom.spec_id().unwrap()));
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+use serde_json::Value as JsonValue;
+use std::borrow::Cow;
+
+/// The format of an exported ledger table.
+///
+/// Both formats start with a line that records the table and `TableKind::VERSION`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values, with a `#` comment line for the version, followed by a header row.
+    Csv,
+    /// One JSON object per line, starting with an object for the table and version.
+    JsonLines,
+}
+
+/// The ledger tables that may be exported.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TableKind {
+    /// One row per block.
+    Blocks,
+    /// One row per confirmed transaction.
+    Transactions,
+    /// One row per transition, including fee transitions.
+    Transitions,
+}
+
+impl TableKind {
+    /// The version of the column sets, which is incremented whenever a column set changes.
+    pub const VERSION: u8 = 1;
+
+    /// Returns the name of the table.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Blocks => "blocks",
+            Self::Transactions => "transactions",
+            Self::Transitions => "transitions",
+        }
+    }
+
+    /// Returns the columns of the table, in order.
+    pub const fn columns(&self) -> &'static [&'static str] {
+        match self {
+            Self::Blocks => &["height", "hash", "timestamp", "num_transactions", "total_fees"],
+            Self::Transactions => &["id", "block_height", "fee", "type"],
+            Self::Transitions => &["id", "transaction_id", "program", "function", "num_inputs", "num_outputs", "fee"],
+        }
+    }
+}
+
+/// A writer that streams the rows of a table in the given format.
+pub(crate) struct TableWriter<W: Write> {
+    /// The underlying writer.
+    writer: W,
+    /// The columns of the table.
+    columns: &'static [&'static str],
+    /// The export format.
+    format: ExportFormat,
+}
+
+impl<W: Write> TableWriter<W> {
+    /// Initializes a new table writer, writing the version line and the header row if the format has one.
+    pub(crate) fn new(mut writer: W, table: TableKind, format: ExportFormat) -> Result<Self> {
+        let (name, version) = (table.name(), TableKind::VERSION);
+        let columns = table.columns();
+        match format {
+            ExportFormat::Csv => {
+                writeln!(writer, "# table={name} version={version}")?;
+                writeln!(writer, "{}", columns.join(","))?;
+            }
+            ExportFormat::JsonLines => writeln!(writer, "{{\"table\":\"{name}\",\"version\":{version}}}")?,
+        }
+        Ok(Self { writer, columns, format })
+    }
+
+    /// Writes the given row, which must contain one value per column.
+    pub(crate) fn write_row(&mut self, row: &[JsonValue]) -> Result<()> {
+        // Ensure the row matches the columns.
+        let num_columns = self.columns.len();
+        ensure!(row.len() == num_columns, "Expected {num_columns} values in the row, found {}", row.len());
+
+        match self.format {
+            ExportFormat::Csv => {
+                for (i, value) in row.iter().enumerate() {
+                    if i > 0 {
+                        write!(self.writer, ",")?;
+                    }
+                    match value {
+                        JsonValue::String(string) => write!(self.writer, "{}", escape_csv(string))?,
+                        value => write!(self.writer, "{value}")?,
+                    }
+                }
+            }
+            ExportFormat::JsonLines => {
+                // Note: The object is written by hand to preserve the column order.
+                write!(self.writer, "{{")?;
+                for (i, (column, value)) in self.columns.iter().zip_eq(row).enumerate() {
+                    if i > 0 {
+                        write!(self.writer, ",")?;
+                    }
+                    write!(self.writer, "{}:{value}", JsonValue::from(*column))?;
+                }
+                write!(self.writer, "}}")?;
+            }
+        }
+        writeln!(self.writer)?;
+        Ok(())
+    }
+
+    /// Flushes the underlying writer.
+    pub(crate) fn flush(&mut self) -> Result<()> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+/// Returns the given string as a CSV field, quoting it if it contains a delimiter, quote, or newline.
+fn escape_csv(string: &str) -> Cow<'_, str> {
+    match string.contains([',', '"', '\n', '\r']) {
+        true => Cow::Owned(format!("\"{}\"", string.replace('"', "\"\""))),
+        false => Cow::Borrowed(string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_csv() {
+        assert_eq!(escape_csv("credits.aleo"), "credits.aleo");
+        assert_eq!(escape_csv("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv("a\nb"), "\"a\nb\"");
+    }
+
+    #[test]
+    fn test_write_row() {
+        let row = [JsonValue::from(1u32), JsonValue::from("a,b"), JsonValue::from(2u64), JsonValue::from("fee")];
+
+        // Ensure the CSV format writes the version and a header, and escapes the values.
+        let mut buffer = vec![];
+        let mut writer = TableWriter::new(&mut buffer, TableKind::Transactions, ExportFormat::Csv).unwrap();
+        writer.write_row(&row).unwrap();
+        let expected = "# table=transactions version=1\nid,block_height,fee,type\n1,\"a,b\",2,fee\n";
+        assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+
+        // Ensure the JSON lines format writes the version, and preserves the column order.
+        let mut buffer = vec![];
+        let mut writer = TableWriter::new(&mut buffer, TableKind::Transactions, ExportFormat::JsonLines).unwrap();
+        writer.write_row(&row).unwrap();
+        let expected = concat!(
+            "{\"table\":\"transactions\",\"version\":1}\n",
+            "{\"id\":1,\"block_height\":\"a,b\",\"fee\":2,\"type\":\"fee\"}\n",
+        );
+        assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+
+        // Ensure a row with the wrong number of values is rejected.
+        let mut writer = TableWriter::new(vec![], TableKind::Blocks, ExportFormat::Csv).unwrap();
+        assert!(writer.write_row(&row).is_err());
+    }
+
+    /// A writer that discards its input, while tracking the total and the largest single write.
+    #[derive(Default)]
+    struct TrackingWriter {
+        num_bytes: usize,
+        max_write: usize,
+    }
+
+    impl Write for TrackingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.num_bytes += buf.len();
+            self.max_write = self.max_write.max(buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_rows_are_streamed() {
+        const NUM_ROWS: usize = 10_000;
+
+        let row = [JsonValue::from(1u32), JsonValue::from("a,b"), JsonValue::from(2u64), JsonValue::from("fee")];
+
+        for format in [ExportFormat::Csv, ExportFormat::JsonLines] {
+            let mut tracker = TrackingWriter::default();
+            let mut writer = TableWriter::new(&mut tracker, TableKind::Transactions, format).unwrap();
+            for _ in 0..NUM_ROWS {
+                writer.write_row(&row).unwrap();
+            }
+            writer.flush().unwrap();
+
+            // Ensure every row was written, without ever buffering more than a single value.
+            assert!(tracker.num_bytes > NUM_ROWS * 10);
+            assert!(tracker.max_write <= 64, "Found a write of {} bytes", tracker.max_write);
+        }
+    }
+}
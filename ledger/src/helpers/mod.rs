@@ -15,5 +15,14 @@
 mod bft;
 pub use bft::*;
 
+mod chain_spec;
+pub use chain_spec::*;
+
+mod export;
+pub use export::*;
+
+mod size;
+pub(crate) use size::*;
+
 mod supply;
 pub use supply::*;
@@ -88,6 +88,52 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transition_store().records()
     }
 
+    /* Audit */
+
+    /// Returns an iterator over the `(serial number, block height)` pairs, for all transition inputs that are records.
+    pub fn serial_numbers_with_heights(&self) -> impl '_ + Iterator<Item = Result<(Field<N>, u32)>> {
+        self.serial_numbers().map(|serial_number| {
+            let serial_number = serial_number.into_owned();
+            Ok((serial_number, self.find_block_height_from_transition_input_or_output(&serial_number)?))
+        })
+    }
+
+    /// Returns an iterator over the `(commitment, block height)` pairs, for all transition outputs that are records.
+    pub fn commitments_with_heights(&self) -> impl '_ + Iterator<Item = Result<(Field<N>, u32)>> {
+        self.commitments().map(|commitment| {
+            let commitment = commitment.into_owned();
+            Ok((commitment, self.find_block_height_from_transition_input_or_output(&commitment)?))
+        })
+    }
+
+    /// Returns the number of serial numbers in the ledger.
+    pub fn num_serial_numbers(&self) -> usize {
+        self.vm.transition_store().num_serial_numbers()
+    }
+
+    /// Returns the number of commitments in the ledger.
+    pub fn num_commitments(&self) -> usize {
+        self.vm.transition_store().num_commitments()
+    }
+
+    /// Returns the height of the block that contains the given transition input or output ID.
+    fn find_block_height_from_transition_input_or_output(&self, id: &Field<N>) -> Result<u32> {
+        // Retrieve the transition ID.
+        let transition_id = self.find_transition_id(id)?;
+        // Retrieve the transaction ID.
+        let transaction_id = match self.find_transaction_id_from_transition_id(&transition_id)? {
+            Some(transaction_id) => transaction_id,
+            None => bail!("Missing transaction for transition '{transition_id}'"),
+        };
+        // Retrieve the block hash.
+        let block_hash = match self.find_block_hash(&transaction_id)? {
+            Some(block_hash) => block_hash,
+            None => bail!("Missing block for transaction '{transaction_id}'"),
+        };
+        // Retrieve the block height.
+        self.get_height(&block_hash)
+    }
+
     /* Metadata */
 
     /// Returns an iterator over the transition public keys, for all transactions.
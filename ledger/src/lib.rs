@@ -38,9 +38,11 @@ mod advance;
 mod check_next_block;
 mod check_transaction_basic;
 mod contains;
+mod export;
 mod find;
 mod get;
 mod iterators;
+mod snapshot;
 
 #[cfg(test)]
 mod tests;
@@ -79,6 +81,9 @@ use rayon::prelude::*;
 
 pub type RecordMap<N> = IndexMap<Field<N>, Record<N, Plaintext<N>>>;
 
+/// The maximum number of blocks or headers that may be retrieved in a single range.
+pub const MAX_BLOCKS_PER_RANGE: u32 = 50;
+
 #[derive(Copy, Clone, Debug)]
 pub enum RecordsFilter<N: Network> {
     /// Returns all records associated with the account.
@@ -97,6 +102,8 @@ pub enum RecordsFilter<N: Network> {
 pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     /// The VM state.
     vm: VM<N, C>,
+    /// The chain spec.
+    spec: ChainSpec<N>,
     /// The genesis block.
     genesis_block: Block<N>,
     /// The current epoch hash.
@@ -110,38 +117,60 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Loads the ledger from storage.
     pub fn load(genesis_block: Block<N>, storage_mode: StorageMode) -> Result<Self> {
+        Self::load_with_spec(genesis_block, ChainSpec::default(), storage_mode)
+    }
+
+    /// Loads the ledger from storage with the given chain spec.
+    ///
+    /// The genesis block must commit to the spec ID of the given chain spec (see `ChainSpec::genesis_spec_id`),
+    /// so that a ledger cannot be loaded with a chain spec other than the one it was initialized with.
+    pub fn load_with_spec(genesis_block: Block<N>, spec: ChainSpec<N>, storage_mode: StorageMode) -> Result<Self> {
         let timer = timer!("Ledger::load");
 
         // Retrieve the genesis hash.
         let genesis_hash = genesis_block.hash();
         // Initialize the ledger.
-        let ledger = Self::load_unchecked(genesis_block, storage_mode)?;
+        let ledger = Self::load_unchecked_with_spec(genesis_block, spec, storage_mode)?;
 
         // Ensure the ledger contains the correct genesis block.
         if !ledger.contains_block_hash(&genesis_hash)? {
             bail!("Incorrect genesis block (run 'snarkos clean' and try again)")
         }
 
-        // Spot check the integrity of `NUM_BLOCKS` random blocks upon bootup.
+        // Spot check the integrity of random blocks upon bootup.
+        ledger.check_random_blocks()?;
+        lap!(timer, "Check existence of random blocks");
+
+        finish!(timer);
+        Ok(ledger)
+    }
+
+    /// Spot checks the integrity of `NUM_BLOCKS` random blocks in storage.
+    fn check_random_blocks(&self) -> Result<()> {
         const NUM_BLOCKS: usize = 10;
         // Retrieve the latest height.
-        let latest_height = ledger.current_block.read().height();
-        debug_assert_eq!(latest_height, *ledger.vm.block_store().heights().max().unwrap(), "Mismatch in latest height");
+        let latest_height = self.current_block.read().height();
+        debug_assert_eq!(latest_height, self.vm.block_store().max_height().unwrap(), "Mismatch in latest height");
         // Sample random block heights.
         let block_heights: Vec<u32> =
             (0..=latest_height).choose_multiple(&mut OsRng, (latest_height as usize).min(NUM_BLOCKS));
         cfg_into_iter!(block_heights).try_for_each(|height| {
-            ledger.get_block(height)?;
+            self.get_block(height)?;
             Ok::<_, Error>(())
-        })?;
-        lap!(timer, "Check existence of {NUM_BLOCKS} random blocks");
-
-        finish!(timer);
-        Ok(ledger)
+        })
     }
 
     /// Loads the ledger from storage, without performing integrity checks.
     pub fn load_unchecked(genesis_block: Block<N>, storage_mode: StorageMode) -> Result<Self> {
+        Self::load_unchecked_with_spec(genesis_block, ChainSpec::default(), storage_mode)
+    }
+
+    /// Loads the ledger from storage with the given chain spec, without performing integrity checks.
+    pub fn load_unchecked_with_spec(
+        genesis_block: Block<N>,
+        spec: ChainSpec<N>,
+        storage_mode: StorageMode,
+    ) -> Result<Self> {
         let timer = timer!("Ledger::load_unchecked");
 
         info!("Loading the ledger from storage...");
@@ -152,9 +181,21 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
         lap!(timer, "Load consensus store");
 
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::load_from_store(genesis_block, spec, store)?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Loads the ledger from the given consensus store, without performing integrity checks.
+    /// If the consensus store is empty, the genesis block is added to it.
+    fn load_from_store(genesis_block: Block<N>, spec: ChainSpec<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        // Ensure the genesis block commits to the given chain spec.
+        spec.check_genesis_block(&genesis_block)?;
+
         // Initialize a new VM.
         let vm = VM::from(store)?;
-        lap!(timer, "Initialize a new VM");
 
         // Retrieve the current committee.
         let current_committee = vm.finalize_store().committee_store().current_committee().ok();
@@ -162,6 +203,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Initialize the ledger.
         let mut ledger = Self {
             vm,
+            spec,
             genesis_block: genesis_block.clone(),
             current_epoch_hash: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
@@ -169,15 +211,20 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
 
         // If the block store is empty, initialize the genesis block.
-        if ledger.vm.block_store().heights().max().is_none() {
+        if ledger.vm.block_store().max_height().is_none() {
             // Add the genesis block.
             ledger.advance_to_next_block(&genesis_block)?;
+        } else {
+            // Ensure the ledger was initialized with the given genesis block, and thus with the given chain spec.
+            ensure!(
+                ledger.vm.block_store().get_block_hash(0)? == Some(genesis_block.hash()),
+                "Incorrect genesis block (run 'snarkos clean' and try again)"
+            );
         }
-        lap!(timer, "Initialize genesis");
 
         // Retrieve the latest height.
         let latest_height =
-            *ledger.vm.block_store().heights().max().ok_or_else(|| anyhow!("Failed to load blocks from the ledger"))?;
+            ledger.vm.block_store().max_height().ok_or_else(|| anyhow!("Failed to load blocks from the ledger"))?;
         // Fetch the latest block.
         let block = ledger
             .get_block(latest_height)
@@ -190,7 +237,6 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Set the current epoch hash.
         ledger.current_epoch_hash = Arc::new(RwLock::new(Some(ledger.get_epoch_hash(latest_height)?)));
 
-        finish!(timer, "Initialize ledger");
         Ok(ledger)
     }
 
@@ -199,6 +245,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         &self.vm
     }
 
+    /// Returns the chain spec.
+    pub const fn spec(&self) -> &ChainSpec<N> {
+        &self.spec
+    }
+
     /// Returns the puzzle.
     pub const fn puzzle(&self) -> &Puzzle<N> {
         self.vm.puzzle()
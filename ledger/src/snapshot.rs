@@ -0,0 +1,452 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::FinalizeStoreTrait;
+use sha2::{Digest, Sha256};
+
+/// The version of the snapshot layout, which is incremented whenever the layout changes.
+const SNAPSHOT_VERSION: u8 = 2;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Exports a snapshot of the ledger state to the writer.
+    ///
+    /// The snapshot is laid out as follows:
+    ///   - the snapshot version, and the spec ID of the chain spec of the ledger,
+    ///   - the length-prefixed blocks, and the length-prefixed committee for each block height,
+    ///   - the length-prefixed entries of every mapping of every program,
+    ///   - the state root, and the checksum of the finalize state,
+    ///   - the SHA-256 checksum of all of the above.
+    ///
+    /// The snapshot is streamed to the writer, one block or mapping at a time.
+    /// Note: The ledger must not advance during the export, otherwise the export fails.
+    pub fn export_snapshot<W: Write>(&self, writer: W) -> Result<()> {
+        let timer = timer!("Ledger::export_snapshot");
+
+        let mut writer = ChecksumWriter::new(writer);
+        SNAPSHOT_VERSION.write_le(&mut writer)?;
+        self.spec.spec_id()?.write_le(&mut writer)?;
+
+        // Write the blocks.
+        let latest_height = self.latest_height();
+        latest_height.checked_add(1).ok_or_else(|| anyhow!("Too many blocks to export"))?.write_le(&mut writer)?;
+        for height in 0..=latest_height {
+            write_length_prefixed(&mut writer, &self.get_block(height)?)?;
+        }
+        lap!(timer, "Write the blocks");
+
+        // Write the committee for each block height.
+        let committee_store = self.vm.finalize_store().committee_store();
+        for height in 0..=latest_height {
+            let Some(committee) = committee_store.get_committee(height)? else {
+                bail!("Missing the committee for block {height} in storage")
+            };
+            write_length_prefixed(&mut writer, &committee)?;
+        }
+        lap!(timer, "Write the committees");
+
+        // Collect the programs that have mappings, starting with 'credits.aleo'.
+        let finalize_store = self.vm.finalize_store();
+        let mut mappings = Vec::new();
+        let program_ids = self.vm.transaction_store().program_ids().map(|program_id| *program_id);
+        for program_id in std::iter::once(*Program::<N>::credits()?.id()).chain(program_ids) {
+            if let Some(mapping_names) = finalize_store.get_mapping_names_confirmed(&program_id)? {
+                mappings.push((program_id, mapping_names));
+            }
+        }
+        // Write the entries of every mapping.
+        u32::try_from(mappings.len())?.write_le(&mut writer)?;
+        for (program_id, mapping_names) in mappings {
+            program_id.write_le(&mut writer)?;
+            u32::try_from(mapping_names.len())?.write_le(&mut writer)?;
+            for mapping_name in mapping_names {
+                mapping_name.write_le(&mut writer)?;
+                let entries = finalize_store.get_mapping_confirmed(program_id, mapping_name)?;
+                u32::try_from(entries.len())?.write_le(&mut writer)?;
+                for (key, value) in entries {
+                    write_length_prefixed(&mut writer, &key)?;
+                    write_length_prefixed(&mut writer, &value)?;
+                }
+            }
+        }
+        lap!(timer, "Write the mappings");
+
+        // Ensure the ledger did not advance, so that the state root and finalize state match the blocks.
+        ensure!(self.latest_height() == latest_height, "The ledger advanced during the snapshot export");
+        // Write the state root and the finalize checksum.
+        self.latest_state_root().write_le(&mut writer)?;
+        finalize_store.get_checksum_confirmed()?.write_le(&mut writer)?;
+
+        // Write the checksum of the snapshot.
+        let (mut writer, checksum) = writer.finish();
+        writer.write_all(&checksum)?;
+        writer.flush()?;
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Imports a snapshot of the ledger state from the reader, into a new ledger in the given storage.
+    ///
+    /// The storage must be empty, the snapshot must be exported from a ledger with the given chain spec,
+    /// and the first block of the snapshot must be the given genesis block.
+    /// The snapshot is restored as it is streamed from the reader. If the snapshot checksum, the state root,
+    /// or the finalize state does not match, the restored state is removed from storage again.
+    ///
+    /// Note: The checksum only detects a corrupted snapshot, and does not authenticate it.
+    /// The snapshot is not re-executed, so it must be obtained from a trusted source.
+    pub fn import_snapshot<R: Read>(
+        reader: R,
+        genesis_block: Block<N>,
+        spec: ChainSpec<N>,
+        storage_mode: StorageMode,
+    ) -> Result<Self> {
+        // Initialize the consensus store.
+        let store = ConsensusStore::<N, C>::open(storage_mode)?;
+        // Import the snapshot into the consensus store.
+        Self::import_snapshot_into_store(reader, genesis_block, spec, store)
+    }
+
+    /// Imports a snapshot of the ledger state from the reader, into a new ledger in the given consensus store.
+    fn import_snapshot_into_store<R: Read>(
+        reader: R,
+        genesis_block: Block<N>,
+        spec: ChainSpec<N>,
+        store: ConsensusStore<N, C>,
+    ) -> Result<Self> {
+        let timer = timer!("Ledger::import_snapshot");
+
+        // Ensure the consensus store is empty.
+        ensure!(store.block_store().max_height().is_none(), "Cannot import a snapshot into a non-empty ledger");
+        // Ensure the genesis block commits to the given chain spec, before any state is restored.
+        spec.check_genesis_block(&genesis_block)?;
+
+        // Restore the snapshot, and remove the restored state again if it fails.
+        let mut restored = RestoredState::default();
+        if let Err(error) = restore_snapshot(reader, &genesis_block, &spec, &store, &mut restored) {
+            return match restored.remove_from(&store) {
+                Ok(()) => Err(error),
+                Err(cleanup_error) => bail!("{error} (failed to remove the partially imported state: {cleanup_error})"),
+            };
+        }
+        lap!(timer, "Restore the snapshot");
+
+        // Initialize the ledger.
+        let ledger = Self::load_from_store(genesis_block, spec, store)?;
+        // Spot check the integrity of random blocks.
+        ledger.check_random_blocks()?;
+
+        finish!(timer);
+        Ok(ledger)
+    }
+}
+
+/// The state that was written to storage by an import, so that a failed import can be undone.
+struct RestoredState<N: Network> {
+    /// The number of inserted blocks.
+    num_blocks: u32,
+    /// The number of inserted committees.
+    num_committees: u32,
+    /// The programs with initialized mappings.
+    program_ids: Vec<ProgramID<N>>,
+}
+
+impl<N: Network> Default for RestoredState<N> {
+    fn default() -> Self {
+        Self { num_blocks: 0, num_committees: 0, program_ids: Vec::new() }
+    }
+}
+
+impl<N: Network> RestoredState<N> {
+    /// Removes the restored state from the given consensus store, in the reverse order of insertion.
+    fn remove_from<C: ConsensusStorage<N>>(&self, store: &ConsensusStore<N, C>) -> Result<()> {
+        for program_id in self.program_ids.iter().rev() {
+            store.finalize_store().remove_program(program_id)?;
+        }
+        for height in (0..self.num_committees).rev() {
+            store.finalize_store().committee_store().remove(height)?;
+        }
+        if self.num_blocks > 0 {
+            store.block_store().remove_last_n(self.num_blocks)?;
+        }
+        Ok(())
+    }
+}
+
+/// Restores the snapshot from the reader into the given consensus store, recording the restored state as it goes.
+fn restore_snapshot<N: Network, C: ConsensusStorage<N>, R: Read>(
+    reader: R,
+    genesis_block: &Block<N>,
+    spec: &ChainSpec<N>,
+    store: &ConsensusStore<N, C>,
+    restored: &mut RestoredState<N>,
+) -> Result<()> {
+    let mut reader = ChecksumReader::new(reader);
+
+    // Read the snapshot version.
+    let version = u8::read_le(&mut reader)?;
+    ensure!(version == SNAPSHOT_VERSION, "Unsupported snapshot version {version} (expected {SNAPSHOT_VERSION})");
+
+    // Ensure the snapshot was exported for the given chain spec.
+    let snapshot_spec_id = Field::<N>::read_le(&mut reader)?;
+    let expected_spec_id = spec.spec_id()?;
+    ensure!(
+        snapshot_spec_id == expected_spec_id,
+        "Incorrect chain spec: the snapshot was exported for spec ID {snapshot_spec_id}, \
+         but the ledger expects {expected_spec_id}"
+    );
+
+    // Insert the blocks, which rebuilds the block tree.
+    let num_blocks = u32::read_le(&mut reader)?;
+    ensure!(num_blocks > 0, "The snapshot does not contain a genesis block");
+    for height in 0..num_blocks {
+        let block: Block<N> = read_length_prefixed(&mut reader)?;
+        // Ensure the snapshot starts from the expected genesis block.
+        if height == 0 {
+            let is_genesis = block.hash() == genesis_block.hash();
+            ensure!(is_genesis, "The snapshot does not start from the expected genesis block");
+        }
+        store.block_store().insert(&block)?;
+        restored.num_blocks += 1;
+    }
+
+    // Insert the committee for each block height.
+    for height in 0..num_blocks {
+        let committee: Committee<N> = read_length_prefixed(&mut reader)?;
+        store.finalize_store().committee_store().insert(height, committee)?;
+        restored.num_committees += 1;
+    }
+
+    // Restore the entries of every mapping.
+    let num_programs = u32::read_le(&mut reader)?;
+    for _ in 0..num_programs {
+        let program_id = ProgramID::<N>::read_le(&mut reader)?;
+        let num_mappings = u32::read_le(&mut reader)?;
+        for index in 0..num_mappings {
+            let mapping_name = Identifier::<N>::read_le(&mut reader)?;
+            store.finalize_store().initialize_mapping(program_id, mapping_name)?;
+            if index == 0 {
+                restored.program_ids.push(program_id);
+            }
+            let num_entries = u32::read_le(&mut reader)?;
+            for _ in 0..num_entries {
+                let key = read_length_prefixed(&mut reader)?;
+                let value = read_length_prefixed(&mut reader)?;
+                store.finalize_store().insert_key_value(program_id, mapping_name, key, value)?;
+            }
+        }
+    }
+
+    // Read the state root and the finalize checksum.
+    let expected_state_root = N::StateRoot::read_le(&mut reader)?;
+    let expected_finalize_checksum = Field::<N>::read_le(&mut reader)?;
+
+    // Ensure the checksum of the snapshot is correct.
+    let (mut reader, checksum) = reader.finish();
+    let mut expected_checksum = [0u8; 32];
+    reader.read_exact(&mut expected_checksum)?;
+    ensure!(checksum == expected_checksum, "The snapshot checksum does not match its contents");
+    ensure!(reader.read(&mut [0u8; 1])? == 0, "The snapshot contains trailing bytes");
+
+    // Ensure the state root and the finalize state match the snapshot.
+    ensure!(
+        store.block_store().current_state_root() == expected_state_root,
+        "The imported state root does not match the snapshot"
+    );
+    ensure!(
+        store.finalize_store().get_checksum_confirmed()? == expected_finalize_checksum,
+        "The imported finalize state does not match the snapshot"
+    );
+    Ok(())
+}
+
+/// A writer that computes the SHA-256 checksum of the bytes written through it.
+struct ChecksumWriter<W: Write> {
+    writer: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    /// Initializes a new checksum writer.
+    fn new(writer: W) -> Self {
+        Self { writer, hasher: Sha256::new() }
+    }
+
+    /// Returns the underlying writer, and the checksum of the bytes written so far.
+    fn finish(self) -> (W, [u8; 32]) {
+        (self.writer, self.hasher.finalize().into())
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        let num_bytes = self.writer.write(buffer)?;
+        self.hasher.update(&buffer[..num_bytes]);
+        Ok(num_bytes)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// A reader that computes the SHA-256 checksum of the bytes read through it.
+struct ChecksumReader<R: Read> {
+    reader: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> ChecksumReader<R> {
+    /// Initializes a new checksum reader.
+    fn new(reader: R) -> Self {
+        Self { reader, hasher: Sha256::new() }
+    }
+
+    /// Returns the underlying reader, and the checksum of the bytes read so far.
+    fn finish(self) -> (R, [u8; 32]) {
+        (self.reader, self.hasher.finalize().into())
+    }
+}
+
+impl<R: Read> Read for ChecksumReader<R> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let num_bytes = self.reader.read(buffer)?;
+        self.hasher.update(&buffer[..num_bytes]);
+        Ok(num_bytes)
+    }
+}
+
+/// Writes the given object to the writer, prefixed with its length in bytes.
+fn write_length_prefixed<W: Write, T: ToBytes>(mut writer: W, object: &T) -> Result<()> {
+    let bytes = object.to_bytes_le()?;
+    u32::try_from(bytes.len())?.write_le(&mut writer)?;
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+/// Reads an object from the reader, which must be prefixed with its length in bytes.
+fn read_length_prefixed<R: Read, T: FromBytes>(mut reader: R) -> Result<T> {
+    let length = u32::read_le(&mut reader)?;
+    // Note: The buffer grows as bytes are read, so a corrupted length cannot cause a large allocation up front.
+    let mut bytes = Vec::new();
+    (&mut reader).take(u64::from(length)).read_to_end(&mut bytes)?;
+    ensure!(bytes.len() == length as usize, "The snapshot is truncated");
+    Ok(T::from_bytes_le(&bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{CurrentConsensusStore, CurrentLedger, CurrentNetwork};
+
+    #[test]
+    fn test_import_snapshot_retries_after_failure() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the test environment.
+        let crate::test_helpers::TestEnv { ledger, .. } = crate::test_helpers::sample_test_env(rng);
+        let genesis_block = ledger.get_block(0).unwrap();
+
+        // Export the snapshot.
+        let mut snapshot = vec![];
+        ledger.export_snapshot(&mut snapshot).unwrap();
+
+        // Initialize a store, which is shared by every import attempt below.
+        let store = CurrentConsensusStore::open(None).unwrap();
+        let import = |snapshot: &[u8]| {
+            let spec = ChainSpec::default();
+            CurrentLedger::import_snapshot_into_store(snapshot, genesis_block.clone(), spec, store.clone())
+        };
+
+        // Ensure a snapshot with a corrupted checksum is rejected, after its contents were written to storage.
+        let mut corrupted = snapshot.clone();
+        *corrupted.last_mut().unwrap() ^= 1;
+        assert!(import(&corrupted).unwrap_err().to_string().contains("checksum"));
+        // Ensure a truncated snapshot is rejected.
+        assert!(import(&snapshot[..snapshot.len() - 33]).is_err());
+        // Ensure the storage is empty again.
+        assert!(store.block_store().max_height().is_none());
+        assert!(store.finalize_store().committee_store().get_committee(0).unwrap().is_none());
+        let credits_id = *Program::<CurrentNetwork>::credits().unwrap().id();
+        assert!(!store.finalize_store().contains_program_confirmed(&credits_id).unwrap());
+
+        // Ensure the snapshot is imported on the same storage.
+        let candidate = import(&snapshot).unwrap();
+        assert_eq!(ledger.latest_hash(), candidate.latest_hash());
+        assert_eq!(ledger.latest_state_root(), candidate.latest_state_root());
+        assert_eq!(
+            ledger.vm.finalize_store().get_checksum_confirmed().unwrap(),
+            candidate.vm.finalize_store().get_checksum_confirmed().unwrap()
+        );
+
+        // Ensure a snapshot cannot be imported into a non-empty ledger.
+        assert!(import(&snapshot).is_err());
+    }
+
+    #[test]
+    fn test_import_snapshot_rejects_another_genesis_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the test environment.
+        let crate::test_helpers::TestEnv { ledger, .. } = crate::test_helpers::sample_test_env(rng);
+
+        // Export the snapshot.
+        let mut snapshot = vec![];
+        ledger.export_snapshot(&mut snapshot).unwrap();
+
+        // Ensure the snapshot is rejected for another genesis block.
+        let genesis_block = crate::test_helpers::sample_genesis_block();
+        assert_ne!(genesis_block.hash(), ledger.get_block(0).unwrap().hash());
+        let store = CurrentConsensusStore::open(None).unwrap();
+        let spec = ChainSpec::default();
+        let result = CurrentLedger::import_snapshot_into_store(&snapshot[..], genesis_block, spec, store);
+        assert!(result.unwrap_err().to_string().contains("genesis block"));
+    }
+
+    #[test]
+    fn test_import_snapshot_rejects_another_chain_spec() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the test environment.
+        let crate::test_helpers::TestEnv { ledger, .. } = crate::test_helpers::sample_test_env(rng);
+        let genesis_block = ledger.get_block(0).unwrap();
+
+        // Export the snapshot, which starts with the version and the spec ID.
+        let mut snapshot = vec![];
+        ledger.export_snapshot(&mut snapshot).unwrap();
+        let spec_id = ChainSpec::<CurrentNetwork>::default().spec_id().unwrap();
+        assert_eq!(snapshot[1..33], spec_id.to_bytes_le().unwrap());
+
+        // Replace the spec ID with the spec ID of another chain spec.
+        let another_spec = ChainSpec::<CurrentNetwork>::new(
+            CurrentNetwork::MAX_SOLUTIONS,
+            CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES,
+            CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK,
+            CurrentNetwork::MAX_TRANSACTION_SIZE_IN_BYTES,
+            1,
+            CurrentNetwork::BLOCK_TIME,
+        )
+        .unwrap();
+        let mut corrupted = snapshot.clone();
+        corrupted[1..33].copy_from_slice(&another_spec.spec_id().unwrap().to_bytes_le().unwrap());
+
+        // Ensure the snapshot is rejected before any state is restored.
+        let store = CurrentConsensusStore::open(None).unwrap();
+        let spec = ChainSpec::default();
+        let result = CurrentLedger::import_snapshot_into_store(&corrupted[..], genesis_block, spec, store.clone());
+        assert!(result.unwrap_err().to_string().contains("Incorrect chain spec"));
+        assert!(store.block_store().max_height().is_none());
+    }
+}
@@ -14,9 +14,12 @@
 
 use crate::{
     advance::split_candidate_solutions,
-    test_helpers::{CurrentLedger, CurrentNetwork},
+    test_helpers::{CurrentConsensusStore, CurrentLedger, CurrentNetwork},
+    ChainSpec,
+    ExportFormat,
     Ledger,
     RecordsFilter,
+    TableKind,
 };
 use aleo_std::StorageMode;
 use console::{
@@ -24,7 +27,16 @@ use console::{
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
 };
-use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
+use ledger_block::{
+    block_reward,
+    Block,
+    ConfirmedTransaction,
+    Ratifications,
+    Ratify,
+    Rejected,
+    Transaction,
+    Transactions,
+};
 use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
 use synthesizer::{program::Program, vm::VM, Stack};
@@ -70,6 +82,518 @@ fn test_load_unchecked() {
     assert_eq!(ledger.latest_block(), genesis);
 }
 
+#[test]
+fn test_load_with_spec() {
+    let rng = &mut TestRng::default();
+
+    // Sample the genesis private key.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+
+    // Initialize a ledger with the default chain spec.
+    let ledger = sample_ledger_with_spec(&private_key, ChainSpec::default(), rng);
+    assert_eq!(ledger.spec(), &ChainSpec::default());
+    // Initialize a ledger with a chain spec that requires a minimum fee.
+    let spec =
+        sample_spec(CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES, CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK, 1_000_000_000);
+    let ledger_with_spec = sample_ledger_with_spec(&private_key, spec.clone(), rng);
+    assert_eq!(ledger_with_spec.spec(), &spec);
+
+    // Create a public transfer in each ledger, which pays less than the minimum fee in the chain spec.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_with_spec = ledger_with_spec
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction_with_spec.id();
+
+    // Ensure the transaction is only well-formed under the default chain spec.
+    ledger.check_transaction_basic(&transaction, None, rng).unwrap();
+    let error = ledger_with_spec.check_transaction_basic(&transaction_with_spec, None, rng).unwrap_err();
+    assert!(error.to_string().contains("below the minimum fee of 1000000000 microcredits"), "{error}");
+
+    // Ensure the ledger with the default chain spec includes the transaction.
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng)
+        .unwrap();
+    assert_eq!(block.transactions().len(), 1);
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the ledger with the chain spec aborts the transaction.
+    let block = ledger_with_spec
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction_with_spec.clone()], rng)
+        .unwrap();
+    assert!(block.transactions().is_empty());
+    assert_eq!(block.aborted_transaction_ids(), &vec![transaction_id]);
+    ledger_with_spec.check_next_block(&block, rng).unwrap();
+
+    // Ensure a block that includes the transaction is rejected under the chain spec.
+    let confirmed = ConfirmedTransaction::accepted_execute(0, transaction_with_spec, vec![]).unwrap();
+    let tampered_block = tamper_block(&block, block.ratifications().clone(), Transactions::from(&[confirmed]));
+    let error = ledger_with_spec.check_next_block(&tampered_block, rng).unwrap_err();
+    assert!(error.to_string().contains(&format!("Transaction '{transaction_id}' pays")), "{error}");
+    assert!(error.to_string().contains("below the minimum fee of 1000000000 microcredits"), "{error}");
+    ledger_with_spec.advance_to_next_block(&block).unwrap();
+}
+
+#[test]
+fn test_check_next_block_size() {
+    let rng = &mut TestRng::default();
+
+    // Sample the genesis private key.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Initialize the ledger with the default chain spec.
+    let ledger = sample_ledger_with_spec(&private_key, ChainSpec::default(), rng);
+
+    // Construct the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    let block_size_in_bytes = block.to_bytes_le().unwrap().len();
+    // Ensure the counted size matches the serialized size.
+    assert_eq!(block_size_in_bytes, crate::helpers::size_in_bytes(&block).unwrap());
+    // Ensure the block is valid under the default chain spec.
+    ledger.check_next_block(&block, rng).unwrap();
+
+    // Ensure an empty block is valid under a chain spec that allows exactly its size.
+    let spec = sample_spec(block_size_in_bytes, CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK, 0);
+    let ledger_with_spec = sample_ledger_with_spec(&private_key, spec, rng);
+    let block =
+        ledger_with_spec.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    assert_eq!(block.to_bytes_le().unwrap().len(), block_size_in_bytes);
+    ledger_with_spec.check_next_block(&block, rng).unwrap();
+
+    // Ensure an empty block is rejected under a chain spec whose maximum block size is one byte below its size.
+    let spec = sample_spec(block_size_in_bytes - 1, CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK, 0);
+    let ledger_with_spec = sample_ledger_with_spec(&private_key, spec, rng);
+    let block =
+        ledger_with_spec.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    assert_eq!(block.to_bytes_le().unwrap().len(), block_size_in_bytes);
+    let error = ledger_with_spec.check_next_block(&block, rng).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Block {} is {block_size_in_bytes} bytes, exceeding the chain spec limit of {} bytes",
+            block.height(),
+            block_size_in_bytes - 1
+        )
+    );
+}
+
+/// Returns a ledger whose genesis block commits to the given chain spec, and which is loaded with it.
+fn sample_ledger_with_spec(
+    private_key: &PrivateKey<CurrentNetwork>,
+    spec: ChainSpec<CurrentNetwork>,
+    rng: &mut TestRng,
+) -> CurrentLedger {
+    // Create a genesis block that commits to the chain spec.
+    let genesis = sample_genesis_with_spec(private_key, &spec, rng);
+    // Initialize the ledger with the genesis block and the chain spec.
+    CurrentLedger::load_with_spec(genesis, spec, StorageMode::Production).unwrap()
+}
+
+/// Returns a genesis block that commits to the given chain spec.
+fn sample_genesis_with_spec(
+    private_key: &PrivateKey<CurrentNetwork>,
+    spec: &ChainSpec<CurrentNetwork>,
+    rng: &mut TestRng,
+) -> Block<CurrentNetwork> {
+    let store = CurrentConsensusStore::open(None).unwrap();
+    VM::from(store).unwrap().genesis_beacon_with_spec_id(private_key, spec.genesis_spec_id().unwrap(), rng).unwrap()
+}
+
+/// Returns a chain spec with the given limits, and the network limits otherwise.
+fn sample_spec(
+    max_block_size_in_bytes: usize,
+    max_transactions_per_block: usize,
+    minimum_transaction_fee: u64,
+) -> ChainSpec<CurrentNetwork> {
+    ChainSpec::new(
+        CurrentNetwork::MAX_SOLUTIONS,
+        max_block_size_in_bytes,
+        max_transactions_per_block,
+        CurrentNetwork::MAX_TRANSACTION_SIZE_IN_BYTES,
+        minimum_transaction_fee,
+        CurrentNetwork::BLOCK_TIME,
+    )
+    .unwrap()
+}
+
+/// Returns a copy of the given block with the given ratifications and transactions, without re-signing it.
+fn tamper_block(
+    block: &Block<CurrentNetwork>,
+    ratifications: Ratifications<CurrentNetwork>,
+    transactions: Transactions<CurrentNetwork>,
+) -> Block<CurrentNetwork> {
+    Block::from_unchecked(
+        block.hash(),
+        block.previous_hash(),
+        *block.header(),
+        block.authority().clone(),
+        ratifications,
+        block.solutions().clone(),
+        block.aborted_solution_ids().clone(),
+        transactions,
+        block.aborted_transaction_ids().clone(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_check_next_block_rejects_excess_transactions() {
+    let rng = &mut TestRng::default();
+
+    // Sample the genesis private key.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+    // Initialize the ledger with a chain spec that allows no transactions.
+    let spec = sample_spec(CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES, 0, 0);
+    let ledger = sample_ledger_with_spec(&private_key, spec, rng);
+
+    // Construct a block with a public transfer.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    assert_eq!(block.transactions().len(), 1);
+
+    // Ensure the block is rejected under the chain spec.
+    let error = ledger.check_next_block(&block, rng).unwrap_err();
+    assert!(error.to_string().contains("contains 1 transactions, exceeding the chain spec limit of 0"), "{error}");
+}
+
+#[test]
+fn test_check_next_block_rejects_excess_transaction_size() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Determine the size of a public transfer.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_size_in_bytes = transaction.to_bytes_le().unwrap().len();
+
+    // Initialize a ledger with a chain spec whose maximum transaction size is one byte below that size.
+    let spec = ChainSpec::new(
+        CurrentNetwork::MAX_SOLUTIONS,
+        CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES,
+        CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK,
+        transaction_size_in_bytes - 1,
+        0,
+        CurrentNetwork::BLOCK_TIME,
+    )
+    .unwrap();
+    let ledger_with_spec = sample_ledger_with_spec(&private_key, spec, rng);
+
+    // Construct a public transfer of the same size in the ledger with the chain spec.
+    let transaction = ledger_with_spec
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    assert_eq!(transaction.to_bytes_le().unwrap().len(), transaction_size_in_bytes);
+
+    // Ensure the ledger with the chain spec aborts the transaction.
+    let block = ledger_with_spec
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction.clone()], rng)
+        .unwrap();
+    assert_eq!(block.aborted_transaction_ids(), &vec![transaction_id]);
+    ledger_with_spec.check_next_block(&block, rng).unwrap();
+
+    // Ensure a block that includes the transaction is rejected under the chain spec.
+    let confirmed = ConfirmedTransaction::accepted_execute(0, transaction, vec![]).unwrap();
+    let block = tamper_block(&block, block.ratifications().clone(), Transactions::from(&[confirmed]));
+    let error = ledger_with_spec.check_next_block(&block, rng).unwrap_err();
+    assert!(error.to_string().contains(&format!("Transaction '{transaction_id}' is")), "{error}");
+}
+
+#[test]
+fn test_check_next_block_rejects_unclaimed_transaction_fees() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Construct a public transfer with a priority fee.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let priority_fee = 1;
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, priority_fee, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+
+    // Construct a valid block, and insert the transaction into it, without claiming its fee in the block reward.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    let confirmed = ConfirmedTransaction::accepted_execute(0, transaction, vec![]).unwrap();
+    let block = tamper_block(&block, block.ratifications().clone(), Transactions::from(&[confirmed]));
+
+    // Ensure the block is rejected, and the error names the transaction.
+    let error = ledger.check_next_block(&block, rng).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Transaction '{transaction_id}' pays a priority fee of {priority_fee} microcredits, exceeding the 0 \
+             microcredits in transaction fees claimed by block {}",
+            block.height()
+        )
+    );
+}
+
+#[test]
+fn test_check_next_block_rejects_mismatched_transaction_fees() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Construct a valid block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+
+    // Claim one more microcredit in the block reward than the transactions pay.
+    let ratifications = Ratifications::try_from_iter(block.ratifications().iter().map(|ratify| match ratify {
+        Ratify::BlockReward(block_reward) => Ratify::BlockReward(block_reward + 1),
+        ratify => ratify.clone(),
+    }))
+    .unwrap();
+    let block = tamper_block(&block, ratifications, block.transactions().clone());
+
+    // Ensure the block is rejected, as its transactions pay less than the claimed transaction fees.
+    let error = ledger.check_next_block(&block, rng).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Block {} claims 1 microcredits in transaction fees, but its transactions pay 0 microcredits",
+            block.height()
+        )
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_check_next_block_sequential_matches_parallel() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+    // Initialize a thread pool with a single thread, which runs every parallel iterator sequentially.
+    let sequential = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+
+    // Construct the next block with a public transfer.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+
+    // Ensure the block is valid, both in parallel and on a single thread.
+    ledger.check_next_block(&block, rng).unwrap();
+    sequential.install(|| ledger.check_next_block(&block, rng)).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the block is now rejected with the same error, both in parallel and on a single thread.
+    let expected = ledger.check_next_block(&block, rng).unwrap_err();
+    let candidate = sequential.install(|| ledger.check_next_block(&block, rng)).unwrap_err();
+    assert_eq!(expected.to_string(), candidate.to_string());
+}
+
+#[test]
+fn test_load_rejects_another_spec() {
+    let rng = &mut TestRng::default();
+
+    // Sample the genesis private key.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Create a genesis block for a chain spec that requires a minimum fee.
+    let spec = sample_spec(CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES, CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK, 1);
+    let genesis = sample_genesis_with_spec(&private_key, &spec, rng);
+    let spec_id = spec.spec_id().unwrap();
+
+    // Ensure the genesis block commits to the spec ID.
+    match genesis.ratifications().iter().next() {
+        Some(Ratify::Genesis(_, _, _, Some(committed_spec_id))) => assert_eq!(**committed_spec_id, spec_id),
+        _ => panic!("The genesis block does not commit to the spec ID"),
+    }
+
+    // Ensure a node with the default chain spec rejects the genesis block, before initializing its store.
+    let store = CurrentConsensusStore::open(None).unwrap();
+    let error = CurrentLedger::load_from_store(genesis.clone(), ChainSpec::default(), store.clone()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Incorrect chain spec: the genesis block commits to spec ID '{spec_id}', but the ledger expects the \
+             default chain spec"
+        )
+    );
+    assert_eq!(store.block_store().max_height(), None);
+    // Ensure a node with another chain spec rejects the genesis block.
+    let another_spec =
+        sample_spec(CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES, CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK, 2);
+    let error = CurrentLedger::load_from_store(genesis.clone(), another_spec.clone(), store.clone()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Incorrect chain spec: the genesis block commits to spec ID '{spec_id}', but the ledger expects spec \
+             ID '{}'",
+            another_spec.spec_id().unwrap()
+        )
+    );
+
+    // Initialize the ledger with its chain spec, and advance it.
+    let ledger = CurrentLedger::load_from_store(genesis.clone(), spec.clone(), store.clone()).unwrap();
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the ledger cannot be reloaded with the default chain spec.
+    let error = CurrentLedger::load_from_store(genesis.clone(), ChainSpec::default(), store.clone()).unwrap_err();
+    assert!(error.to_string().contains("Incorrect chain spec"), "{error}");
+    // Ensure the ledger cannot be reloaded with a genesis block for the default chain spec.
+    let default_genesis = sample_genesis_with_spec(&private_key, &ChainSpec::default(), rng);
+    let error = CurrentLedger::load_from_store(default_genesis, ChainSpec::default(), store.clone()).unwrap_err();
+    assert!(error.to_string().contains("Incorrect genesis block"), "{error}");
+    // Ensure the ledger cannot be reloaded with another genesis block for its chain spec.
+    let another_genesis = sample_genesis_with_spec(&PrivateKey::new(rng).unwrap(), &spec, rng);
+    let error = CurrentLedger::load_from_store(another_genesis, spec.clone(), store.clone()).unwrap_err();
+    assert!(error.to_string().contains("Incorrect genesis block"), "{error}");
+
+    // Ensure the ledger is reloaded with its genesis block and chain spec.
+    let ledger = CurrentLedger::load_from_store(genesis, spec, store).unwrap();
+    assert_eq!(ledger.latest_hash(), block.hash());
+}
+
+#[test]
+fn test_default_spec_genesis_is_unchanged() {
+    // Load the genesis block of the network.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Ensure the genesis block commits to no spec ID, and serializes to the same bytes.
+    assert!(matches!(genesis.ratifications().iter().next(), Some(Ratify::Genesis(_, _, _, None))));
+    assert_eq!(genesis.to_bytes_le().unwrap(), CurrentNetwork::genesis_bytes());
+
+    // Ensure the genesis block is loaded with the default chain spec.
+    let store = CurrentConsensusStore::open(None).unwrap();
+    let ledger = CurrentLedger::load_from_store(genesis.clone(), ChainSpec::default(), store).unwrap();
+    assert_eq!(ledger.latest_hash(), genesis.hash());
+
+    // Ensure the genesis block is rejected with another chain spec.
+    let spec = sample_spec(CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES, CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK, 1);
+    let store = CurrentConsensusStore::open(None).unwrap();
+    let error = CurrentLedger::load_from_store(genesis, spec, store).unwrap_err();
+    assert!(error.to_string().contains("the genesis block commits to the default chain spec"), "{error}");
+}
+
+#[test]
+fn test_spec_block_time() {
+    let rng = &mut TestRng::default();
+
+    // Sample the genesis private key.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Initialize the ledger with a chain spec whose block time is half the network block time.
+    let block_time = CurrentNetwork::BLOCK_TIME / 2;
+    let spec = ChainSpec::new(
+        CurrentNetwork::MAX_SOLUTIONS,
+        CurrentNetwork::MAX_BLOCK_SIZE_IN_BYTES,
+        CurrentNetwork::MAX_TRANSACTIONS_PER_BLOCK,
+        CurrentNetwork::MAX_TRANSACTION_SIZE_IN_BYTES,
+        0,
+        block_time,
+    )
+    .unwrap();
+    let ledger = sample_ledger_with_spec(&private_key, spec, rng);
+
+    // Construct the next block, without solutions or transactions.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+
+    // Ensure the block reward is scheduled by the block time of the chain spec.
+    let expected_block_reward = block_reward(CurrentNetwork::STARTING_SUPPLY, block_time, 0, 0);
+    assert_ne!(expected_block_reward, block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME, 0, 0));
+    assert_eq!(block.ratifications().iter().next(), Some(&Ratify::BlockReward(expected_block_reward)));
+
+    // Ensure the block is valid, and is added to the ledger.
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure a block that claims the block reward for the network block time is rejected.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    let ratifications = Ratifications::try_from_iter(block.ratifications().iter().map(|ratify| match ratify {
+        Ratify::BlockReward(_) => {
+            Ratify::BlockReward(block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME, 0, 0))
+        }
+        ratify => ratify.clone(),
+    }))
+    .unwrap();
+    let block = tamper_block(&block, ratifications, block.transactions().clone());
+    let error = ledger.check_next_block(&block, rng).unwrap_err();
+    assert!(error.to_string().contains("but its transactions pay 0 microcredits"), "{error}");
+}
+
+#[test]
+fn test_default_spec_reproduces_the_network() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment, whose ledger is loaded with the default chain spec.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+    assert_eq!(ledger.spec(), &ChainSpec::default());
+
+    // Construct a large program, with the maximum number of functions.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("large_program.aleo").unwrap();
+    let mut program = format!("program {program_id};\n");
+    for i in 0..CurrentNetwork::MAX_FUNCTIONS {
+        program.push_str(&format!("\nfunction f{i}:\n    input r0 as field.private;\n"));
+        for j in 0..32 {
+            program.push_str(&format!("    mul r{j} r{j} into r{};\n", j + 1));
+        }
+        program.push_str("    output r32 as field.private;\n");
+    }
+    let program = Program::<CurrentNetwork>::from_str(&program).unwrap();
+
+    // Construct a deployment of the large program, and a few public transfers.
+    let mut transactions = vec![ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap()];
+    for amount in 1..=3 {
+        let inputs =
+            [Value::from_str(&format!("{address}")).unwrap(), Value::from_str(&format!("{amount}u64")).unwrap()];
+        transactions.push(
+            ledger
+                .vm
+                .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+                .unwrap(),
+        );
+    }
+
+    // Ensure the default chain spec accepts every transaction that the network accepts.
+    for transaction in &transactions {
+        ledger.vm().check_transaction(transaction, None, rng).unwrap();
+        ledger.spec().check_transaction(transaction).unwrap();
+        ledger.check_transaction_basic(transaction, None, rng).unwrap();
+    }
+
+    // Ensure the next block includes every transaction, without aborting any of them.
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], transactions.clone(), rng).unwrap();
+    assert!(block.aborted_transaction_ids().is_empty());
+    assert_eq!(block.transactions().num_accepted(), transactions.len());
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(program, ledger.get_program(program_id).unwrap());
+
+    // Ensure the genesis block commits to no spec ID, as the chain spec is the default.
+    assert!(matches!(ledger.genesis_block.ratifications().iter().next(), Some(Ratify::Genesis(_, _, _, None))));
+}
+
 #[test]
 fn test_state_path() {
     let rng = &mut TestRng::default();
@@ -86,6 +610,241 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_serial_numbers_and_commitments_with_heights() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Advance the ledger by a few blocks, each spending a record.
+    for _ in 0..3 {
+        // Retrieve an unspent record with a nonzero balance.
+        let microcredits = Identifier::from_str("microcredits").unwrap();
+        let (_, record) = ledger
+            .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+            .unwrap()
+            .find(|(_, record)| match record.data().get(&microcredits) {
+                Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => **amount > 100,
+                _ => false,
+            })
+            .unwrap();
+
+        // Split the record.
+        let inputs = [Value::Record(record), Value::from_str("100u64").unwrap()];
+        let transaction =
+            ledger.vm.execute(&private_key, ("credits.aleo", "split"), inputs.iter(), None, 0, None, rng).unwrap();
+
+        // Construct and add the next block.
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.check_next_block(&block, rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+
+    // Re-scan all transitions for the serial numbers and commitments, with their block heights.
+    let mut expected_serial_numbers = IndexMap::new();
+    let mut expected_commitments = IndexMap::new();
+    for height in 0..=ledger.latest_height() {
+        let block = ledger.get_block(height).unwrap();
+        for transition in block.transitions() {
+            expected_serial_numbers.extend(transition.serial_numbers().map(|serial_number| (*serial_number, height)));
+            expected_commitments.extend(transition.commitments().map(|commitment| (*commitment, height)));
+        }
+    }
+    assert_eq!(expected_serial_numbers.len(), 3);
+
+    // Ensure the iterators match the re-scanned sets.
+    let serial_numbers = ledger.serial_numbers_with_heights().collect::<Result<IndexMap<_, _>>>().unwrap();
+    let commitments = ledger.commitments_with_heights().collect::<Result<IndexMap<_, _>>>().unwrap();
+    assert_eq!(serial_numbers, expected_serial_numbers);
+    assert_eq!(commitments, expected_commitments);
+
+    // Ensure the counts match the iterator lengths.
+    assert_eq!(ledger.num_serial_numbers(), serial_numbers.len());
+    assert_eq!(ledger.num_commitments(), commitments.len());
+
+    // Remove the last block, and retrieve the serial numbers and commitments it contained.
+    let block = ledger.get_block(ledger.latest_height()).unwrap();
+    let num_removed_serial_numbers = block.transitions().flat_map(|transition| transition.serial_numbers()).count();
+    let num_removed_commitments = block.transitions().flat_map(|transition| transition.commitments()).count();
+    assert!(num_removed_serial_numbers > 0);
+    ledger.vm.remove_last_n(1).unwrap();
+
+    // Ensure the counts are updated along with the removed block.
+    assert_eq!(ledger.num_serial_numbers(), serial_numbers.len() - num_removed_serial_numbers);
+    assert_eq!(ledger.num_commitments(), commitments.len() - num_removed_commitments);
+    assert_eq!(ledger.num_serial_numbers(), ledger.serial_numbers_with_heights().count());
+    assert_eq!(ledger.num_commitments(), ledger.commitments_with_heights().count());
+}
+
+#[test]
+fn test_export_table() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Advance the ledger by a block with a public transfer.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction.clone()], rng)
+        .unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    let fee_amount = (*transaction.fee_amount().unwrap()).to_string();
+
+    // Exports the given table as CSV, returning the header and the rows, split into their values.
+    let export_csv = |table: TableKind| {
+        let mut buffer = vec![];
+        ledger.export_table(table, &mut buffer, ExportFormat::Csv).unwrap();
+        // Ensure the export starts with the table and version.
+        let version = format!("# table={} version={}\n", table.name(), TableKind::VERSION);
+        assert!(buffer.starts_with(version.as_bytes()));
+
+        let mut reader = csv::ReaderBuilder::new().comment(Some(b'#')).from_reader(buffer.as_slice());
+        let header = reader.headers().unwrap().iter().map(str::to_string).collect::<Vec<_>>();
+        let rows = reader
+            .records()
+            .map(|record| record.unwrap().iter().map(str::to_string).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        (header, rows)
+    };
+
+    // Compute the expected number of rows.
+    let blocks = (0..=ledger.latest_height()).map(|height| ledger.get_block(height).unwrap()).collect::<Vec<_>>();
+    let num_transactions = blocks.iter().map(|block| block.transactions().len()).sum::<usize>();
+    let num_transitions = blocks.iter().map(|block| block.transitions().count()).sum::<usize>();
+
+    // Ensure the blocks table is correct.
+    let (header, rows) = export_csv(TableKind::Blocks);
+    assert_eq!(header, TableKind::Blocks.columns());
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[1][0], "1");
+    assert_eq!(rows[1][1], block.hash().to_string());
+    assert_eq!(rows[1][2], block.timestamp().to_string());
+    assert_eq!(rows[1][3], "1");
+    assert_eq!(rows[1][4], fee_amount);
+
+    // Ensure the transactions table is correct.
+    let (header, rows) = export_csv(TableKind::Transactions);
+    assert_eq!(header, TableKind::Transactions.columns());
+    assert_eq!(rows.len(), num_transactions);
+    let row = rows.last().unwrap();
+    assert_eq!(row[0], transaction.id().to_string());
+    assert_eq!(row[1], "1");
+    assert_eq!(row[2], fee_amount);
+    assert_eq!(row[3], "execute");
+
+    // Ensure the transitions table is correct.
+    let (header, rows) = export_csv(TableKind::Transitions);
+    assert_eq!(header, TableKind::Transitions.columns());
+    assert_eq!(rows.len(), num_transitions);
+    let transaction_rows = rows.iter().filter(|row| row[1] == transaction.id().to_string()).collect::<Vec<_>>();
+    assert_eq!(transaction_rows.len(), transaction.transitions().count());
+    let transfer = transaction_rows.iter().find(|row| row[3] == "transfer_public").unwrap();
+    assert_eq!(transfer[2], "credits.aleo");
+    assert_eq!(transfer[6], "0");
+    let fee = transaction_rows.iter().find(|row| row[3] == "fee_public").unwrap();
+    assert_eq!(fee[6], fee_amount);
+
+    // Ensure the JSON lines export contains the same rows.
+    let mut buffer = vec![];
+    ledger.export_table(TableKind::Transitions, &mut buffer, ExportFormat::JsonLines).unwrap();
+    let mut rows = String::from_utf8(buffer)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    // Ensure the export starts with the table and version.
+    let version = rows.remove(0);
+    assert_eq!(version["table"], "transitions");
+    assert_eq!(version["version"], TableKind::VERSION);
+    assert_eq!(rows.len(), num_transitions);
+    let transfer = rows
+        .iter()
+        .find(|row| row["transaction_id"] == transaction.id().to_string() && row["function"] == "transfer_public")
+        .unwrap();
+    assert_eq!(transfer["program"], "credits.aleo");
+    assert_eq!(transfer["num_inputs"], 2);
+}
+
+#[test]
+fn test_snapshot() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Advance the ledger by a few blocks with public transfers.
+    for _ in 0..3 {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+        let transaction = ledger
+            .vm
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+            .unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.check_next_block(&block, rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+
+    // Export the snapshot.
+    let mut snapshot = vec![];
+    ledger.export_snapshot(&mut snapshot).unwrap();
+
+    // Import the snapshot into a fresh store.
+    let genesis_block = ledger.get_block(0).unwrap();
+    let import = |snapshot: &[u8]| {
+        CurrentLedger::import_snapshot(snapshot, genesis_block.clone(), ChainSpec::default(), StorageMode::Production)
+    };
+    let candidate = import(&snapshot).unwrap();
+
+    // Ensure the imported ledger matches.
+    assert_eq!(ledger.latest_height(), candidate.latest_height());
+    assert_eq!(ledger.latest_hash(), candidate.latest_hash());
+    assert_eq!(ledger.latest_state_root(), candidate.latest_state_root());
+    assert_eq!(ledger.latest_committee().unwrap(), candidate.latest_committee().unwrap());
+    assert_eq!(
+        ledger.vm.finalize_store().get_checksum_confirmed().unwrap(),
+        candidate.vm.finalize_store().get_checksum_confirmed().unwrap()
+    );
+    // Ensure a random state path matches.
+    let commitments = (0..=ledger.latest_height())
+        .flat_map(|height| ledger.get_block(height).unwrap().transactions().commitments().copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let commitment = &commitments[rng.gen_range(0..commitments.len())];
+    assert_eq!(
+        ledger.get_state_path_for_commitment(commitment).unwrap(),
+        candidate.get_state_path_for_commitment(commitment).unwrap()
+    );
+    // Ensure the imported ledger accepts the next block.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = candidate
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        candidate.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    candidate.check_next_block(&block, rng).unwrap();
+    candidate.advance_to_next_block(&block).unwrap();
+
+    // Ensure a corrupted snapshot is rejected.
+    let mut corrupted = snapshot.clone();
+    corrupted[16] ^= 1;
+    assert!(import(&corrupted).is_err());
+    // Ensure a truncated snapshot is rejected.
+    assert!(import(&snapshot[..snapshot.len() - 1]).is_err());
+    // Ensure an unknown snapshot version is rejected.
+    let mut unknown_version = snapshot;
+    unknown_version[0] += 1;
+    assert!(import(&unknown_version).is_err());
+}
+
 #[test]
 fn test_insufficient_private_fees() {
     let rng = &mut TestRng::default();
@@ -1677,6 +2436,325 @@ fn test_deployment_exceeding_max_transaction_spend() {
     assert!(result.is_err());
 }
 
+/// A seeded harness that drives random `credits.aleo` activity and program deployments through the ledger
+/// for several rounds, and checks global invariants after every block. Failures report the seed and the round.
+mod random_activity {
+    use super::*;
+    use console::{
+        account::ViewKey,
+        program::{Record, BLOCKS_DEPTH},
+    };
+    use ledger_block::Block;
+    use std::collections::HashSet;
+
+    /// The seed of the random chain activity, unless overridden by `SEED_ENV_VAR`.
+    const DEFAULT_SEED: u64 = 0x5eed_ac71_0b17_2024;
+    /// The environment variable that overrides the seed, to reproduce or explore other runs.
+    const SEED_ENV_VAR: &str = "RANDOM_ACTIVITY_SEED";
+    /// The number of blocks to produce.
+    const NUM_ROUNDS: usize = 2;
+    /// The number of blocks to produce in the long run.
+    const NUM_ROUNDS_LONG: usize = 16;
+    /// The maximum number of transactions in each block.
+    const MAX_TRANSACTIONS_PER_ROUND: usize = 3;
+    /// The minimum public balance (in microcredits) an account needs to send a transaction.
+    const MIN_SENDER_BALANCE: u64 = 1_000_000;
+
+    /// An account tracked by the harness, along with its expected balances.
+    struct Account {
+        private_key: PrivateKey<CurrentNetwork>,
+        view_key: ViewKey<CurrentNetwork>,
+        address: Address<CurrentNetwork>,
+        /// The expected public balance, in microcredits.
+        public_balance: u64,
+        /// The expected sum of the unspent records, in microcredits.
+        private_balance: u64,
+    }
+
+    impl Account {
+        /// Samples a new account with no balance.
+        fn new(rng: &mut TestRng) -> Self {
+            let private_key = PrivateKey::new(rng).unwrap();
+            let view_key = ViewKey::try_from(&private_key).unwrap();
+            let address = Address::try_from(&private_key).unwrap();
+            Self { private_key, view_key, address, public_balance: 0, private_balance: 0 }
+        }
+    }
+
+    /// Returns the public balance of the given address in `credits.aleo`.
+    fn public_balance(ledger: &CurrentLedger, address: &Address<CurrentNetwork>) -> u64 {
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(*address));
+        match ledger.vm().finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap() {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+            None => 0,
+            _ => panic!("Unexpected value type"),
+        }
+    }
+
+    /// Returns the number of microcredits in the given record.
+    fn microcredits(record: &Record<CurrentNetwork, Plaintext<CurrentNetwork>>) -> u64 {
+        match record.data().get(&Identifier::from_str("microcredits").unwrap()) {
+            Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => **amount,
+            _ => panic!("Expected a credits record"),
+        }
+    }
+
+    /// Executes the given `credits.aleo` function, paying the fee publicly, and returns the transaction and its fee.
+    fn execute(
+        ledger: &CurrentLedger,
+        private_key: &PrivateKey<CurrentNetwork>,
+        function_name: &str,
+        inputs: Vec<Value<CurrentNetwork>>,
+        context: &str,
+        rng: &mut TestRng,
+    ) -> (Transaction<CurrentNetwork>, u64) {
+        let transaction = ledger
+            .vm
+            .execute(private_key, ("credits.aleo", function_name), inputs.into_iter(), None, 0, None, rng)
+            .unwrap_or_else(|e| panic!("Failed to execute '{function_name}' ({context}): {e}"));
+        let fee = *transaction.fee_amount().unwrap();
+        (transaction, fee)
+    }
+
+    /// Deploys a new program with the given name, paying the fee publicly, and returns the transaction and program.
+    fn deploy(
+        ledger: &CurrentLedger,
+        private_key: &PrivateKey<CurrentNetwork>,
+        name: &str,
+        context: &str,
+        rng: &mut TestRng,
+    ) -> (Transaction<CurrentNetwork>, Program<CurrentNetwork>) {
+        let program = Program::from_str(&format!(
+            r"program {name}.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 1u64 into r1;
+    output r1 as u64.private;"
+        ))
+        .unwrap();
+        let transaction = ledger
+            .vm
+            .deploy(private_key, &program, None, 0, None, rng)
+            .unwrap_or_else(|e| panic!("Failed to deploy '{name}.aleo' ({context}): {e}"));
+        (transaction, program)
+    }
+
+    /// Asserts the ledger invariants after the given block was added.
+    fn check_invariants(
+        ledger: &CurrentLedger,
+        block: &Block<CurrentNetwork>,
+        transactions: &[Transaction<CurrentNetwork>],
+        accounts: &[Account],
+        programs: &[Program<CurrentNetwork>],
+        context: &str,
+    ) {
+        // Ensure every transaction was accepted, and is retrievable from the ledger.
+        assert!(block.aborted_transaction_ids().is_empty(), "Aborted transactions ({context})");
+        for transaction in transactions {
+            let id = transaction.id();
+            let confirmed = ledger.get_confirmed_transaction(id).unwrap();
+            assert!(confirmed.is_accepted(), "Transaction '{id}' was not accepted ({context})");
+            assert!(ledger.contains_transaction_id(&id).unwrap(), "Missing transaction '{id}' ({context})");
+            assert_eq!(&ledger.get_transaction(id).unwrap(), transaction, "Mismatched transaction ({context})");
+            assert_eq!(ledger.find_block_hash(&id).unwrap(), Some(block.hash()), "Mismatched block ({context})");
+        }
+
+        // Ensure the state root matches a recomputation over all block hashes.
+        let hashes = (0..=ledger.latest_height())
+            .map(|height| ledger.get_hash(height).unwrap().to_bits_le())
+            .collect::<Vec<_>>();
+        let block_tree = CurrentNetwork::merkle_tree_bhp::<BLOCKS_DEPTH>(&hashes).unwrap();
+        let expected_state_root = <CurrentNetwork as Network>::StateRoot::from(*block_tree.root());
+        assert_eq!(ledger.latest_state_root(), expected_state_root, "Mismatched state root ({context})");
+        assert!(ledger.contains_state_root(&expected_state_root).unwrap(), "Missing state root ({context})");
+
+        // Ensure every deployed program is retrievable from the ledger, and loaded in the VM.
+        for program in programs {
+            let id = program.id();
+            assert_eq!(&ledger.get_program(*id).unwrap(), program, "Mismatched program '{id}' ({context})");
+            assert!(ledger.vm().contains_program(id), "Program '{id}' is not loaded ({context})");
+        }
+
+        // Ensure no record was spent twice.
+        let serial_numbers = ledger.serial_numbers().map(|serial_number| *serial_number).collect::<Vec<_>>();
+        let num_unique = serial_numbers.iter().collect::<HashSet<_>>().len();
+        assert_eq!(serial_numbers.len(), num_unique, "Found a duplicate serial number ({context})");
+
+        for account in accounts {
+            let address = account.address;
+            // Ensure the public balance matches the expected balance.
+            let balance = public_balance(ledger, &address);
+            assert_eq!(balance, account.public_balance, "Mismatched public balance for '{address}' ({context})");
+
+            // Ensure the unspent records sum to the expected private balance.
+            let unspent = ledger
+                .find_records(&account.view_key, RecordsFilter::Unspent)
+                .unwrap()
+                .map(|(_, record)| microcredits(&record))
+                .sum::<u64>();
+            assert_eq!(unspent, account.private_balance, "Mismatched private balance for '{address}' ({context})");
+
+            // Ensure scanning with the view key agrees with a direct ownership check of every record.
+            let scanned = ledger
+                .find_records(&account.view_key, RecordsFilter::All)
+                .unwrap()
+                .map(|(commitment, _)| commitment)
+                .collect::<HashSet<_>>();
+            let owned = ledger
+                .records()
+                .filter(|(_, record)| record.is_owner(&account.view_key))
+                .map(|(commitment, _)| *commitment)
+                .collect::<HashSet<_>>();
+            assert_eq!(scanned, owned, "Mismatched records for '{address}' ({context})");
+        }
+    }
+
+    /// Returns the seed of the random chain activity.
+    fn seed() -> u64 {
+        match std::env::var(SEED_ENV_VAR) {
+            Ok(seed) => seed.parse().unwrap_or_else(|e| panic!("Failed to parse {SEED_ENV_VAR} as u64: {e}")),
+            Err(_) => DEFAULT_SEED,
+        }
+    }
+
+    #[test]
+    fn test_random_chain_activity() {
+        run_random_chain_activity(seed(), NUM_ROUNDS);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_random_chain_activity_long() {
+        run_random_chain_activity(seed(), NUM_ROUNDS_LONG);
+    }
+
+    /// Produces the given number of blocks of random transactions, checking the ledger invariants after each block.
+    fn run_random_chain_activity(seed: u64, num_rounds: usize) {
+        let rng = &mut TestRng::from_seed(seed);
+
+        // Initialize the test environment.
+        let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+        let mut accounts: Vec<Account> = vec![];
+        let mut programs: Vec<Program<CurrentNetwork>> = vec![];
+
+        for round in 1..=num_rounds {
+            let context = format!("seed {seed}, round {round}");
+
+            // Only accounts funded in a previous block may send transactions, as fees are checked against the ledger.
+            let num_funded = accounts.len();
+            // The commitments of the records spent in this round.
+            let mut spent = HashSet::new();
+            let mut transactions = vec![];
+
+            for _ in 0..rng.gen_range(1..=MAX_TRANSACTIONS_PER_ROUND) {
+                // Select the senders with enough public balance to pay for the amount and the fee.
+                let senders =
+                    (0..num_funded).filter(|i| accounts[*i].public_balance >= MIN_SENDER_BALANCE).collect::<Vec<_>>();
+                let action = match senders.is_empty() || accounts.len() < 2 {
+                    true => 0,
+                    false => rng.gen_range(0..5),
+                };
+                let sender = if senders.is_empty() { 0 } else { senders[rng.gen_range(0..senders.len())] };
+                let receiver = loop {
+                    let receiver = rng.gen_range(0..accounts.len().max(1));
+                    if receiver != sender || accounts.len() < 2 {
+                        break receiver;
+                    }
+                };
+
+                match action {
+                    // Create a new account, and fund it from the genesis account.
+                    0 => {
+                        let mut account = Account::new(rng);
+                        let amount = rng.gen_range(10_000_000..100_000_000u64);
+                        let inputs = vec![
+                            Value::from(Literal::Address(account.address)),
+                            Value::from_str(&format!("{amount}u64")).unwrap(),
+                        ];
+                        let (transaction, _) = execute(&ledger, &private_key, "transfer_public", inputs, &context, rng);
+                        account.public_balance = amount;
+                        accounts.push(account);
+                        transactions.push(transaction);
+                    }
+                    // Transfer public credits between two accounts.
+                    1 => {
+                        let amount = rng.gen_range(1..=accounts[sender].public_balance / 4);
+                        let inputs = vec![
+                            Value::from(Literal::Address(accounts[receiver].address)),
+                            Value::from_str(&format!("{amount}u64")).unwrap(),
+                        ];
+                        let (transaction, fee) =
+                            execute(&ledger, &accounts[sender].private_key, "transfer_public", inputs, &context, rng);
+                        accounts[sender].public_balance -= amount + fee;
+                        accounts[receiver].public_balance += amount;
+                        transactions.push(transaction);
+                    }
+                    // Transfer public credits into a record.
+                    2 => {
+                        let amount = rng.gen_range(1..=accounts[sender].public_balance / 4);
+                        let inputs = vec![
+                            Value::from(Literal::Address(accounts[receiver].address)),
+                            Value::from_str(&format!("{amount}u64")).unwrap(),
+                        ];
+                        let sender_key = &accounts[sender].private_key;
+                        let (transaction, fee) =
+                            execute(&ledger, sender_key, "transfer_public_to_private", inputs, &context, rng);
+                        accounts[sender].public_balance -= amount + fee;
+                        accounts[receiver].private_balance += amount;
+                        transactions.push(transaction);
+                    }
+                    // Deploy a new program, paid for by the genesis account.
+                    3 => {
+                        let name = format!("random_activity_program_{}", programs.len());
+                        let (transaction, program) = deploy(&ledger, &private_key, &name, &context, rng);
+                        programs.push(program);
+                        transactions.push(transaction);
+                    }
+                    // Transfer part of an unspent record, if the sender has one.
+                    _ => {
+                        let record = ledger
+                            .find_records(&accounts[sender].view_key, RecordsFilter::Unspent)
+                            .unwrap_or_else(|e| panic!("Failed to find records ({context}): {e}"))
+                            .find(|(commitment, record)| !spent.contains(commitment) && microcredits(record) > 0);
+                        let Some((commitment, record)) = record else {
+                            continue;
+                        };
+                        let amount = rng.gen_range(1..=microcredits(&record));
+                        let inputs = vec![
+                            Value::Record(record),
+                            Value::from(Literal::Address(accounts[receiver].address)),
+                            Value::from_str(&format!("{amount}u64")).unwrap(),
+                        ];
+                        let (transaction, fee) =
+                            execute(&ledger, &accounts[sender].private_key, "transfer_private", inputs, &context, rng);
+                        spent.insert(commitment);
+                        accounts[sender].public_balance -= fee;
+                        accounts[sender].private_balance -= amount;
+                        accounts[receiver].private_balance += amount;
+                        transactions.push(transaction);
+                    }
+                }
+            }
+
+            // Construct the next block.
+            let block = ledger
+                .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], transactions.clone(), rng)
+                .unwrap_or_else(|e| panic!("Failed to prepare the block ({context}): {e}"));
+            // Check that the next block is valid.
+            ledger.check_next_block(&block, rng).unwrap_or_else(|e| panic!("Invalid block ({context}): {e}"));
+            // Add the block to the ledger.
+            ledger.advance_to_next_block(&block).unwrap_or_else(|e| panic!("Failed to add the block ({context}): {e}"));
+
+            // Check the invariants.
+            check_invariants(&ledger, &block, &transactions, &accounts, &programs, &context);
+        }
+    }
+}
+
 // These tests require the proof targets to be low enough to be able to generate **valid** solutions.
 // This requires the 'test' feature to be enabled for the `console` dependency.
 #[cfg(feature = "test")]
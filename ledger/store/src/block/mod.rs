@@ -19,7 +19,7 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{BigEndian, Map, MapRead, OrderedMap},
     TransactionStorage,
     TransactionStore,
     TransitionStorage,
@@ -47,8 +47,9 @@ use synthesizer_program::{FinalizeOperation, Program};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
+use core::ops::Range;
 use parking_lot::RwLock;
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, collections::BTreeSet, sync::Arc};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -110,7 +111,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The mapping of `state root` to `block height`.
     type ReverseStateRootMap: for<'a> Map<'a, N::StateRoot, u32>;
     /// The mapping of `block height` to `block hash`.
-    type IDMap: for<'a> Map<'a, u32, N::BlockHash>;
+    /// Note: This map must be ordered, as the latest block height and the block ranges are derived from it.
+    type IDMap: for<'a> Map<'a, BigEndian<u32>, N::BlockHash> + for<'a> OrderedMap<'a, BigEndian<u32>, N::BlockHash>;
     /// The mapping of `block hash` to `block height`.
     type ReverseIDMap: for<'a> Map<'a, N::BlockHash, u32>;
     /// The mapping of `block hash` to `block header`.
@@ -139,6 +141,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of `block tree node index` to `block tree node hash`.
+    type TreeMap: for<'a> Map<'a, BigEndian<u64>, Field<N>>
+        + for<'a> OrderedMap<'a, BigEndian<u64>, Field<N>>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -181,6 +186,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the block tree map.
+    fn tree_map(&self) -> &Self::TreeMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -213,6 +220,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.tree_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -235,6 +243,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.tree_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -257,6 +266,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.tree_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -279,6 +289,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.tree_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -301,6 +312,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.tree_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -323,6 +335,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.tree_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -345,6 +358,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.tree_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -395,7 +409,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             self.reverse_state_root_map().insert(state_root, block.height())?;
 
             // Store the block hash.
-            self.id_map().insert(block.height(), block.hash())?;
+            self.id_map().insert(BigEndian(block.height()), block.hash())?;
             // Store the block height.
             self.reverse_id_map().insert(block.hash(), block.height())?;
             // Store the block header.
@@ -526,7 +540,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             self.reverse_state_root_map().remove(&state_root)?;
 
             // Remove the block hash.
-            self.id_map().remove(&block_height)?;
+            self.id_map().remove(&BigEndian(block_height))?;
             // Remove the block height.
             self.reverse_id_map().remove(block_hash)?;
             // Remove the block header.
@@ -735,7 +749,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
         match height.is_zero() {
             true => Ok(Some(N::BlockHash::default())),
-            false => match self.id_map().get_confirmed(&(height - 1))? {
+            false => match self.id_map().get_confirmed(&BigEndian(height - 1))? {
                 Some(block_hash) => Ok(Some(cow_to_copied!(block_hash))),
                 None => Ok(None),
             },
@@ -744,7 +758,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns the block hash for the given `block height`.
     fn get_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
-        match self.id_map().get_confirmed(&height)? {
+        match self.id_map().get_confirmed(&BigEndian(height))? {
             Some(block_hash) => Ok(Some(cow_to_copied!(block_hash))),
             None => Ok(None),
         }
@@ -997,6 +1011,26 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 }
 
+/// Returns the indices of the block tree nodes that change when the given leaves change,
+/// in ascending order, where `num_nodes` is the number of non-leaf nodes in the block tree.
+fn changed_node_indices(num_nodes: usize, leaves: Range<usize>) -> BTreeSet<usize> {
+    let mut indices = BTreeSet::new();
+    for leaf in leaves {
+        let mut index = num_nodes + leaf;
+        // Insert the leaf and its ancestors, stopping at the first ancestor that is already included.
+        while indices.insert(index) && index > 0 {
+            index = (index - 1) / 2;
+        }
+    }
+    indices
+}
+
+#[cfg(test)]
+thread_local! {
+    /// The number of times the block tree was rebuilt from the block hashes on this thread.
+    static NUM_TREE_REBUILDS: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+}
+
 /// The block store.
 #[derive(Clone)]
 pub struct BlockStore<N: Network, B: BlockStorage<N>> {
@@ -1011,27 +1045,123 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize the block storage.
         let storage = B::open(storage)?;
+        // Initialize the block store.
+        Self::from_storage(storage)
+    }
 
-        // Compute the block tree.
-        let tree = {
-            // Prepare an iterator over the block heights.
-            let heights = storage.id_map().keys_confirmed();
-            // Prepare the leaves of the block tree.
-            let hashes = match heights.max() {
-                Some(height) => cfg_into_iter!(0..=cow_to_copied!(height))
-                    .map(|height| match storage.get_block_hash(height)? {
-                        Some(hash) => Ok(hash.to_bits_le()),
-                        None => bail!("Missing block hash for block {height}"),
-                    })
-                    .collect::<Result<Vec<Vec<bool>>>>()?,
-                None => vec![],
-            };
-            // Construct the block tree.
-            Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
+    /// Initializes the block store from the given block storage.
+    fn from_storage(storage: B) -> Result<Self> {
+        // Load the persisted block tree, or rebuild it if it is missing or invalid.
+        let tree = match Self::load_tree(&storage)? {
+            Some(tree) => tree,
+            None => {
+                #[cfg(test)]
+                NUM_TREE_REBUILDS.with(|count| count.set(count.get() + 1));
+
+                // Construct the block tree.
+                let tree: BlockTree<N> = N::merkle_tree_bhp(&Self::tree_leaves(&storage)?)?;
+
+                // Determine the stale nodes of the persisted block tree.
+                let stale_indices = storage
+                    .tree_map()
+                    .range(BigEndian(tree.tree().len() as u64)..)
+                    .map(|(index, _)| *index)
+                    .collect::<Vec<_>>();
+                // Persist the block tree.
+                atomic_batch_scope!(storage, {
+                    // Remove the stale nodes.
+                    for index in &stale_indices {
+                        storage.tree_map().remove(index)?;
+                    }
+                    // Store every node.
+                    Self::persist_nodes(&storage, tree.tree(), 0..tree.tree().len())
+                })?;
+                tree
+            }
         };
 
         // Return the block store.
-        Ok(Self { storage, tree })
+        Ok(Self { storage, tree: Arc::new(RwLock::new(tree)) })
+    }
+
+    /// Returns the leaves of the block tree, in ascending block height order.
+    fn tree_leaves(storage: &B) -> Result<Vec<Vec<bool>>> {
+        storage
+            .id_map()
+            .range(..)
+            .enumerate()
+            .map(|(expected_height, (height, hash))| {
+                ensure!(height.0 as usize == expected_height, "Missing block hash for block {expected_height}");
+                Ok(hash.to_bits_le())
+            })
+            .collect()
+    }
+
+    /// Returns the persisted block tree, or `None` if it is missing, incomplete, its leaves do not match
+    /// the block hashes, its internal nodes do not match its leaves, or its root does not match the latest state root.
+    fn load_tree(storage: &B) -> Result<Option<BlockTree<N>>> {
+        // Determine the number of blocks and the latest state root.
+        let (number_of_leaves, expected_root) = match storage.id_map().last_key() {
+            Some(BigEndian(height)) => match storage.get_state_root(height)? {
+                Some(state_root) => (usize::try_from(height)? + 1, Some(state_root)),
+                None => return Ok(None),
+            },
+            None => (0, None),
+        };
+        // Compute the number of nodes in the block tree.
+        let num_nodes = match number_of_leaves.checked_next_power_of_two() {
+            Some(max_leaves) => 2 * max_leaves - 1,
+            None => return Ok(None),
+        };
+
+        // Load the nodes of the block tree, in ascending index order.
+        let mut nodes = Vec::with_capacity(num_nodes);
+        for (index, node) in storage.tree_map().range(..) {
+            // Ensure the nodes are contiguous, and there are no extra nodes.
+            if index.0 != nodes.len() as u64 || nodes.len() == num_nodes {
+                return Ok(None);
+            }
+            nodes.push(cow_to_copied!(node));
+        }
+        // Construct the block tree, which ensures the internal nodes match the leaves.
+        let tree: BlockTree<N> = match N::merkle_tree_bhp_from_tree(nodes, number_of_leaves) {
+            Ok(tree) => tree,
+            Err(_) => return Ok(None),
+        };
+        // Ensure the leaves of the block tree match the block hashes.
+        if tree.check_leaves(&Self::tree_leaves(storage)?).is_err() {
+            return Ok(None);
+        }
+        // Ensure the root of the block tree matches the latest state root.
+        match expected_root {
+            Some(expected_root) if *tree.root() != *expected_root => Ok(None),
+            _ => Ok(Some(tree)),
+        }
+    }
+
+    /// Persists the nodes of the `updated` block tree that differ from the `current` block tree,
+    /// given that only the leaves in `leaves` differ between the two block trees.
+    fn persist_tree(storage: &B, current: &BlockTree<N>, updated: &BlockTree<N>, leaves: Range<usize>) -> Result<()> {
+        let (current, updated) = (current.tree(), updated.tree());
+        // If the block tree was resized, the nodes have moved, so every node is stored.
+        if current.len() != updated.len() {
+            Self::persist_nodes(storage, updated, 0..updated.len())?;
+            // Remove the nodes that are no longer in the block tree.
+            for index in updated.len()..current.len() {
+                storage.tree_map().remove(&BigEndian(index as u64))?;
+            }
+            return Ok(());
+        }
+        // Otherwise, only the given leaves and their ancestors have changed.
+        Self::persist_nodes(storage, updated, changed_node_indices(updated.len() / 2, leaves))
+    }
+
+    /// Stores the nodes at the given indices of the block tree.
+    fn persist_nodes(storage: &B, nodes: &[Field<N>], indices: impl IntoIterator<Item = usize>) -> Result<()> {
+        for index in indices {
+            storage.tree_map().insert(BigEndian(index as u64), nodes[index])?;
+        }
+        Ok(())
     }
 
     /// Stores the given block into storage.
@@ -1044,8 +1174,13 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+        atomic_batch_scope!(self, {
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            // Persist the updated block tree.
+            let leaves = tree.number_of_leaves()..updated_tree.number_of_leaves();
+            Self::persist_tree(&self.storage, &tree, &updated_tree, leaves)
+        })?;
         // Update the block tree.
         *tree = updated_tree;
         // Return success.
@@ -1060,6 +1195,9 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         let mut tree = self.tree.write();
         // Prepare an updated Merkle tree removing the last 'n' block hashes.
         let updated_tree = tree.prepare_remove_last_n(usize::try_from(n)?)?;
+        // Persist the updated block tree.
+        let leaves = updated_tree.number_of_leaves()..tree.number_of_leaves();
+        atomic_batch_scope!(self, { Self::persist_tree(&self.storage, &tree, &updated_tree, leaves) })?;
         // Update the block tree.
         *tree = updated_tree;
         // Return success.
@@ -1075,10 +1213,8 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         let mut tree = self.tree.write();
 
         // Determine the block heights to remove.
-        let heights = match self.storage.id_map().keys_confirmed().max() {
-            Some(height) => {
-                // Determine the end block height to remove.
-                let end_height = cow_to_copied!(height);
+        let heights = match self.storage.id_map().last_key() {
+            Some(BigEndian(end_height)) => {
                 // Determine the start block height to remove.
                 let start_height = end_height
                     .checked_sub(n - 1)
@@ -1106,7 +1242,9 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
             for block_hash in hashes.iter().rev() {
                 self.storage.remove(block_hash)?;
             }
-            Ok(())
+            // Persist the updated block tree.
+            let leaves = updated_tree.number_of_leaves()..tree.number_of_leaves();
+            Self::persist_tree(&self.storage, &tree, &updated_tree, leaves)
         })?;
 
         // Update the block tree.
@@ -1304,7 +1442,7 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Returns `true` if the given block height exists.
     pub fn contains_block_height(&self, height: u32) -> Result<bool> {
-        self.storage.id_map().contains_key_confirmed(&height)
+        self.storage.id_map().contains_key_confirmed(&BigEndian(height))
     }
 
     /// Returns `true` if the given block hash exists.
@@ -1350,9 +1488,31 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.reverse_state_root_map().keys_confirmed()
     }
 
-    /// Returns an iterator over the block heights, for all blocks in `self`.
+    /// Returns an iterator over the block heights, for all blocks in `self`, in ascending order.
     pub fn heights(&self) -> impl '_ + Iterator<Item = Cow<'_, u32>> {
-        self.storage.id_map().keys_confirmed()
+        self.storage.id_map().keys_confirmed().map(|height| Cow::Owned(height.0))
+    }
+
+    /// Returns the latest block height in `self`, if any.
+    pub fn max_height(&self) -> Option<u32> {
+        self.storage.id_map().last_key().map(|height| height.0)
+    }
+
+    /// Returns the block headers for the given block heights, in ascending block height order.
+    pub fn headers_in_range(&self, heights: Range<u32>) -> Result<Vec<Header<N>>> {
+        let (start, end) = (heights.start, heights.end);
+        let headers = self
+            .storage
+            .id_map()
+            .range(BigEndian(start)..BigEndian(end))
+            .map(|(height, hash)| match self.storage.header_map().get_confirmed(&hash)? {
+                Some(header) => Ok(cow_to_copied!(header)),
+                None => bail!("Missing block header for block {}", height.0),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure every block in the range exists.
+        ensure!(headers.len() == heights.len(), "Missing block headers in the block range {start}..{end}");
+        Ok(headers)
     }
 
     /// Returns an iterator over the block hashes, for all blocks in `self`.
@@ -1364,6 +1524,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn solution_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, SolutionID<N>>> {
         self.storage.solution_ids_map().keys_confirmed()
     }
+
+    /// Returns an iterator over the block headers, for all blocks in `self`.
+    pub fn headers(&self) -> impl '_ + Iterator<Item = Cow<'_, Header<N>>> {
+        self.storage.header_map().values_confirmed()
+    }
 }
 
 #[cfg(test)]
@@ -1371,6 +1536,8 @@ mod tests {
     use super::*;
     use crate::helpers::memory::BlockMemory;
 
+    use std::collections::HashSet;
+
     type CurrentNetwork = console::network::MainnetV0;
 
     #[test]
@@ -1403,6 +1570,110 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_changed_node_indices() {
+        // A block tree with 4 leaves has 3 non-leaf nodes, and its leaves are at indices 3 to 6.
+        assert_eq!(changed_node_indices(3, 0..0), BTreeSet::new());
+        assert_eq!(changed_node_indices(3, 0..1), BTreeSet::from([0, 1, 3]));
+        assert_eq!(changed_node_indices(3, 3..4), BTreeSet::from([0, 2, 6]));
+        assert_eq!(changed_node_indices(3, 1..3), BTreeSet::from([0, 1, 2, 4, 5]));
+        assert_eq!(changed_node_indices(3, 0..4), BTreeSet::from([0, 1, 2, 3, 4, 5, 6]));
+        // A block tree with 1 leaf has no non-leaf nodes.
+        assert_eq!(changed_node_indices(0, 0..1), BTreeSet::from([0]));
+    }
+
+    #[test]
+    fn test_load_tree() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        assert!(block.transactions().num_accepted() > 0, "This test must be run with at least one transaction.");
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Ensure the empty block tree is persisted.
+        let tree = BlockStore::<CurrentNetwork, BlockMemory<_>>::load_tree(&block_store.storage).unwrap().unwrap();
+        assert_eq!(tree.root(), block_store.tree.read().root());
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+        // Ensure the updated block tree is persisted.
+        let tree = BlockStore::<CurrentNetwork, BlockMemory<_>>::load_tree(&block_store.storage).unwrap().unwrap();
+        assert_eq!(tree.tree(), block_store.tree.read().tree());
+
+        // Reopen the block store on the same storage.
+        let num_rebuilds = NUM_TREE_REBUILDS.with(|count| count.get());
+        let candidate =
+            BlockStore::<CurrentNetwork, BlockMemory<_>>::from_storage(block_store.storage.clone()).unwrap();
+        assert_eq!(candidate.current_state_root(), block_store.current_state_root());
+        // Ensure the block tree was loaded, rather than rebuilt.
+        assert_eq!(NUM_TREE_REBUILDS.with(|count| count.get()), num_rebuilds);
+        // Ensure the state paths are identical.
+        for commitment in block.transactions().commitments() {
+            let expected = block_store.get_state_path_for_commitment(commitment).unwrap();
+            assert!(candidate.get_state_path_for_commitment(commitment).unwrap() == expected);
+        }
+
+        // Corrupt the persisted block tree.
+        block_store.storage.tree_map().insert(BigEndian(0), Field::zero()).unwrap();
+        // Ensure the corrupted block tree is not loaded.
+        assert!(BlockStore::<CurrentNetwork, BlockMemory<_>>::load_tree(&block_store.storage).unwrap().is_none());
+        // Remove the last node of the persisted block tree.
+        let num_nodes = block_store.tree.read().tree().len() as u64;
+        block_store.storage.tree_map().remove(&BigEndian(num_nodes - 1)).unwrap();
+        // Ensure the incomplete block tree is not loaded.
+        assert!(BlockStore::<CurrentNetwork, BlockMemory<_>>::load_tree(&block_store.storage).unwrap().is_none());
+        // Ensure reopening the block store rebuilds and persists the block tree.
+        let candidate =
+            BlockStore::<CurrentNetwork, BlockMemory<_>>::from_storage(block_store.storage.clone()).unwrap();
+        assert_eq!(candidate.current_state_root(), block_store.current_state_root());
+        assert_eq!(NUM_TREE_REBUILDS.with(|count| count.get()), num_rebuilds + 1);
+        assert!(BlockStore::<CurrentNetwork, BlockMemory<_>>::load_tree(&block_store.storage).unwrap().is_some());
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+        // Ensure the reverted block tree is persisted.
+        let tree = BlockStore::<CurrentNetwork, BlockMemory<_>>::load_tree(&block_store.storage).unwrap().unwrap();
+        assert_eq!(tree.tree(), block_store.tree.read().tree());
+    }
+
+    #[test]
+    fn test_iterators() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        assert!(block.transactions().num_accepted() > 0, "This test must be run with at least one transaction.");
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        assert_eq!(block_store.headers().count(), 0);
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the headers are correct.
+        let headers = block_store.headers().map(|header| header.into_owned()).collect::<Vec<_>>();
+        assert_eq!(headers, vec![*block.header()]);
+
+        // Ensure the serial numbers and commitments are correct.
+        let transaction_store = block_store.transaction_store();
+        let serial_numbers = transaction_store.serial_numbers().map(|id| id.into_owned()).collect::<HashSet<_>>();
+        assert_eq!(serial_numbers, block.transactions().serial_numbers().copied().collect::<HashSet<_>>());
+        let commitments = transaction_store.commitments().map(|id| id.into_owned()).collect::<HashSet<_>>();
+        assert_eq!(commitments, block.transactions().commitments().copied().collect::<HashSet<_>>());
+        assert!(!commitments.is_empty());
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        // Ensure the iterators are empty.
+        assert_eq!(block_store.headers().count(), 0);
+        assert_eq!(block_store.transaction_store().serial_numbers().count(), 0);
+        assert_eq!(block_store.transaction_store().commitments().count(), 0);
+    }
+
     #[test]
     fn test_find_block_hash() {
         let rng = &mut TestRng::default();
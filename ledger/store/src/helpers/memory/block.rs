@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory},
+    helpers::{
+        memory::{MemoryMap, TransactionMemory, TransitionMemory},
+        BigEndian,
+    },
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
@@ -36,7 +39,7 @@ pub struct BlockMemory<N: Network> {
     /// The mapping of `state root` to `block height`.
     reverse_state_root_map: MemoryMap<N::StateRoot, u32>,
     /// The mapping of `block height` to `block hash`.
-    id_map: MemoryMap<u32, N::BlockHash>,
+    id_map: MemoryMap<BigEndian<u32>, N::BlockHash>,
     /// The mapping of `block hash` to `block height`.
     reverse_id_map: MemoryMap<N::BlockHash, u32>,
     /// The header map.
@@ -66,6 +69,8 @@ pub struct BlockMemory<N: Network> {
         MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: MemoryMap<Field<N>, Rejected<N>>,
+    /// The block tree map.
+    tree_map: MemoryMap<BigEndian<u64>, Field<N>>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
 }
@@ -74,7 +79,7 @@ pub struct BlockMemory<N: Network> {
 impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type StateRootMap = MemoryMap<u32, N::StateRoot>;
     type ReverseStateRootMap = MemoryMap<N::StateRoot, u32>;
-    type IDMap = MemoryMap<u32, N::BlockHash>;
+    type IDMap = MemoryMap<BigEndian<u32>, N::BlockHash>;
     type ReverseIDMap = MemoryMap<N::BlockHash, u32>;
     type HeaderMap = MemoryMap<N::BlockHash, Header<N>>;
     type AuthorityMap = MemoryMap<N::BlockHash, Authority<N>>;
@@ -89,6 +94,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
+    type TreeMap = MemoryMap<BigEndian<u64>, Field<N>>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -117,6 +123,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
+            tree_map: MemoryMap::default(),
             transaction_store,
         })
     }
@@ -206,6 +213,11 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the block tree map.
+    fn tree_map(&self) -> &Self::TreeMap {
+        &self.tree_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
@@ -14,11 +14,15 @@
 
 #![allow(clippy::type_complexity)]
 
-use crate::helpers::{Map, MapRead};
+use crate::helpers::{Map, MapRead, OrderedKey, OrderedMap};
 use console::network::prelude::*;
 use indexmap::IndexMap;
 
-use core::{borrow::Borrow, hash::Hash};
+use core::{
+    borrow::Borrow,
+    hash::Hash,
+    ops::{Bound, RangeBounds},
+};
 use parking_lot::{Mutex, RwLock};
 use std::{
     borrow::Cow,
@@ -190,6 +194,12 @@ impl<
     /// Finishes an atomic operation, performing all the queued writes.
     ///
     fn finish_atomic(&self) -> Result<()> {
+        // Acquire a write lock on the map.
+        //
+        // Note: The lock is acquired before the atomic batch is taken, so that a concurrent speculative
+        // read cannot miss the queued operations before they are visible in the map.
+        let mut locked_map = self.map.write();
+
         // Retrieve the atomic batch.
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
 
@@ -197,9 +207,6 @@ impl<
         let operations: IndexMap<_, _> = IndexMap::from_iter(operations);
 
         if !operations.is_empty() {
-            // Acquire a write lock on the map.
-            let mut locked_map = self.map.write();
-
             // Prepare the key and value for each queued operation.
             //
             // Note: This step is taken to ensure (with 100% certainty) that there will be
@@ -220,6 +227,7 @@ impl<
                 };
             }
         }
+        drop(locked_map);
 
         // Clear the checkpoint stack.
         *self.checkpoint.lock() = Default::default();
@@ -352,6 +360,18 @@ impl<
         self.map.read().clone().into_iter().map(|(k, v)| (Cow::Owned(bincode::deserialize(&k).unwrap()), Cow::Owned(v)))
     }
 
+    ///
+    /// Returns an iterator visiting each key-value pair in the map, starting from
+    /// the first key whose serialized bytes are greater than or equal to those of the given key.
+    ///
+    fn iter_from(&'a self, start: &K) -> Self::Iterator {
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        let start = bincode::serialize(start).unwrap();
+        let entries: BTreeMap<_, _> = self.map.read().range(start..).map(|(k, v)| (k.clone(), v.clone())).collect();
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        entries.into_iter().map(|(k, v)| (Cow::Owned(bincode::deserialize(&k).unwrap()), Cow::Owned(v)))
+    }
+
     ///
     /// Returns an iterator over each key in the map.
     ///
@@ -368,6 +388,59 @@ impl<
     }
 }
 
+impl<
+    'a,
+    K: 'a + OrderedKey + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> OrderedMap<'a, K, V> for MemoryMap<K, V>
+{
+    ///
+    /// Returns an iterator visiting each confirmed key-value pair with a key in the given bounds,
+    /// in ascending key order.
+    ///
+    fn range<R: RangeBounds<K>>(&'a self, bounds: R) -> Self::Iterator {
+        // Serialize the bounds.
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        let serialize = |bound: Bound<&K>| match bound {
+            Bound::Included(key) => Bound::Included(bincode::serialize(key).unwrap()),
+            Bound::Excluded(key) => Bound::Excluded(bincode::serialize(key).unwrap()),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        let (start, end) = (serialize(bounds.start_bound()), serialize(bounds.end_bound()));
+        // Note: `BTreeMap::range` panics on decreasing bounds, which are treated as an empty range instead.
+        let is_empty = match (&start, &end) {
+            (Bound::Included(start), Bound::Included(end)) => start > end,
+            (Bound::Included(start) | Bound::Excluded(start), Bound::Included(end) | Bound::Excluded(end)) => {
+                start >= end
+            }
+            _ => false,
+        };
+        // Note: The serialized keys sort in the same order as the keys, as they are ordered keys.
+        let entries: BTreeMap<_, _> = match is_empty {
+            true => BTreeMap::new(),
+            false => self.map.read().range::<Vec<u8>, _>((start, end)).map(|(k, v)| (k.clone(), v.clone())).collect(),
+        };
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        entries.into_iter().map(|(k, v)| (Cow::Owned(bincode::deserialize(&k).unwrap()), Cow::Owned(v)))
+    }
+
+    ///
+    /// Returns the smallest confirmed key in the map, if the map is not empty.
+    ///
+    fn first_key(&'a self) -> Option<K> {
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        self.map.read().keys().next().map(|k| bincode::deserialize(k).unwrap())
+    }
+
+    ///
+    /// Returns the largest confirmed key in the map, if the map is not empty.
+    ///
+    fn last_key(&'a self) -> Option<K> {
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        self.map.read().keys().next_back().map(|k| bincode::deserialize(k).unwrap())
+    }
+}
+
 impl<
     K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
     V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
@@ -383,8 +456,8 @@ impl<
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{atomic_batch_scope, atomic_finalize, FinalizeMode};
-    use console::{account::Address, network::MainnetV0};
+    use crate::{atomic_batch_scope, atomic_finalize, helpers::BigEndian, FinalizeMode};
+    use console::{account::Address, network::MainnetV0, types::Field};
 
     type CurrentNetwork = MainnetV0;
 
@@ -436,6 +509,30 @@ mod tests {
         crate::helpers::test_helpers::map::check_iterators_match(map);
     }
 
+    #[test]
+    fn test_check_ordered_map() {
+        // Initialize a map.
+        let map: MemoryMap<BigEndian<u32>, String> = Default::default();
+
+        crate::helpers::test_helpers::map::check_ordered_map(map);
+    }
+
+    #[test]
+    fn test_check_iter_from() {
+        // Check the iteration order of integer keys.
+        crate::helpers::test_helpers::map::check_iter_from(
+            MemoryMap::<u32, String>::default(),
+            &[1000, 1, 256, 0, 65536, 255],
+            &[2, 257, u32::MAX],
+        );
+
+        // Check the iteration order of field keys.
+        let rng = &mut TestRng::default();
+        let keys: Vec<Field<CurrentNetwork>> = (0..100).map(|_| Uniform::rand(rng)).collect();
+        let missing: Vec<Field<CurrentNetwork>> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        crate::helpers::test_helpers::map::check_iter_from(MemoryMap::default(), &keys, &missing);
+    }
+
     #[test]
     fn test_atomic_writes_are_batched() {
         // Initialize a map.
@@ -973,4 +1070,94 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_atomic_batch_is_isolated_from_concurrent_readers() {
+        const NUM_KEYS: usize = 64;
+        const NUM_BATCHES: usize = 200;
+        const NUM_READERS: usize = 4;
+
+        // Initialize a map, in which every key holds the index of the batch that last wrote it.
+        let map: MemoryMap<usize, usize> = Default::default();
+        let is_done = Arc::new(AtomicBool::new(false));
+
+        std::thread::scope(|scope| {
+            // Spawn the readers, which must only ever see a pre-batch or a post-batch state.
+            for _ in 0..NUM_READERS {
+                let (map, is_done) = (map.clone(), is_done.clone());
+                scope.spawn(move || {
+                    while !is_done.load(Ordering::SeqCst) {
+                        let entries = map.iter_confirmed().map(|(k, v)| (*k, *v)).collect::<Vec<_>>();
+                        // The map is either still empty, or it holds every key from a single batch.
+                        if let Some((_, batch)) = entries.first() {
+                            assert_eq!(entries.len(), NUM_KEYS);
+                            assert!(entries.iter().all(|(_, value)| value == batch), "Observed a partial batch");
+                        }
+                    }
+                });
+            }
+
+            // Write every batch atomically, aborting every other one after it has been queued.
+            for batch in 0..NUM_BATCHES {
+                map.start_atomic();
+                for key in 0..NUM_KEYS {
+                    map.insert(key, batch).unwrap();
+                }
+                match batch % 2 {
+                    0 => map.finish_atomic().unwrap(),
+                    _ => map.abort_atomic(),
+                }
+            }
+            is_done.store(true, Ordering::SeqCst);
+        });
+
+        // Ensure the map holds the last committed batch.
+        assert!(map.iter_confirmed().all(|(_, value)| *value == NUM_BATCHES - 2));
+        assert_eq!(map.len_confirmed(), NUM_KEYS);
+    }
+
+    #[test]
+    fn test_speculative_reads_race_finish_atomic() {
+        const NUM_KEYS: usize = 16;
+        const NUM_BATCHES: usize = 500;
+        const NUM_READERS: usize = 4;
+
+        // Initialize a map, in which every key holds the index of the batch that last wrote it.
+        let map: MemoryMap<usize, usize> = Default::default();
+        for key in 0..NUM_KEYS {
+            map.insert(key, 0).unwrap();
+        }
+        let is_done = Arc::new(AtomicBool::new(false));
+
+        std::thread::scope(|scope| {
+            // Spawn the readers, which must see every key at all times, with a value that never decreases.
+            for _ in 0..NUM_READERS {
+                let (map, is_done) = (map.clone(), is_done.clone());
+                scope.spawn(move || {
+                    let mut last_seen = [0usize; NUM_KEYS];
+                    while !is_done.load(Ordering::SeqCst) {
+                        for (key, last_seen) in last_seen.iter_mut().enumerate() {
+                            assert!(map.contains_key_speculative(&key).unwrap(), "Key {key} went missing");
+                            let value = *map.get_speculative(&key).unwrap().expect("Key went missing");
+                            assert!(value >= *last_seen, "Key {key} went back from {last_seen} to {value}");
+                            *last_seen = value;
+                        }
+                    }
+                });
+            }
+
+            // Write every batch atomically, while the readers race against `finish_atomic`.
+            for batch in 1..=NUM_BATCHES {
+                map.start_atomic();
+                for key in 0..NUM_KEYS {
+                    map.insert(key, batch).unwrap();
+                }
+                map.finish_atomic().unwrap();
+            }
+            is_done.store(true, Ordering::SeqCst);
+        });
+
+        // Ensure the map holds the last batch.
+        assert!(map.iter_confirmed().all(|(_, value)| *value == NUM_BATCHES));
+    }
 }
@@ -127,6 +127,8 @@ pub struct InputMemory<N: Network> {
     record_tag: MemoryMap<Field<N>, Field<N>>,
     /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
     external_record: MemoryMap<Field<N>, ()>,
+    /// The mapping of `()` to `number of serial numbers`.
+    serial_number_count: MemoryMap<u8, u64>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -141,6 +143,7 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
     type RecordMap = MemoryMap<Field<N>, Field<N>>;
     type RecordTagMap = MemoryMap<Field<N>, Field<N>>;
     type ExternalRecordMap = MemoryMap<Field<N>, ()>;
+    type SerialNumberCountMap = MemoryMap<u8, u64>;
 
     /// Initializes the transition input storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -153,6 +156,7 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
             record: MemoryMap::default(),
             record_tag: MemoryMap::default(),
             external_record: MemoryMap::default(),
+            serial_number_count: MemoryMap::default(),
             storage_mode: storage.into(),
         })
     }
@@ -197,6 +201,11 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
         &self.external_record
     }
 
+    /// Returns the serial number count map.
+    fn serial_number_count_map(&self) -> &Self::SerialNumberCountMap {
+        &self.serial_number_count
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
@@ -225,6 +234,8 @@ pub struct OutputMemory<N: Network> {
     external_record: MemoryMap<Field<N>, ()>,
     /// The mapping of `future hash` to `(optional) future`.
     future: MemoryMap<Field<N>, Option<Future<N>>>,
+    /// The mapping of `()` to `number of commitments`.
+    commitment_count: MemoryMap<u8, u64>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -240,6 +251,7 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
     type RecordNonceMap = MemoryMap<Group<N>, Field<N>>;
     type ExternalRecordMap = MemoryMap<Field<N>, ()>;
     type FutureMap = MemoryMap<Field<N>, Option<Future<N>>>;
+    type CommitmentCountMap = MemoryMap<u8, u64>;
 
     /// Initializes the transition output storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -253,6 +265,7 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
             record_nonce: Default::default(),
             external_record: Default::default(),
             future: Default::default(),
+            commitment_count: Default::default(),
             storage_mode: storage.into(),
         })
     }
@@ -302,6 +315,11 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
         &self.future
     }
 
+    /// Returns the commitment count map.
+    fn commitment_count_map(&self) -> &Self::CommitmentCountMap {
+        &self.commitment_count
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
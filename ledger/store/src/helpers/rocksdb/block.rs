@@ -13,12 +13,17 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{
-        internal::{self, DataMap, Database},
-        BlockMap,
-        MapID,
-        TransactionDB,
-        TransitionDB,
+    helpers::{
+        rocksdb::{
+            internal::{self, DataMap, Database},
+            BlockMap,
+            MapID,
+            TransactionDB,
+            TransitionDB,
+        },
+        BigEndian,
+        Map,
+        MapRead,
     },
     BlockStorage,
     ConfirmedTxType,
@@ -42,7 +47,7 @@ pub struct BlockDB<N: Network> {
     /// The mapping of `state root` to `block height`.
     reverse_state_root_map: DataMap<N::StateRoot, u32>,
     /// The mapping of `block height` to `block hash`.
-    id_map: DataMap<u32, N::BlockHash>,
+    id_map: DataMap<BigEndian<u32>, N::BlockHash>,
     /// The mapping of `block hash` to `block height`.
     reverse_id_map: DataMap<N::BlockHash, u32>,
     /// The header map.
@@ -72,6 +77,8 @@ pub struct BlockDB<N: Network> {
         DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: DataMap<Field<N>, Rejected<N>>,
+    /// The block tree map.
+    tree_map: DataMap<BigEndian<u64>, Field<N>>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionDB<N>>,
 }
@@ -80,7 +87,7 @@ pub struct BlockDB<N: Network> {
 impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type StateRootMap = DataMap<u32, N::StateRoot>;
     type ReverseStateRootMap = DataMap<N::StateRoot, u32>;
-    type IDMap = DataMap<u32, N::BlockHash>;
+    type IDMap = DataMap<BigEndian<u32>, N::BlockHash>;
     type ReverseIDMap = DataMap<N::BlockHash, u32>;
     type HeaderMap = DataMap<N::BlockHash, Header<N>>;
     type AuthorityMap = DataMap<N::BlockHash, Authority<N>>;
@@ -95,6 +102,7 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = DataMap<Field<N>, Rejected<N>>;
+    type TreeMap = DataMap<BigEndian<u64>, Field<N>>;
     type TransactionStorage = TransactionDB<N>;
     type TransitionStorage = TransitionDB<N>;
 
@@ -104,11 +112,15 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         let transition_store = TransitionStore::<N, TransitionDB<N>>::open(storage.clone())?;
         // Initialize the transaction store.
         let transaction_store = TransactionStore::<N, TransactionDB<N>>::open(transition_store)?;
+        // Initialize the ID map, migrating the entries of the legacy ID map into it.
+        let id_map = internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ID))?;
+        let legacy_id_map = internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::LegacyID))?;
+        migrate_legacy_id_map(&legacy_id_map, &id_map)?;
         // Return the block storage.
         Ok(Self {
             state_root_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::StateRoot))?,
             reverse_state_root_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ReverseStateRoot))?,
-            id_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ID))?,
+            id_map,
             reverse_id_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ReverseID))?,
             header_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Header))?,
             authority_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Authority))?,
@@ -122,7 +134,8 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             aborted_transaction_ids_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedTransactionIDs))?,
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ConfirmedTransactions))?,
-            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            tree_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::Tree))?,
             transaction_store,
         })
     }
@@ -212,8 +225,98 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the block tree map.
+    fn tree_map(&self) -> &Self::TreeMap {
+        &self.tree_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
     }
 }
+
+/// Moves the entries of the legacy ID map, whose block heights are stored in little-endian,
+/// into the ID map, whose block heights are stored in big-endian, in one atomic batch.
+///
+/// This fails if both maps contain entries, as the storage is then in an inconsistent state.
+fn migrate_legacy_id_map<'a, H: 'a + Copy + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync>(
+    legacy_id_map: &'a impl Map<'a, u32, H>,
+    id_map: &'a impl Map<'a, BigEndian<u32>, H>,
+) -> Result<()> {
+    // If the legacy ID map is empty, there is nothing to migrate.
+    if legacy_id_map.iter_confirmed().next().is_none() {
+        return Ok(());
+    }
+    // Ensure the ID map is empty, so that the migration does not overwrite existing entries.
+    ensure!(
+        id_map.iter_confirmed().next().is_none(),
+        "Found block heights in both the legacy and the current block ID maps - the storage is corrupted"
+    );
+
+    // Collect the legacy entries.
+    let entries = legacy_id_map.iter_confirmed().map(|(height, hash)| (*height, *hash)).collect::<Vec<_>>();
+
+    // Move the entries in one atomic batch, so that a failure leaves the legacy ID map intact.
+    // Note: Both maps share the same database, so the batch is written when the outermost atomic write finishes.
+    id_map.start_atomic();
+    legacy_id_map.start_atomic();
+    let result = entries.into_iter().try_for_each(|(height, hash)| {
+        id_map.insert(BigEndian(height), hash)?;
+        legacy_id_map.remove(&height)
+    });
+    match result {
+        Ok(()) => {
+            legacy_id_map.finish_atomic()?;
+            id_map.finish_atomic()
+        }
+        Err(error) => {
+            legacy_id_map.abort_atomic();
+            id_map.abort_atomic();
+            Err(error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::memory::MemoryMap;
+
+    #[test]
+    fn test_migrate_legacy_id_map() {
+        // Prepare a legacy ID map with a few block heights.
+        let legacy_id_map = MemoryMap::<u32, u64>::default();
+        for height in [0u32, 1, 255, 256, 65_536] {
+            legacy_id_map.insert(height, u64::from(height) * 10).unwrap();
+        }
+
+        // Migrate the entries into an empty ID map.
+        let id_map = MemoryMap::<BigEndian<u32>, u64>::default();
+        migrate_legacy_id_map(&legacy_id_map, &id_map).unwrap();
+
+        // Ensure the legacy ID map is empty, and the ID map contains every entry.
+        assert!(legacy_id_map.iter_confirmed().next().is_none());
+        for height in [0u32, 1, 255, 256, 65_536] {
+            assert_eq!(Some(u64::from(height) * 10), id_map.get_confirmed(&BigEndian(height)).unwrap().map(|h| *h));
+        }
+
+        // Ensure a second migration is a no-op.
+        migrate_legacy_id_map(&legacy_id_map, &id_map).unwrap();
+        assert_eq!(5, id_map.iter_confirmed().count());
+    }
+
+    #[test]
+    fn test_migrate_legacy_id_map_fails_on_conflict() {
+        // Prepare a legacy ID map and an ID map that both contain entries.
+        let legacy_id_map = MemoryMap::<u32, u64>::default();
+        legacy_id_map.insert(1, 10).unwrap();
+        let id_map = MemoryMap::<BigEndian<u32>, u64>::default();
+        id_map.insert(BigEndian(0), 0).unwrap();
+
+        // Ensure the migration fails, and neither map is modified.
+        assert!(migrate_legacy_id_map(&legacy_id_map, &id_map).is_err());
+        assert_eq!(Some(10), legacy_id_map.get_confirmed(&1).unwrap().map(|h| *h));
+        assert_eq!(1, id_map.iter_confirmed().count());
+    }
+}
@@ -69,7 +69,8 @@ pub enum BFTMap {
 pub enum BlockMap {
     StateRoot = DataID::BlockStateRootMap as u16,
     ReverseStateRoot = DataID::BlockReverseStateRootMap as u16,
-    ID = DataID::BlockIDMap as u16,
+    ID = DataID::BlockHeightOrderedIDMap as u16,
+    LegacyID = DataID::BlockIDMap as u16,
     ReverseID = DataID::BlockReverseIDMap as u16,
     Header = DataID::BlockHeaderMap as u16,
     Authority = DataID::BlockAuthorityMap as u16,
@@ -84,6 +85,7 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    Tree = DataID::BlockTreeMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -147,6 +149,7 @@ pub enum TransitionInputMap {
     Record = DataID::InputRecordMap as u16,
     RecordTag = DataID::InputRecordTagMap as u16,
     ExternalRecord = DataID::InputExternalRecordMap as u16,
+    SerialNumberCount = DataID::InputSerialNumberCountMap as u16,
 }
 
 /// The RocksDB map prefix for transition output entries.
@@ -164,6 +167,7 @@ pub enum TransitionOutputMap {
     RecordNonce = DataID::OutputRecordNonceMap as u16,
     ExternalRecord = DataID::OutputExternalRecordMap as u16,
     Future = DataID::OutputFutureMap as u16,
+    CommitmentCount = DataID::OutputCommitmentCountMap as u16,
 }
 
 /// The RocksDB map prefix for transaction-related entries.
@@ -225,6 +229,8 @@ enum DataID {
     // Block
     BlockStateRootMap,
     BlockReverseStateRootMap,
+    // Note: Deprecated in favor of `BlockHeightOrderedIDMap`, which stores the block heights in big-endian.
+    // Its entries are migrated into `BlockHeightOrderedIDMap` when the block storage is opened.
     BlockIDMap,
     BlockReverseIDMap,
     BlockHeaderMap,
@@ -290,6 +296,13 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    // Block (cont.)
+    BlockTreeMap,
+    BlockHeightOrderedIDMap,
+    // Input (cont.)
+    InputSerialNumberCountMap,
+    // Output (cont.)
+    OutputCommitmentCountMap,
 
     // Testing
     #[cfg(test)]
@@ -15,9 +15,15 @@
 #![allow(clippy::type_complexity)]
 
 use super::*;
-use crate::helpers::{Map, MapRead};
-
-use core::{fmt, fmt::Debug, hash::Hash, mem};
+use crate::helpers::{Map, MapRead, OrderedKey, OrderedMap};
+
+use core::{
+    fmt,
+    fmt::Debug,
+    hash::Hash,
+    mem,
+    ops::{Bound, RangeBounds},
+};
 use indexmap::IndexMap;
 use std::{borrow::Cow, ops::Deref, sync::atomic::Ordering};
 use tracing::error;
@@ -378,6 +384,17 @@ impl<
         Iter::new(self.database.prefix_iterator(&self.context))
     }
 
+    ///
+    /// Returns an iterator visiting each key-value pair in the map, starting from
+    /// the first key whose serialized bytes are greater than or equal to those of the given key.
+    ///
+    fn iter_from(&'a self, start: &K) -> Self::Iterator {
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        let lower = self.create_prefixed_key(start).unwrap();
+        let read_options = Self::bounded_read_options(lower, self.context_upper_bound());
+        Iter::new(self.database.iterator_opt(rocksdb::IteratorMode::Start, read_options))
+    }
+
     ///
     /// Returns an iterator over each key in the map.
     ///
@@ -393,6 +410,51 @@ impl<
     }
 }
 
+impl<
+    'a,
+    K: 'a + OrderedKey + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+> OrderedMap<'a, K, V> for DataMap<K, V>
+{
+    ///
+    /// Returns an iterator visiting each confirmed key-value pair with a key in the given bounds,
+    /// in ascending key order.
+    ///
+    fn range<R: RangeBounds<K>>(&'a self, bounds: R) -> Self::Iterator {
+        // Prepare the lower bound (inclusive) and the upper bound (exclusive) of the raw keys.
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        // Note: Appending a zero byte to a raw key yields the smallest raw key that is greater than it.
+        let lower = match bounds.start_bound() {
+            Bound::Included(key) => self.create_prefixed_key(key).unwrap(),
+            Bound::Excluded(key) => [self.create_prefixed_key(key).unwrap(), vec![0u8]].concat(),
+            Bound::Unbounded => self.context.clone(),
+        };
+        let upper = match bounds.end_bound() {
+            Bound::Included(key) => Some([self.create_prefixed_key(key).unwrap(), vec![0u8]].concat()),
+            Bound::Excluded(key) => Some(self.create_prefixed_key(key).unwrap()),
+            Bound::Unbounded => self.context_upper_bound(),
+        };
+        // Note: The serialized keys sort in the same order as the keys, as they are ordered keys.
+        Iter::new(self.database.iterator_opt(rocksdb::IteratorMode::Start, Self::bounded_read_options(lower, upper)))
+    }
+
+    ///
+    /// Returns the smallest confirmed key in the map, if the map is not empty.
+    ///
+    fn first_key(&'a self) -> Option<K> {
+        let read_options = Self::bounded_read_options(self.context.clone(), self.context_upper_bound());
+        Keys::new(self.database.iterator_opt(rocksdb::IteratorMode::Start, read_options)).next().map(Cow::into_owned)
+    }
+
+    ///
+    /// Returns the largest confirmed key in the map, if the map is not empty.
+    ///
+    fn last_key(&'a self) -> Option<K> {
+        let read_options = Self::bounded_read_options(self.context.clone(), self.context_upper_bound());
+        Keys::new(self.database.iterator_opt(rocksdb::IteratorMode::End, read_options)).next().map(Cow::into_owned)
+    }
+}
+
 /// An iterator over all key-value pairs in a data map.
 pub struct Iter<
     'a,
@@ -529,6 +591,31 @@ impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K
         Ok(raw_key)
     }
 
+    /// Returns the smallest raw key that is greater than every raw key in the map, if one exists.
+    fn context_upper_bound(&self) -> Option<Vec<u8>> {
+        let mut upper = self.context.clone();
+        // Increment the context as a big-endian integer, dropping the trailing bytes that overflow.
+        while let Some(byte) = upper.pop() {
+            if let Some(byte) = byte.checked_add(1) {
+                upper.push(byte);
+                return Some(upper);
+            }
+        }
+        None
+    }
+
+    /// Returns the read options for an iterator over the raw keys within the given bounds.
+    fn bounded_read_options(lower: Vec<u8>, upper: Option<Vec<u8>>) -> rocksdb::ReadOptions {
+        let mut read_options = rocksdb::ReadOptions::default();
+        // Note: The bounds are enforced by RocksDB, rather than by the prefix extractor.
+        read_options.set_total_order_seek(true);
+        read_options.set_iterate_lower_bound(lower);
+        if let Some(upper) = upper {
+            read_options.set_iterate_upper_bound(upper);
+        }
+        read_options
+    }
+
     fn get_raw<Q>(&self, key: &Q) -> Result<Option<rocksdb::DBPinnableSlice>>
     where
         K: Borrow<Q>,
@@ -554,12 +641,17 @@ mod tests {
     use crate::{
         atomic_batch_scope,
         atomic_finalize,
-        helpers::rocksdb::{internal::tests::temp_dir, MapID, TestMap},
+        helpers::{
+            rocksdb::{internal::tests::temp_dir, MapID, TestMap},
+            BigEndian,
+        },
         FinalizeMode,
     };
     use console::{
         account::{Address, FromStr},
         network::MainnetV0,
+        prelude::{TestRng, Uniform},
+        types::Field,
     };
 
     use anyhow::anyhow;
@@ -788,6 +880,35 @@ mod tests {
         crate::helpers::test_helpers::map::check_iterators_match(map);
     }
 
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_check_iter_from() {
+        // Check the iteration order of integer keys.
+        let map: DataMap<u32, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        crate::helpers::test_helpers::map::check_iter_from(map, &[1000, 1, 256, 0, 65536, 255], &[2, 257, u32::MAX]);
+
+        // Check the iteration order of field keys.
+        let rng = &mut TestRng::default();
+        let keys: Vec<Field<CurrentNetwork>> = (0..100).map(|_| Uniform::rand(rng)).collect();
+        let missing: Vec<Field<CurrentNetwork>> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        let map: DataMap<Field<CurrentNetwork>, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        crate::helpers::test_helpers::map::check_iter_from(map, &keys, &missing);
+    }
+
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_check_ordered_map() {
+        // Initialize a map.
+        let map: DataMap<BigEndian<u32>, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+
+        crate::helpers::test_helpers::map::check_ordered_map(map);
+    }
+
     #[test]
     #[serial]
     #[traced_test]
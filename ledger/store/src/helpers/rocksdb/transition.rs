@@ -134,6 +134,8 @@ pub struct InputDB<N: Network> {
     record_tag: DataMap<Field<N>, Field<N>>,
     /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
     external_record: DataMap<Field<N>, ()>,
+    /// The mapping of `()` to `number of serial numbers`.
+    serial_number_count: DataMap<u8, u64>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -148,6 +150,7 @@ impl<N: Network> InputStorage<N> for InputDB<N> {
     type RecordMap = DataMap<Field<N>, Field<N>>;
     type RecordTagMap = DataMap<Field<N>, Field<N>>;
     type ExternalRecordMap = DataMap<Field<N>, ()>;
+    type SerialNumberCountMap = DataMap<u8, u64>;
 
     /// Initializes the transition input storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -160,6 +163,7 @@ impl<N: Network> InputStorage<N> for InputDB<N> {
             record: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::Record))?,
             record_tag: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::RecordTag))?,
             external_record: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::ExternalRecord))?,
+            serial_number_count: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::SerialNumberCount))?,
             storage_mode: storage.into(),
         })
     }
@@ -204,6 +208,11 @@ impl<N: Network> InputStorage<N> for InputDB<N> {
         &self.external_record
     }
 
+    /// Returns the serial number count map.
+    fn serial_number_count_map(&self) -> &Self::SerialNumberCountMap {
+        &self.serial_number_count
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
@@ -232,6 +241,8 @@ pub struct OutputDB<N: Network> {
     external_record: DataMap<Field<N>, ()>,
     /// The mapping of `future hash` to `(optional) future`.
     future: DataMap<Field<N>, Option<Future<N>>>,
+    /// The mapping of `()` to `number of commitments`.
+    commitment_count: DataMap<u8, u64>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -247,6 +258,7 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
     type RecordNonceMap = DataMap<Group<N>, Field<N>>;
     type ExternalRecordMap = DataMap<Field<N>, ()>;
     type FutureMap = DataMap<Field<N>, Option<Future<N>>>;
+    type CommitmentCountMap = DataMap<u8, u64>;
 
     /// Initializes the transition output storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -260,6 +272,7 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
             record_nonce: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::RecordNonce))?,
             external_record: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::ExternalRecord))?,
             future: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::Future))?,
+            commitment_count: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::CommitmentCount))?,
             storage_mode: storage.into(),
         })
     }
@@ -309,6 +322,11 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
         &self.future
     }
 
+    /// Returns the commitment count map.
+    fn commitment_count_map(&self) -> &Self::CommitmentCountMap {
+        &self.commitment_count
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
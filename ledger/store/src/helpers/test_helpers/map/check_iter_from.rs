@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ensure_map_is_empty;
+use crate::helpers::{Map, MapRead};
+use console::network::prelude::{DeserializeOwned, Serialize};
+
+use core::{fmt::Debug, hash::Hash};
+
+pub fn check_iter_from<K: Copy + Debug + Eq + Hash + Serialize + DeserializeOwned + Send + Sync>(
+    map: impl for<'a> Map<'a, K, String>,
+    keys: &[K],
+    missing: &[K],
+) {
+    ensure_map_is_empty(&map);
+
+    // Ensure an empty map yields nothing.
+    for key in keys {
+        assert!(map.iter_from(key).next().is_none());
+    }
+
+    // Insert the keys, in the given order.
+    for (i, key) in keys.iter().enumerate() {
+        map.insert(*key, i.to_string()).unwrap();
+    }
+
+    // Sort the keys by their serialized bytes.
+    let mut expected = keys.iter().map(|key| (bincode::serialize(key).unwrap(), *key)).collect::<Vec<_>>();
+    expected.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    // Ensure the iterators visit the keys in the order of their serialized bytes.
+    let sorted = expected.iter().map(|(_, key)| *key).collect::<Vec<_>>();
+    assert_eq!(map.keys_confirmed().map(|key| *key).collect::<Vec<_>>(), sorted);
+    assert_eq!(map.iter_confirmed().map(|(key, _)| *key).collect::<Vec<_>>(), sorted);
+
+    // Ensure `iter_from` starts at the first key whose serialized bytes are greater than or equal to the start.
+    for start in keys.iter().chain(missing) {
+        let start_bytes = bincode::serialize(start).unwrap();
+        let expected = expected.iter().filter(|(bytes, _)| *bytes >= start_bytes).map(|(_, key)| *key);
+        assert_eq!(map.iter_from(start).map(|(key, _)| *key).collect::<Vec<_>>(), expected.collect::<Vec<_>>());
+    }
+
+    // Ensure an existing start key is visited first, with its value.
+    for (i, key) in keys.iter().enumerate() {
+        let (first_key, first_value) = map.iter_from(key).next().unwrap();
+        assert_eq!((*first_key, first_value.into_owned()), (*key, i.to_string()));
+    }
+
+    // Ensure pending operations are not visible.
+    map.start_atomic();
+    for key in missing {
+        map.insert(*key, "pending".to_string()).unwrap();
+    }
+    for start in missing {
+        assert!(map.iter_from(start).all(|(_, value)| *value != "pending"));
+    }
+    map.abort_atomic();
+
+    // Clear the map.
+    for key in keys {
+        map.remove(key).unwrap();
+    }
+    ensure_map_is_empty(&map);
+}
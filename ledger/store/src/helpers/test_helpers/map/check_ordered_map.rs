@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ensure_map_is_empty;
+use crate::helpers::{BigEndian, Map, MapRead, OrderedMap};
+
+use core::ops::Bound;
+use std::borrow::Cow;
+
+// Note: These keys are chosen so that their little-endian order differs from their numeric order.
+const KEYS: [u32; 6] = [1000, 1, 256, 0, 65536, 255];
+
+/// Returns the keys of the given entries.
+fn keys<'a>(entries: impl Iterator<Item = (Cow<'a, BigEndian<u32>>, Cow<'a, String>)>) -> Vec<u32> {
+    entries.map(|(key, _)| key.0).collect()
+}
+
+pub fn check_ordered_map(
+    map: impl for<'a> Map<'a, BigEndian<u32>, String> + for<'a> OrderedMap<'a, BigEndian<u32>, String>,
+) {
+    ensure_map_is_empty(&map);
+
+    // Ensure an empty map has no first or last key.
+    assert_eq!(map.first_key(), None);
+    assert_eq!(map.last_key(), None);
+    assert_eq!(map.range(..).count(), 0);
+
+    // Insert the keys, out of order.
+    for key in KEYS {
+        map.insert(BigEndian(key), key.to_string()).unwrap();
+    }
+
+    // Ensure the first and last keys are correct.
+    assert_eq!(map.first_key(), Some(BigEndian(0)));
+    assert_eq!(map.last_key(), Some(BigEndian(65536)));
+
+    // Ensure the full range, and the regular iterators, visit the keys in ascending order.
+    assert_eq!(keys(map.range(..)), vec![0, 1, 255, 256, 1000, 65536]);
+    assert_eq!(keys(map.iter_confirmed()), vec![0, 1, 255, 256, 1000, 65536]);
+    assert_eq!(map.keys_confirmed().map(|key| key.0).collect::<Vec<_>>(), vec![0, 1, 255, 256, 1000, 65536]);
+    // Ensure the values match their keys.
+    assert!(map.range(..).all(|(key, value)| key.0.to_string() == *value));
+
+    // Ensure an empty range yields nothing.
+    assert_eq!(map.range(BigEndian(2)..BigEndian(255)).count(), 0);
+    assert_eq!(map.range(BigEndian(256)..BigEndian(256)).count(), 0);
+    assert_eq!(map.range((Bound::Excluded(BigEndian(256)), Bound::Excluded(BigEndian(256)))).count(), 0);
+    assert_eq!(map.range((Bound::Included(BigEndian(1000)), Bound::Included(BigEndian(1)))).count(), 0);
+    // Ensure a single-element range yields exactly that element.
+    assert_eq!(keys(map.range(BigEndian(256)..=BigEndian(256))), vec![256]);
+    // Ensure the bounds are respected.
+    assert_eq!(keys(map.range(BigEndian(1)..BigEndian(1000))), vec![1, 255, 256]);
+    assert_eq!(keys(map.range(BigEndian(256)..)), vec![256, 1000, 65536]);
+    assert_eq!(keys(map.range(..=BigEndian(1))), vec![0, 1]);
+    assert_eq!(keys(map.range(BigEndian(65536)..=BigEndian(u32::MAX))), vec![65536]);
+
+    // Ensure the last key is updated after deletions.
+    map.remove(&BigEndian(65536)).unwrap();
+    assert_eq!(map.last_key(), Some(BigEndian(1000)));
+    map.remove(&BigEndian(1000)).unwrap();
+    assert_eq!(map.last_key(), Some(BigEndian(256)));
+    map.remove(&BigEndian(0)).unwrap();
+    assert_eq!(map.first_key(), Some(BigEndian(1)));
+
+    // Ensure pending operations are not visible.
+    map.start_atomic();
+    map.insert(BigEndian(70000), "70000".to_string()).unwrap();
+    assert_eq!(map.last_key(), Some(BigEndian(256)));
+    map.finish_atomic().unwrap();
+    assert_eq!(map.last_key(), Some(BigEndian(70000)));
+
+    // Clear the map.
+    for key in [1, 255, 256, 70000] {
+        map.remove(&BigEndian(key)).unwrap();
+    }
+    ensure_map_is_empty(&map);
+}
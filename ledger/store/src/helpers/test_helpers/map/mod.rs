@@ -24,13 +24,24 @@ pub use check_contains_key::*;
 mod check_insert_and_get_speculative;
 pub use check_insert_and_get_speculative::*;
 
+mod check_iter_from;
+pub use check_iter_from::*;
+
 mod check_iterators_match;
 pub use check_iterators_match::*;
 
+mod check_ordered_map;
+pub use check_ordered_map::*;
+
 mod check_remove_and_get_speculative;
 pub use check_remove_and_get_speculative::*;
 
-fn ensure_map_is_empty(map: &impl for<'a> crate::helpers::Map<'a, usize, String>) {
+use console::network::prelude::{DeserializeOwned, Serialize};
+use core::hash::Hash;
+
+fn ensure_map_is_empty<K: Copy + Eq + Hash + Serialize + DeserializeOwned + Send + Sync>(
+    map: &impl for<'a> crate::helpers::Map<'a, K, String>,
+) {
     // Sanity check.
     assert!(map.iter_pending().next().is_none());
     assert!(map.iter_confirmed().next().is_none());
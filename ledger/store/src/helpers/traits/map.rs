@@ -12,9 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use console::network::prelude::{Deserialize, Result, Serialize};
+use console::network::prelude::{Deserialize, Deserializer, Result, Serialize, Serializer};
 
-use core::{borrow::Borrow, hash::Hash};
+use core::{borrow::Borrow, hash::Hash, ops::RangeBounds};
 use std::borrow::Cow;
 
 /// A trait representing map-like storage operations with read-write capabilities.
@@ -89,6 +89,9 @@ pub trait Map<
 }
 
 /// A trait representing map-like storage operations with read-only capabilities.
+///
+/// The confirmed entries are visited in ascending order of their serialized keys.
+/// Note that this is the order of the keys themselves only for an `OrderedKey` (see `OrderedMap`).
 pub trait MapRead<
     'a,
     K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + Deserialize<'a> + Sync,
@@ -177,6 +180,12 @@ pub trait MapRead<
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator;
 
+    ///
+    /// Returns an iterator visiting each key-value pair in the map, starting from
+    /// the first key whose serialized bytes are greater than or equal to those of the given key.
+    ///
+    fn iter_from(&'a self, start: &K) -> Self::Iterator;
+
     ///
     /// Returns an iterator over each key in the map.
     ///
@@ -187,3 +196,85 @@ pub trait MapRead<
     ///
     fn values_confirmed(&'a self) -> Self::Values;
 }
+
+/// A key whose serialized bytes sort in the same order as the key itself.
+///
+/// Note: `bincode` encodes integers in little-endian, so their serialized bytes do not sort in numeric order.
+/// Integer keys that must be ordered are wrapped in `BigEndian` instead.
+pub trait OrderedKey: Copy + Ord {}
+
+/// An unsigned integer key, which is serialized in big-endian, so that its serialized bytes sort in numeric order.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BigEndian<T>(pub T);
+
+macro_rules! impl_big_endian {
+    ($($integer:ty),*) => {
+        $(
+            impl OrderedKey for BigEndian<$integer> {}
+
+            impl Serialize for BigEndian<$integer> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+                    // Note: The bytes are swapped, so that the little-endian encoding of `bincode` is big-endian.
+                    self.0.swap_bytes().serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for BigEndian<$integer> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+                    <$integer>::deserialize(deserializer).map(|integer| Self(integer.swap_bytes()))
+                }
+            }
+        )*
+    };
+}
+
+impl_big_endian!(u32, u64);
+
+/// A trait for maps whose confirmed entries can be visited in the order of their keys.
+///
+/// The entries are served directly from the underlying ordered storage, as the serialized keys
+/// sort in the same order as the keys themselves. Storage that relies on key order, such as the
+/// block heights, must require this trait, so that a map with unordered keys fails to compile.
+///
+/// ```
+/// use snarkvm_ledger_store::helpers::{memory::MemoryMap, BigEndian, OrderedMap};
+///
+/// fn latest_height<M: for<'a> OrderedMap<'a, BigEndian<u32>, u64>>(map: &M) -> Option<u32> {
+///     map.last_key().map(|height| height.0)
+/// }
+///
+/// assert_eq!(latest_height(&MemoryMap::<BigEndian<u32>, u64>::default()), None);
+/// ```
+///
+/// ```compile_fail
+/// use snarkvm_ledger_store::helpers::{memory::MemoryMap, OrderedMap};
+///
+/// fn latest_height<M: for<'a> OrderedMap<'a, u32, u64>>(map: &M) -> Option<u32> {
+///     map.last_key()
+/// }
+///
+/// // A `u32` key is serialized in little-endian, which does not sort in numeric order, and is therefore rejected.
+/// latest_height(&MemoryMap::<u32, u64>::default());
+/// ```
+pub trait OrderedMap<
+    'a,
+    K: 'a + OrderedKey + Clone + PartialEq + Eq + Hash + Serialize + Deserialize<'a> + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + Deserialize<'a> + Sync,
+>: MapRead<'a, K, V>
+{
+    ///
+    /// Returns an iterator visiting each confirmed key-value pair with a key in the given bounds,
+    /// in ascending key order.
+    ///
+    fn range<R: RangeBounds<K>>(&'a self, bounds: R) -> Self::Iterator;
+
+    ///
+    /// Returns the smallest confirmed key in the map, if the map is not empty.
+    ///
+    fn first_key(&'a self) -> Option<K>;
+
+    ///
+    /// Returns the largest confirmed key in the map, if the map is not empty.
+    ///
+    fn last_key(&'a self) -> Option<K>;
+}
@@ -31,6 +31,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Identifier, ProgramID},
+    types::Field,
 };
 use ledger_block::{Deployment, Execution, Transaction};
 use synthesizer_program::Program;
@@ -422,6 +423,28 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     ) -> Result<Option<N::TransactionID>> {
         self.storage.find_transaction_id_from_transition_id(transition_id)
     }
+
+    /// Returns the transaction ID that contains the given `serial number`.
+    pub fn find_transaction_id_from_serial_number(&self, serial_number: &Field<N>) -> Result<Option<N::TransactionID>> {
+        // Ensure the serial number exists.
+        if !self.transition_store().contains_serial_number(serial_number)? {
+            return Ok(None);
+        }
+        // Note: The input ID of a record input is its serial number.
+        let transition_id = self.transition_store().find_transition_id(serial_number)?;
+        self.find_transaction_id_from_transition_id(&transition_id)
+    }
+
+    /// Returns the transaction ID that contains the given `commitment`.
+    pub fn find_transaction_id_from_commitment(&self, commitment: &Field<N>) -> Result<Option<N::TransactionID>> {
+        // Ensure the commitment exists.
+        if !self.transition_store().contains_commitment(commitment)? {
+            return Ok(None);
+        }
+        // Note: The output ID of a record output is its commitment.
+        let transition_id = self.transition_store().find_transition_id(commitment)?;
+        self.find_transaction_id_from_transition_id(&transition_id)
+    }
 }
 
 impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
@@ -462,6 +485,16 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().programs()
     }
 
+    /// Returns an iterator over the serial numbers, for all transition inputs that are records.
+    pub fn serial_numbers(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
+        self.transition_store().serial_numbers()
+    }
+
+    /// Returns an iterator over the commitments, for all transition outputs that are records.
+    pub fn commitments(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
+        self.transition_store().commitments()
+    }
+
     /// Returns an iterator over the `((program ID, function name, edition), verifying key)`, for all deployments.
     pub fn verifying_keys(
         &self,
@@ -568,4 +601,59 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_find_transaction_id_from_serial_number_and_commitment() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        for transaction in [
+            ledger_test_helpers::sample_execution_transaction_with_fee(true, rng),
+            ledger_test_helpers::sample_execution_transaction_with_fee(false, rng),
+            ledger_test_helpers::sample_fee_private_transaction(rng),
+            ledger_test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let transaction_id = transaction.id();
+            let ids = transaction.serial_numbers().chain(transaction.commitments()).copied().collect::<Vec<_>>();
+
+            // Initialize a new transition store.
+            let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+            // Initialize a new transaction store.
+            let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+            let find = |id: &Field<_>| {
+                let from_serial_number = transaction_store.find_transaction_id_from_serial_number(id).unwrap();
+                let from_commitment = transaction_store.find_transaction_id_from_commitment(id).unwrap();
+                from_serial_number.or(from_commitment)
+            };
+
+            // Ensure the transaction ID is not found.
+            for id in &ids {
+                assert_eq!(None, find(id));
+            }
+
+            // Insert the transaction.
+            transaction_store.insert(&transaction).unwrap();
+
+            // Find the transaction ID from each serial number and commitment.
+            for serial_number in transaction.serial_numbers() {
+                let candidate = transaction_store.find_transaction_id_from_serial_number(serial_number).unwrap();
+                assert_eq!(Some(transaction_id), candidate);
+            }
+            for commitment in transaction.commitments() {
+                let candidate = transaction_store.find_transaction_id_from_commitment(commitment).unwrap();
+                assert_eq!(Some(transaction_id), candidate);
+            }
+            // Ensure a random field element is not found.
+            assert_eq!(None, find(&Field::rand(rng)));
+
+            // Remove the transaction.
+            transaction_store.remove(&transaction_id).unwrap();
+
+            // Ensure the transaction ID is not found.
+            for id in &ids {
+                assert_eq!(None, find(id));
+            }
+        }
+    }
 }
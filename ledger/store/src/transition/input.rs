@@ -14,6 +14,7 @@
 
 use crate::{
     atomic_batch_scope,
+    cow_to_copied,
     helpers::{Map, MapRead},
 };
 use console::{
@@ -27,6 +28,9 @@ use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use std::borrow::Cow;
 
+/// The key of the number of serial numbers in the serial number count map.
+const COUNT_KEY: u8 = 0;
+
 /// A trait for transition input storage.
 pub trait InputStorage<N: Network>: Clone + Send + Sync {
     /// The mapping of `transition ID` to `input IDs`.
@@ -45,6 +49,8 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
     type RecordTagMap: for<'a> Map<'a, Field<N>, Field<N>>;
     /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
     type ExternalRecordMap: for<'a> Map<'a, Field<N>, ()>;
+    /// The mapping of `()` to `number of serial numbers`.
+    type SerialNumberCountMap: for<'a> Map<'a, u8, u64>;
 
     /// Initializes the transition input storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -65,6 +71,8 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
     fn record_tag_map(&self) -> &Self::RecordTagMap;
     /// Returns the external record map.
     fn external_record_map(&self) -> &Self::ExternalRecordMap;
+    /// Returns the serial number count map.
+    fn serial_number_count_map(&self) -> &Self::SerialNumberCountMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode;
@@ -79,6 +87,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().start_atomic();
         self.record_tag_map().start_atomic();
         self.external_record_map().start_atomic();
+        self.serial_number_count_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -91,6 +100,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
             || self.record_map().is_atomic_in_progress()
             || self.record_tag_map().is_atomic_in_progress()
             || self.external_record_map().is_atomic_in_progress()
+            || self.serial_number_count_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -103,6 +113,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().atomic_checkpoint();
         self.record_tag_map().atomic_checkpoint();
         self.external_record_map().atomic_checkpoint();
+        self.serial_number_count_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -115,6 +126,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().clear_latest_checkpoint();
         self.record_tag_map().clear_latest_checkpoint();
         self.external_record_map().clear_latest_checkpoint();
+        self.serial_number_count_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -127,6 +139,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().atomic_rewind();
         self.record_tag_map().atomic_rewind();
         self.external_record_map().atomic_rewind();
+        self.serial_number_count_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -139,6 +152,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().abort_atomic();
         self.record_tag_map().abort_atomic();
         self.external_record_map().abort_atomic();
+        self.serial_number_count_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -150,11 +164,15 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().finish_atomic()?;
         self.record_map().finish_atomic()?;
         self.record_tag_map().finish_atomic()?;
-        self.external_record_map().finish_atomic()
+        self.external_record_map().finish_atomic()?;
+        self.serial_number_count_map().finish_atomic()
     }
 
     /// Stores the given `(transition ID, input)` pair into storage.
     fn insert(&self, transition_id: N::TransitionID, inputs: &[Input<N>]) -> Result<()> {
+        // Count the serial numbers.
+        let num_serial_numbers = inputs.iter().filter(|input| matches!(input, Input::Record(..))).count() as u64;
+
         atomic_batch_scope!(self, {
             // Store the input IDs.
             self.id_map().insert(transition_id, inputs.iter().map(Input::id).copied().collect())?;
@@ -178,7 +196,8 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
                 }
             }
 
-            Ok(())
+            // Update the number of serial numbers.
+            self.update_serial_number_count(|count| count.checked_add(num_serial_numbers))
         })
     }
 
@@ -195,6 +214,9 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
             // Remove the input IDs.
             self.id_map().remove(transition_id)?;
 
+            // Count the removed serial numbers.
+            let mut num_serial_numbers = 0u64;
+
             // Remove the inputs.
             for input_id in input_ids {
                 // Remove the reverse input ID.
@@ -203,6 +225,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
                 // If the input is a record, remove the record tag.
                 if let Some(tag) = self.record_map().get_confirmed(&input_id)? {
                     self.record_tag_map().remove(&tag)?;
+                    num_serial_numbers += 1;
                 }
 
                 // Remove the input.
@@ -213,10 +236,36 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
                 self.external_record_map().remove(&input_id)?;
             }
 
-            Ok(())
+            // Update the number of serial numbers.
+            self.update_serial_number_count(|count| count.checked_sub(num_serial_numbers))
         })
     }
 
+    /// Updates the number of serial numbers with the given function, which returns `None` on overflow.
+    fn update_serial_number_count(&self, update: impl FnOnce(u64) -> Option<u64>) -> Result<()> {
+        // Retrieve the current number of serial numbers, including any pending update.
+        let count = match self.serial_number_count_map().get_speculative(&COUNT_KEY)? {
+            Some(count) => cow_to_copied!(count),
+            None => 0,
+        };
+        match update(count) {
+            Some(updated) if updated != count => self.serial_number_count_map().insert(COUNT_KEY, updated),
+            Some(_) => Ok(()),
+            None => bail!("The number of serial numbers overflowed (currently {count})"),
+        }
+    }
+
+    /// Initializes the number of serial numbers from the record map, if the storage predates the count.
+    fn initialize_serial_number_count(&self) -> Result<()> {
+        if self.serial_number_count_map().get_confirmed(&COUNT_KEY)?.is_none() {
+            let num_serial_numbers = self.record_map().len_confirmed();
+            if num_serial_numbers > 0 {
+                self.serial_number_count_map().insert(COUNT_KEY, num_serial_numbers as u64)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the transition ID that contains the given `input ID`.
     fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(input_id)? {
@@ -309,6 +358,8 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize a new transition input storage.
         let storage = I::open(storage)?;
+        // Initialize the number of serial numbers, if the storage predates it.
+        storage.initialize_serial_number_count()?;
         // Return the transition input store.
         Ok(Self {
             constant: storage.constant_map().clone(),
@@ -447,6 +498,14 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.record.keys_confirmed()
     }
 
+    /// Returns the number of serial numbers, for all transition inputs that are records.
+    pub fn num_serial_numbers(&self) -> usize {
+        match self.storage.serial_number_count_map().get_confirmed(&COUNT_KEY) {
+            Ok(Some(count)) => cow_to_copied!(count) as usize,
+            _ => 0,
+        }
+    }
+
     /// Returns an iterator over the external record input IDs, for all transition inputs that are external records.
     pub fn external_input_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
         self.external_record.keys_confirmed()
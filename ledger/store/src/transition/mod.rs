@@ -533,6 +533,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.inputs.serial_numbers()
     }
 
+    /// Returns the number of serial numbers, for all transition inputs that are records.
+    pub fn num_serial_numbers(&self) -> usize {
+        self.inputs.num_serial_numbers()
+    }
+
     /// Returns an iterator over the external record input IDs, for all transition inputs that are external records.
     pub fn external_input_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
         self.inputs.external_input_ids()
@@ -565,6 +570,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.outputs.commitments()
     }
 
+    /// Returns the number of commitments, for all transition outputs that are records.
+    pub fn num_commitments(&self) -> usize {
+        self.outputs.num_commitments()
+    }
+
     /// Returns an iterator over the external record output IDs, for all transition outputs that are external records.
     pub fn external_output_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
         self.outputs.external_output_ids()
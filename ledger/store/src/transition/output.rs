@@ -14,6 +14,7 @@
 
 use crate::{
     atomic_batch_scope,
+    cow_to_copied,
     helpers::{Map, MapRead},
 };
 use console::{
@@ -27,6 +28,9 @@ use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use std::borrow::Cow;
 
+/// The key of the number of commitments in the commitment count map.
+const COUNT_KEY: u8 = 0;
+
 /// A trait for transition output storage.
 pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     /// The mapping of `transition ID` to `output IDs`.
@@ -47,6 +51,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     type ExternalRecordMap: for<'a> Map<'a, Field<N>, ()>;
     /// The mapping of `future hash` to `(optional) future`.
     type FutureMap: for<'a> Map<'a, Field<N>, Option<Future<N>>>;
+    /// The mapping of `()` to `number of commitments`.
+    type CommitmentCountMap: for<'a> Map<'a, u8, u64>;
 
     /// Initializes the transition output storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -69,6 +75,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     fn external_record_map(&self) -> &Self::ExternalRecordMap;
     /// Returns the future map.
     fn future_map(&self) -> &Self::FutureMap;
+    /// Returns the commitment count map.
+    fn commitment_count_map(&self) -> &Self::CommitmentCountMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode;
@@ -84,6 +92,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_nonce_map().start_atomic();
         self.external_record_map().start_atomic();
         self.future_map().start_atomic();
+        self.commitment_count_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -97,6 +106,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
             || self.record_nonce_map().is_atomic_in_progress()
             || self.external_record_map().is_atomic_in_progress()
             || self.future_map().is_atomic_in_progress()
+            || self.commitment_count_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -110,6 +120,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_nonce_map().atomic_checkpoint();
         self.external_record_map().atomic_checkpoint();
         self.future_map().atomic_checkpoint();
+        self.commitment_count_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -123,6 +134,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_nonce_map().clear_latest_checkpoint();
         self.external_record_map().clear_latest_checkpoint();
         self.future_map().clear_latest_checkpoint();
+        self.commitment_count_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -136,6 +148,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_nonce_map().atomic_rewind();
         self.external_record_map().atomic_rewind();
         self.future_map().atomic_rewind();
+        self.commitment_count_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -149,6 +162,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_nonce_map().abort_atomic();
         self.external_record_map().abort_atomic();
         self.future_map().abort_atomic();
+        self.commitment_count_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -161,11 +175,15 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().finish_atomic()?;
         self.record_nonce_map().finish_atomic()?;
         self.external_record_map().finish_atomic()?;
-        self.future_map().finish_atomic()
+        self.future_map().finish_atomic()?;
+        self.commitment_count_map().finish_atomic()
     }
 
     /// Stores the given `(transition ID, output)` pair into storage.
     fn insert(&self, transition_id: N::TransitionID, outputs: &[Output<N>]) -> Result<()> {
+        // Count the commitments.
+        let num_commitments = outputs.iter().filter(|output| matches!(output, Output::Record(..))).count() as u64;
+
         atomic_batch_scope!(self, {
             // Store the output IDs.
             self.id_map().insert(transition_id, outputs.iter().map(Output::id).copied().collect())?;
@@ -192,7 +210,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                 }
             }
 
-            Ok(())
+            // Update the number of commitments.
+            self.update_commitment_count(|count| count.checked_add(num_commitments))
         })
     }
 
@@ -209,6 +228,9 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
             // Remove the output IDs.
             self.id_map().remove(transition_id)?;
 
+            // Count the removed commitments.
+            let mut num_commitments = 0u64;
+
             // Remove the outputs.
             for output_id in output_ids {
                 // Remove the reverse output ID.
@@ -219,6 +241,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                     if let Some(record) = &record.1 {
                         self.record_nonce_map().remove(record.nonce())?;
                     }
+                    num_commitments += 1;
                 }
 
                 // Remove the output.
@@ -230,10 +253,36 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                 self.future_map().remove(&output_id)?;
             }
 
-            Ok(())
+            // Update the number of commitments.
+            self.update_commitment_count(|count| count.checked_sub(num_commitments))
         })
     }
 
+    /// Updates the number of commitments with the given function, which returns `None` on overflow.
+    fn update_commitment_count(&self, update: impl FnOnce(u64) -> Option<u64>) -> Result<()> {
+        // Retrieve the current number of commitments, including any pending update.
+        let count = match self.commitment_count_map().get_speculative(&COUNT_KEY)? {
+            Some(count) => cow_to_copied!(count),
+            None => 0,
+        };
+        match update(count) {
+            Some(updated) if updated != count => self.commitment_count_map().insert(COUNT_KEY, updated),
+            Some(_) => Ok(()),
+            None => bail!("The number of commitments overflowed (currently {count})"),
+        }
+    }
+
+    /// Initializes the number of commitments from the record map, if the storage predates the count.
+    fn initialize_commitment_count(&self) -> Result<()> {
+        if self.commitment_count_map().get_confirmed(&COUNT_KEY)?.is_none() {
+            let num_commitments = self.record_map().len_confirmed();
+            if num_commitments > 0 {
+                self.commitment_count_map().insert(COUNT_KEY, num_commitments as u64)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the transition ID that contains the given `output ID`.
     fn find_transition_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(output_id)? {
@@ -330,6 +379,8 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize a new transition output storage.
         let storage = O::open(storage)?;
+        // Initialize the number of commitments, if the storage predates it.
+        storage.initialize_commitment_count()?;
         // Return the transition output store.
         Ok(Self {
             constant: storage.constant_map().clone(),
@@ -491,6 +542,14 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.record.keys_confirmed()
     }
 
+    /// Returns the number of commitments, for all transition outputs that are records.
+    pub fn num_commitments(&self) -> usize {
+        match self.storage.commitment_count_map().get_confirmed(&COUNT_KEY) {
+            Ok(Some(count)) => cow_to_copied!(count) as usize,
+            _ => 0,
+        }
+    }
+
     /// Returns an iterator over the external record output IDs, for all transition outputs that are external records.
     pub fn external_output_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
         self.external_record.keys_confirmed()
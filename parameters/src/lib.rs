@@ -30,6 +30,9 @@ pub mod macros;
 pub mod errors;
 pub use errors::*;
 
+pub mod manifest;
+pub use manifest::*;
+
 pub mod mainnet;
 
 pub mod prelude {
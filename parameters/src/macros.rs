@@ -167,7 +167,7 @@ macro_rules! impl_load_bytes_logic_local {
 }
 
 macro_rules! impl_load_bytes_logic_remote {
-    ($remote_url: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr) => {
+    ($remote_url: expr, $local_dir: expr, $filename: expr, $expected_checksum: expr, $expected_size: expr) => {
         // Compose the correct file path for the parameter file.
         let mut file_path = aleo_std::aleo_dir();
         file_path.push($local_dir);
@@ -267,6 +267,15 @@ macro_rules! impl_local {
 
                 impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum);
             }
+
+            /// Returns the parameter bytes, loading and verifying them on first use.
+            ///
+            /// Note: The bytes are retained for the lifetime of the process. Parameters that are
+            /// deserialized once and then dropped, such as the credits keys, use `load_bytes` instead.
+            pub fn bytes() -> Result<&'static [u8], $crate::errors::ParameterError> {
+                static BYTES: once_cell::sync::OnceCell<Vec<u8>> = once_cell::sync::OnceCell::new();
+                BYTES.get_or_try_init(Self::load_bytes).map(Vec::as_slice)
+            }
         }
 
         paste::item! {
@@ -297,6 +306,15 @@ macro_rules! impl_local {
 
                 impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum);
             }
+
+            /// Returns the parameter bytes, loading and verifying them on first use.
+            ///
+            /// Note: The bytes are retained for the lifetime of the process. Parameters that are
+            /// deserialized once and then dropped, such as the credits keys, use `load_bytes` instead.
+            pub fn bytes() -> Result<&'static [u8], $crate::errors::ParameterError> {
+                static BYTES: once_cell::sync::OnceCell<Vec<u8>> = once_cell::sync::OnceCell::new();
+                BYTES.get_or_try_init(Self::load_bytes).map(Vec::as_slice)
+            }
         }
 
         paste::item! {
@@ -320,27 +338,34 @@ macro_rules! impl_remote {
             impl_store_and_remote_fetch!();
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let entry = Self::manifest_entry();
+                impl_load_bytes_logic_remote!($remote_url, $local_dir, &entry.filename, entry.checksum, entry.size);
+            }
+
+            /// Returns the parameter bytes, loading and verifying them on first use.
+            ///
+            /// Note: The bytes are retained for the lifetime of the process. Parameters that are
+            /// deserialized once and then dropped, such as the credits keys, use `load_bytes` instead.
+            pub fn bytes() -> Result<&'static [u8], $crate::errors::ParameterError> {
+                static BYTES: once_cell::sync::OnceCell<Vec<u8>> = once_cell::sync::OnceCell::new();
+                BYTES.get_or_try_init(Self::load_bytes).map(Vec::as_slice)
+            }
+
+            /// Returns the manifest entry of the parameter, as stored on disk.
+            pub fn manifest_entry() -> $crate::ManifestEntry {
                 let metadata: serde_json::Value =
                     serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
-                let expected_checksum: String =
+                let checksum: String =
                     metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
-                let expected_size: usize =
+                let size: usize =
                     metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
 
                 // Construct the versioned filename.
-                let filename = match expected_checksum.get(0..7) {
+                let filename = match checksum.get(0..7) {
                     Some(sum) => format!("{}.{}.{}", $fname, "usrs", sum),
                     _ => format!("{}.{}", $fname, "usrs"),
                 };
-
-                impl_load_bytes_logic_remote!(
-                    $remote_url,
-                    $local_dir,
-                    &filename,
-                    metadata,
-                    expected_checksum,
-                    expected_size
-                );
+                $crate::ManifestEntry::new(concat!($fname, ".", "usrs"), filename, size, checksum)
             }
         }
         paste::item! {
@@ -360,27 +385,34 @@ macro_rules! impl_remote {
             impl_store_and_remote_fetch!();
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let entry = Self::manifest_entry();
+                impl_load_bytes_logic_remote!($remote_url, $local_dir, &entry.filename, entry.checksum, entry.size);
+            }
+
+            /// Returns the parameter bytes, loading and verifying them on first use.
+            ///
+            /// Note: The bytes are retained for the lifetime of the process. Parameters that are
+            /// deserialized once and then dropped, such as the credits keys, use `load_bytes` instead.
+            pub fn bytes() -> Result<&'static [u8], $crate::errors::ParameterError> {
+                static BYTES: once_cell::sync::OnceCell<Vec<u8>> = once_cell::sync::OnceCell::new();
+                BYTES.get_or_try_init(Self::load_bytes).map(Vec::as_slice)
+            }
+
+            /// Returns the manifest entry of the parameter, as stored on disk.
+            pub fn manifest_entry() -> $crate::ManifestEntry {
                 let metadata: serde_json::Value =
                     serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
-                let expected_checksum: String =
+                let checksum: String =
                     metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
-                let expected_size: usize =
+                let size: usize =
                     metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
 
                 // Construct the versioned filename.
-                let filename = match expected_checksum.get(0..7) {
+                let filename = match checksum.get(0..7) {
                     Some(sum) => format!("{}.{}.{}", $fname, $ftype, sum),
                     _ => format!("{}.{}", $fname, $ftype),
                 };
-
-                impl_load_bytes_logic_remote!(
-                    $remote_url,
-                    $local_dir,
-                    &filename,
-                    metadata,
-                    expected_checksum,
-                    expected_size
-                );
+                $crate::ManifestEntry::new(concat!($fname, ".", $ftype), filename, size, checksum)
             }
         }
 
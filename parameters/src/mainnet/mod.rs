@@ -113,6 +113,55 @@ impl_local!(FeePrivateVerifier, "resources/", "fee_private", "verifier");
 impl_remote!(FeePublicProver, REMOTE_URL, "resources/", "fee_public", "prover");
 impl_local!(FeePublicVerifier, "resources/", "fee_public", "verifier");
 
+/// Returns the directory in which the remote parameters are stored.
+pub fn local_dir() -> std::path::PathBuf {
+    aleo_std::aleo_dir().join("resources/")
+}
+
+lazy_static! {
+    /// The parameters, stored in the local directory, that this version of snarkVM expects.
+    pub static ref MANIFEST: crate::Manifest = crate::Manifest::new(vec![
+        Degree17::manifest_entry(),
+        Degree18::manifest_entry(),
+        Degree19::manifest_entry(),
+        Degree20::manifest_entry(),
+        Degree21::manifest_entry(),
+        Degree22::manifest_entry(),
+        Degree23::manifest_entry(),
+        Degree24::manifest_entry(),
+        Degree25::manifest_entry(),
+        Degree26::manifest_entry(),
+        Degree27::manifest_entry(),
+        Degree28::manifest_entry(),
+        ShiftedDegree17::manifest_entry(),
+        ShiftedDegree18::manifest_entry(),
+        ShiftedDegree19::manifest_entry(),
+        ShiftedDegree20::manifest_entry(),
+        ShiftedDegree21::manifest_entry(),
+        ShiftedDegree22::manifest_entry(),
+        ShiftedDegree23::manifest_entry(),
+        ShiftedDegree24::manifest_entry(),
+        ShiftedDegree25::manifest_entry(),
+        ShiftedDegree26::manifest_entry(),
+        ShiftedDegree27::manifest_entry(),
+        BondPublicProver::manifest_entry(),
+        UnbondPublicProver::manifest_entry(),
+        UnbondDelegatorAsValidatorProver::manifest_entry(),
+        ClaimUnbondPublicProver::manifest_entry(),
+        SetValidatorStateProver::manifest_entry(),
+        TransferPrivateProver::manifest_entry(),
+        TransferPublicProver::manifest_entry(),
+        TransferPublicAsSignerProver::manifest_entry(),
+        TransferPrivateToPublicProver::manifest_entry(),
+        TransferPublicToPrivateProver::manifest_entry(),
+        JoinProver::manifest_entry(),
+        SplitProver::manifest_entry(),
+        FeePrivateProver::manifest_entry(),
+        FeePublicProver::manifest_entry(),
+        InclusionProver::manifest_entry(),
+    ]);
+}
+
 #[macro_export]
 macro_rules! insert_credit_keys {
     ($map:ident, $type:ident<$network:ident>, $variant:ident) => {{
@@ -140,7 +189,14 @@ macro_rules! insert_credit_keys {
 macro_rules! insert_key {
     ($map:ident, $string:tt, $type:ident<$network:ident>, ($name:tt, $circuit_key:expr)) => {{
         // Load the circuit key bytes.
-        let key_bytes: Vec<u8> = $circuit_key.expect(&format!("Failed to load {} bytes", $string));
+        let key_bytes: Vec<u8> = $circuit_key.unwrap_or_else(|error| {
+            // Check every parameter, so that the error lists all of the missing and corrupt parameters at once.
+            let report = match $crate::mainnet::MANIFEST.verify_all($crate::mainnet::local_dir()) {
+                Ok(report) => report.to_string(),
+                Err(error) => format!("Failed to check the parameters: {error}"),
+            };
+            panic!("Failed to load {} bytes: {error}\n{report}", $string)
+        });
         // Recover the circuit key.
         let key = $type::<$network>::from_bytes_le(&key_bytes[1..]).expect(&format!("Failed to recover {}", $string));
         // Insert the circuit key.
@@ -156,10 +212,10 @@ impl_local!(InclusionVerifier, "resources/", "inclusion", "verifier");
 pub const NETWORK_INCLUSION_FUNCTION_NAME: &str = "inclusion";
 
 lazy_static! {
-    pub static ref INCLUSION_PROVING_KEY: Vec<u8> =
-        InclusionProver::load_bytes().expect("Failed to load inclusion proving key");
-    pub static ref INCLUSION_VERIFYING_KEY: Vec<u8> =
-        InclusionVerifier::load_bytes().expect("Failed to load inclusion verifying key");
+    pub static ref INCLUSION_PROVING_KEY: &'static [u8] =
+        InclusionProver::bytes().expect("Failed to load inclusion proving key");
+    pub static ref INCLUSION_VERIFYING_KEY: &'static [u8] =
+        InclusionVerifier::bytes().expect("Failed to load inclusion verifying key");
 }
 
 #[cfg(test)]
@@ -168,6 +224,31 @@ mod tests {
     use wasm_bindgen_test::*;
     wasm_bindgen_test_configure!(run_in_browser);
 
+    #[test]
+    fn test_manifest() {
+        // Ensure the entries are derived from the parameter metadata.
+        let entry = JoinProver::manifest_entry();
+        assert_eq!(entry.name, "join.prover");
+        assert_eq!(entry.filename, "join.prover.b609cd8");
+        assert_eq!(entry.size, 74722164);
+        assert_eq!(entry.checksum, "b609cd8cd51eb0870e87b31db05b45768a77daed7a2b0b3c8f5a570e12044112");
+
+        // Ensure every entry in the manifest is distinct.
+        let filenames =
+            MANIFEST.entries().iter().map(|entry| &entry.filename).collect::<std::collections::HashSet<_>>();
+        assert_eq!(filenames.len(), MANIFEST.entries().len());
+        assert!(MANIFEST.entries().contains(&InclusionProver::manifest_entry()));
+    }
+
+    #[test]
+    fn test_bytes_are_cached() {
+        // Ensure the cached bytes match the loaded bytes.
+        let bytes = Gamma::bytes().unwrap();
+        assert_eq!(bytes, Gamma::load_bytes().unwrap().as_slice());
+        // Ensure the bytes are only loaded once.
+        assert!(std::ptr::eq(bytes, Gamma::bytes().unwrap()));
+    }
+
     #[wasm_bindgen_test]
     fn test_load_bytes() {
         Degree16::load_bytes().expect("Failed to load degree 16");
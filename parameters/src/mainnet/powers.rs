@@ -50,11 +50,11 @@ const NUM_POWERS_28: usize = 1 << 28;
 const MAX_NUM_POWERS: usize = NUM_POWERS_28;
 
 lazy_static::lazy_static! {
-    static ref POWERS_OF_BETA_G_15: Vec<u8> = Degree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
-    static ref SHIFTED_POWERS_OF_BETA_G_15: Vec<u8> = ShiftedDegree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
-    static ref POWERS_OF_BETA_GAMMA_G: Vec<u8> = Gamma::load_bytes().expect("Failed to load powers of beta wrt gamma * G in universal SRS");
-    static ref NEG_POWERS_OF_BETA_H: Vec<u8> = NegBeta::load_bytes().expect("Failed to load negative powers of beta in universal SRS");
-    static ref BETA_H: Vec<u8> = BetaH::load_bytes().expect("Failed to load negative powers of beta in universal SRS");
+    static ref POWERS_OF_BETA_G_15: &'static [u8] = Degree15::bytes().expect("Failed to load powers of beta in universal SRS");
+    static ref SHIFTED_POWERS_OF_BETA_G_15: &'static [u8] = ShiftedDegree15::bytes().expect("Failed to load powers of beta in universal SRS");
+    static ref POWERS_OF_BETA_GAMMA_G: &'static [u8] = Gamma::bytes().expect("Failed to load powers of beta wrt gamma * G in universal SRS");
+    static ref NEG_POWERS_OF_BETA_H: &'static [u8] = NegBeta::bytes().expect("Failed to load negative powers of beta in universal SRS");
+    static ref BETA_H: &'static [u8] = BetaH::bytes().expect("Failed to load negative powers of beta in universal SRS");
 }
 
 /// A vector of powers of beta G.
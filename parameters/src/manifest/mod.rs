@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+
+use sha2::{Digest, Sha256};
+use std::{
+    fmt,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// The number of bytes that are read at a time, when hashing an artifact.
+const CHUNK_SIZE_IN_BYTES: usize = 1 << 20;
+
+/// The expected length and checksum of a parameter artifact stored on disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEntry {
+    /// The name of the artifact (e.g. `join.prover`).
+    pub name: String,
+    /// The filename of the artifact in the parameters directory.
+    pub filename: String,
+    /// The expected length of the artifact, in bytes.
+    pub size: usize,
+    /// The expected SHA-256 checksum of the artifact, as a hex string.
+    pub checksum: String,
+}
+
+impl ManifestEntry {
+    /// Initializes a new manifest entry.
+    pub fn new(name: impl Into<String>, filename: impl Into<String>, size: usize, checksum: impl Into<String>) -> Self {
+        Self { name: name.into(), filename: filename.into(), size, checksum: checksum.into() }
+    }
+
+    /// Checks the artifact in the given directory, returning `Ok(None)` if it is missing.
+    /// The artifact is hashed as it is read, so that it is never held in memory in full.
+    fn verify(&self, dir: &Path) -> Result<Option<Result<(), ParameterError>>, ParameterError> {
+        let path = dir.join(&self.filename);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let mut file = std::fs::File::open(path)?;
+
+        // Ensure the size matches.
+        let size = usize::try_from(file.metadata()?.len()).unwrap_or(usize::MAX);
+        if self.size != size {
+            return Ok(Some(Err(ParameterError::SizeMismatch(self.size, size))));
+        }
+        // Hash the artifact, one chunk at a time.
+        let mut hasher = Sha256::new();
+        let mut chunk = vec![0u8; CHUNK_SIZE_IN_BYTES];
+        loop {
+            match file.read(&mut chunk)? {
+                0 => break,
+                num_bytes => hasher.update(&chunk[..num_bytes]),
+            }
+        }
+        // Ensure the checksum matches.
+        let candidate_checksum = hex::encode(hasher.finalize());
+        if self.checksum != candidate_checksum {
+            return Ok(Some(Err(ParameterError::ChecksumMismatch(self.checksum.clone(), candidate_checksum))));
+        }
+        Ok(Some(Ok(())))
+    }
+}
+
+/// The set of parameter artifacts, stored on disk, that this version of snarkVM expects.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    entries: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    /// Initializes a new manifest from the given entries.
+    pub fn new(entries: Vec<ManifestEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the entries in the manifest.
+    pub fn entries(&self) -> &[ManifestEntry] {
+        &self.entries
+    }
+
+    /// Checks the presence and integrity of every artifact in the given directory,
+    /// without stopping at the first failure.
+    pub fn verify_all(&self, dir: impl AsRef<Path>) -> Result<ManifestReport, ParameterError> {
+        let dir = dir.as_ref();
+        let mut report = ManifestReport { dir: dir.to_path_buf(), ..Default::default() };
+        for entry in &self.entries {
+            match entry.verify(dir)? {
+                Some(Ok(())) => report.verified.push(entry.name.clone()),
+                Some(Err(error)) => report.corrupt.push((entry.name.clone(), error)),
+                None => report.missing.push(entry.name.clone()),
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// The result of checking a parameters directory against a manifest.
+#[derive(Debug, Default)]
+pub struct ManifestReport {
+    /// The directory that was checked.
+    pub dir: PathBuf,
+    /// The names of the artifacts that are present and intact.
+    pub verified: Vec<String>,
+    /// The names of the artifacts that are missing.
+    pub missing: Vec<String>,
+    /// The names of the artifacts that are present but corrupt, along with the reason.
+    pub corrupt: Vec<(String, ParameterError)>,
+}
+
+impl ManifestReport {
+    /// Returns `true` if every artifact in the manifest is present and intact.
+    pub fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.corrupt.is_empty()
+    }
+
+    /// Returns an error listing every missing and corrupt artifact, if there are any.
+    pub fn ensure_ok(&self) -> Result<(), ParameterError> {
+        match self.is_ok() {
+            true => Ok(()),
+            false => Err(ParameterError::Message(self.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for ManifestReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_ok() {
+            return write!(f, "All {} parameters in {:?} are intact", self.verified.len(), self.dir);
+        }
+        let (num_missing, num_corrupt) = (self.missing.len(), self.corrupt.len());
+        write!(f, "Found {num_missing} missing and {num_corrupt} corrupt parameters in {:?}", self.dir)?;
+        for name in &self.missing {
+            write!(f, "\n  - {name}: missing")?;
+        }
+        for (name, error) in &self.corrupt {
+            write!(f, "\n  - {name}: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    /// Returns a new, empty directory for the test.
+    fn sample_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("snarkvm-parameters-manifest-{}", rand::thread_rng().gen::<u64>()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Writes the given artifacts to the directory, and returns a manifest for them.
+    fn sample_manifest(dir: &Path, artifacts: &[(&str, &[u8])]) -> Manifest {
+        let entries = artifacts
+            .iter()
+            .map(|(name, bytes)| {
+                let filename = format!("{name}.{}", &checksum!(*bytes)[0..7]);
+                std::fs::write(dir.join(&filename), bytes).unwrap();
+                ManifestEntry::new(*name, filename, bytes.len(), checksum!(*bytes))
+            })
+            .collect();
+        Manifest::new(entries)
+    }
+
+    #[test]
+    fn test_verify_all() {
+        let dir = sample_dir();
+        let manifest = sample_manifest(&dir, &[("a.prover", b"first artifact"), ("b.prover", b"second artifact")]);
+
+        let report = manifest.verify_all(&dir).unwrap();
+        assert!(report.is_ok());
+        assert!(report.ensure_ok().is_ok());
+        assert_eq!(report.verified, ["a.prover", "b.prover"]);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_verify_all_spans_several_chunks() {
+        let dir = sample_dir();
+        // Sample an artifact that is hashed in several chunks, with a partial final chunk.
+        let artifact = (0..3 * CHUNK_SIZE_IN_BYTES + 1).map(|i| i as u8).collect::<Vec<_>>();
+        let manifest = sample_manifest(&dir, &[("a.prover", artifact.as_slice())]);
+        assert!(manifest.verify_all(&dir).unwrap().is_ok());
+
+        // Corrupt the last byte of the artifact.
+        let mut corrupted = artifact;
+        *corrupted.last_mut().unwrap() ^= 1;
+        std::fs::write(dir.join(&manifest.entries()[0].filename), corrupted).unwrap();
+        let report = manifest.verify_all(&dir).unwrap();
+        assert!(matches!(report.corrupt[0].1, ParameterError::ChecksumMismatch(..)));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_verify_all_missing() {
+        let dir = sample_dir();
+        let manifest = sample_manifest(&dir, &[("a.prover", b"first artifact"), ("b.prover", b"second artifact")]);
+        std::fs::remove_file(dir.join(&manifest.entries()[1].filename)).unwrap();
+
+        let report = manifest.verify_all(&dir).unwrap();
+        assert!(!report.is_ok());
+        assert_eq!(report.verified, ["a.prover"]);
+        assert_eq!(report.missing, ["b.prover"]);
+        assert!(report.corrupt.is_empty());
+        assert!(report.ensure_ok().unwrap_err().to_string().contains("b.prover: missing"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_verify_all_corrupt() {
+        let dir = sample_dir();
+        let manifest = sample_manifest(&dir, &[
+            ("a.prover", b"first artifact"),
+            ("b.prover", b"second artifact"),
+            ("c.prover", b"third artifact"),
+        ]);
+        // Corrupt one artifact in place, and truncate another.
+        std::fs::write(dir.join(&manifest.entries()[0].filename), b"first artifacT").unwrap();
+        std::fs::write(dir.join(&manifest.entries()[2].filename), b"third").unwrap();
+
+        let report = manifest.verify_all(&dir).unwrap();
+        assert!(!report.is_ok());
+        assert_eq!(report.verified, ["b.prover"]);
+        assert!(report.missing.is_empty());
+        assert_eq!(report.corrupt.len(), 2);
+        assert_eq!(report.corrupt[0].0, "a.prover");
+        assert!(matches!(report.corrupt[0].1, ParameterError::ChecksumMismatch(..)));
+        assert_eq!(report.corrupt[1].0, "c.prover");
+        assert!(matches!(report.corrupt[1].1, ParameterError::SizeMismatch(14, 5)));
+
+        // Ensure the error lists every corrupt artifact.
+        let error = report.ensure_ok().unwrap_err().to_string();
+        assert!(error.contains("a.prover") && error.contains("c.prover"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
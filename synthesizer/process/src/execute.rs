@@ -56,6 +56,41 @@ impl<N: Network> Process<N> {
         finish!(timer);
         Ok((response, trace))
     }
+
+    /// Synthesizes the transition circuits of the given authorization, and checks that they are satisfied,
+    /// without evaluating or proving the execution.
+    ///
+    /// If a circuit is not satisfied or halts, the returned error downcasts to `SynthesisDiagnostics`,
+    /// which describes the first unsatisfied constraint or the halt, and the instruction that introduced it.
+    #[inline]
+    pub fn check_execution<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("Process::check_execution");
+
+        // Retrieve the main request (without popping it).
+        let request = authorization.peek_next()?;
+
+        // Initialize the diagnostics.
+        let diagnostics = Arc::new(RwLock::new(None));
+        // Initialize the call stack.
+        let call_stack = CallStack::check_execution(authorization, diagnostics.clone())?;
+        lap!(timer, "Initialize call stack");
+
+        // Retrieve the stack.
+        let stack = self.get_stack(request.program_id())?;
+        // Synthesize the circuit.
+        let result = stack.execute_function::<A, R>(call_stack, None, None, rng);
+        finish!(timer, "Synthesize the function");
+
+        // If a circuit is not satisfied, return the diagnostics.
+        if let Some(diagnostics) = diagnostics.write().take() {
+            return Err(diagnostics.into());
+        }
+        result.map(|_| ())
+    }
 }
 
 #[cfg(test)]
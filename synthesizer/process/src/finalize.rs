@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use super::*;
-use console::program::{FinalizeType, Future, Register};
+use console::{
+    network::HaltError,
+    program::{FinalizeType, Future, Register},
+};
 use synthesizer_program::{Await, FinalizeRegistersState, Operand};
 use utilities::try_vm_runtime;
 
@@ -235,7 +238,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         // If the evaluation fails, bail and return the error.
                         Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(payload) => {
+                            return Err(HaltError::from_panic(payload)
+                                .context(format!("'finalize' failed to evaluate command ({command})")));
+                        }
                     }
                 }
                 Command::BranchNeq(branch_neq) => {
@@ -247,7 +253,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         // If the evaluation fails, bail and return the error.
                         Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(payload) => {
+                            return Err(HaltError::from_panic(payload)
+                                .context(format!("'finalize' failed to evaluate command ({command})")));
+                        }
                     }
                 }
                 Command::Await(await_) => {
@@ -280,7 +289,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                             // If the evaluation fails, bail and return the error.
                             Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
                             // If the evaluation fails, bail and return the error.
-                            Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                            Err(payload) => {
+                                return Err(HaltError::from_panic(payload)
+                                    .context(format!("'finalize' failed to evaluate command ({command})")));
+                            }
                         };
 
                     // Increment the call counter.
@@ -310,7 +322,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         // If the evaluation fails, bail and return the error.
                         Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(payload) => {
+                            return Err(HaltError::from_panic(payload)
+                                .context(format!("'finalize' failed to evaluate command ({command})")));
+                        }
                     }
                     counter += 1;
                 }
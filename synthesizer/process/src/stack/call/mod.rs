@@ -374,6 +374,22 @@ impl<N: Network> CallTrait<N> for Call<N> {
                         // Return the request and response.
                         (request, response)
                     }
+                    // If the circuit is in check execution mode, then execute the instructions without evaluating them.
+                    CallStack::CheckExecution(authorization, ..) => {
+                        // Retrieve the next request (without popping it).
+                        let request = authorization.peek_next()?;
+                        // Ensure the inputs match the original inputs.
+                        request.inputs().iter().zip_eq(&inputs).try_for_each(|(request_input, input)| {
+                            ensure!(request_input == input, "Inputs do not match in a 'call' instruction.");
+                            Ok(())
+                        })?;
+
+                        // Execute the request.
+                        let response =
+                            substack.execute_function::<A, R>(registers.call_stack(), console_caller, root_tvk, rng)?;
+                        // Return the request and response.
+                        (request, response)
+                    }
                 }
             };
             lap!(timer, "Computed the request and response");
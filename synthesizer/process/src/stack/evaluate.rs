@@ -56,8 +56,13 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         // Evaluate the instructions.
         for instruction in closure.instructions() {
             // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+            match try_vm_runtime!(|| instruction.evaluate(self, &mut registers)) {
+                Ok(Ok(())) => {}
+                Ok(Err(error)) => bail!("Failed to evaluate instruction ({instruction}): {error}"),
+                Err(payload) => {
+                    let context = format!("Failed to evaluate instruction ({instruction})");
+                    return Err(HaltError::from_panic(payload).context(context));
+                }
             }
         }
         lap!(timer, "Evaluate the instructions");
@@ -177,15 +182,20 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         // Note: We handle the `call` instruction separately, as it requires special handling.
         for instruction in function.instructions() {
             // Evaluate the instruction.
-            let result = match instruction {
+            let result = try_vm_runtime!(|| match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
                 // Otherwise, evaluate the instruction normally.
                 _ => instruction.evaluate(self, &mut registers),
-            };
+            });
             // If the evaluation fails, bail and return the error.
-            if let Err(error) = result {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(error)) => bail!("Failed to evaluate instruction ({instruction}): {error}"),
+                Err(payload) => {
+                    let context = format!("Failed to evaluate instruction ({instruction})");
+                    return Err(HaltError::from_panic(payload).context(context));
+                }
             }
         }
         lap!(timer, "Evaluate the instructions");
@@ -274,30 +274,66 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
 
+        // If the circuit is in `CheckExecution` mode, retrieve the diagnostics.
+        let check_execution_diagnostics = match registers.call_stack() {
+            CallStack::CheckExecution(_, diagnostics) => Some(diagnostics),
+            _ => None,
+        };
+
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
-                let result = match instruction {
+                let result = try_vm_runtime!(|| match instruction {
                     // If the instruction is a `call` instruction, we need to handle it separately.
                     Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
                     // Otherwise, evaluate the instruction normally.
                     _ => instruction.evaluate(self, &mut registers),
-                };
+                });
                 // If the evaluation fails, bail and return the error.
-                if let Err(error) = result {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
+                match result {
+                    Ok(Ok(())) => {}
+                    Ok(Err(error)) => bail!("Failed to evaluate instruction ({instruction}): {error}"),
+                    Err(payload) => {
+                        let context = format!("Failed to evaluate instruction ({instruction})");
+                        return Err(HaltError::from_panic(payload).context(context));
+                    }
                 }
             }
 
             // Execute the instruction.
-            let result = match instruction {
+            let mut execute = || match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
             };
+            // If the circuit is in `CheckExecution` mode, then scope the constraints of each instruction,
+            // so that an unsatisfied constraint can be traced back to its instruction.
+            let result = match &check_execution_diagnostics {
+                Some(diagnostics) => {
+                    let scope = format!("{INSTRUCTION_SCOPE_PREFIX}{index}");
+                    match try_vm_runtime!(|| A::scope(scope.clone(), execute)) {
+                        Ok(result) => result,
+                        // If the instruction halts, record the halt, as the circuit cannot be synthesized further.
+                        Err(payload) => {
+                            let error = HaltError::from_panic(payload);
+                            let diagnostic = SynthesisDiagnostics {
+                                program_id: *self.program_id(),
+                                function_name: *function.name(),
+                                constraint_index: A::num_constraints(),
+                                scope,
+                                instruction: Some((index, instruction.to_string())),
+                                halt_code: Some(error.code()),
+                            };
+                            diagnostics.write().get_or_insert(diagnostic);
+                            return Err(error.context(format!("Failed to execute instruction ({instruction})")));
+                        }
+                    }
+                }
+                None => execute(),
+            };
             // If the execution fails, bail and return the error.
             if let Err(error) = result {
                 bail!("Failed to execute instruction ({instruction}): {error}");
@@ -414,6 +450,27 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             );
         }
 
+        // If the circuit is in `CheckExecution` mode, then record the first unsatisfied constraint, if any.
+        if let CallStack::CheckExecution(_, ref diagnostics) = registers.call_stack() {
+            if let Some((constraint_index, scope)) = A::first_unsatisfied_constraint() {
+                // Retrieve the instruction that introduced the constraint, if any.
+                let instruction = SynthesisDiagnostics::<N>::instruction_index(&scope).and_then(|index| {
+                    function.instructions().get(index).map(|instruction| (index, instruction.to_string()))
+                });
+                let diagnostic = SynthesisDiagnostics {
+                    program_id: *self.program_id(),
+                    function_name: *function.name(),
+                    constraint_index,
+                    scope,
+                    instruction,
+                    halt_code: None,
+                };
+                // Only keep the first failure, as the callers of a failed transition are not synthesized further.
+                let diagnostic = diagnostics.write().get_or_insert(diagnostic).clone();
+                bail!("{diagnostic}")
+            }
+        }
+
         // Eject the circuit assignment and reset the circuit.
         let assignment = A::eject_assignment_and_reset();
 
@@ -36,10 +36,18 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{cost_in_microcredits, traits::*, CallMetrics, Process, Trace};
+use crate::{
+    cost_in_microcredits,
+    traits::*,
+    CallMetrics,
+    Process,
+    SynthesisDiagnostics,
+    Trace,
+    INSTRUCTION_SCOPE_PREFIX,
+};
 use console::{
     account::{Address, PrivateKey},
-    network::prelude::*,
+    network::{prelude::*, HaltError},
     program::{
         Argument,
         Entry,
@@ -66,6 +74,7 @@ use console::{
 use ledger_block::{Deployment, Transition};
 use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
 use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
+use utilities::try_vm_runtime;
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
@@ -85,6 +94,7 @@ pub enum CallStack<N: Network> {
     Evaluate(Authorization<N>),
     Execute(Authorization<N>, Arc<RwLock<Trace<N>>>),
     PackageRun(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
+    CheckExecution(Authorization<N>, Arc<RwLock<Option<SynthesisDiagnostics<N>>>>),
 }
 
 impl<N: Network> CallStack<N> {
@@ -97,6 +107,14 @@ impl<N: Network> CallStack<N> {
     pub fn execute(authorization: Authorization<N>, trace: Arc<RwLock<Trace<N>>>) -> Result<Self> {
         Ok(CallStack::Execute(authorization, trace))
     }
+
+    /// Initializes a call stack as `Self::CheckExecution`.
+    pub fn check_execution(
+        authorization: Authorization<N>,
+        diagnostics: Arc<RwLock<Option<SynthesisDiagnostics<N>>>>,
+    ) -> Result<Self> {
+        Ok(CallStack::CheckExecution(authorization, diagnostics))
+    }
 }
 
 impl<N: Network> CallStack<N> {
@@ -124,6 +142,9 @@ impl<N: Network> CallStack<N> {
             CallStack::PackageRun(requests, private_key, assignments) => {
                 CallStack::PackageRun(requests.clone(), *private_key, Arc::new(RwLock::new(assignments.read().clone())))
             }
+            CallStack::CheckExecution(authorization, diagnostics) => {
+                CallStack::CheckExecution(authorization.replicate(), Arc::new(RwLock::new(diagnostics.read().clone())))
+            }
         }
     }
 
@@ -135,7 +156,9 @@ impl<N: Network> CallStack<N> {
             | CallStack::CheckDeployment(requests, ..)
             | CallStack::PackageRun(requests, ..) => requests.push(request),
             CallStack::Evaluate(authorization) => authorization.push(request),
-            CallStack::Execute(authorization, ..) => authorization.push(request),
+            CallStack::Execute(authorization, ..) | CallStack::CheckExecution(authorization, ..) => {
+                authorization.push(request)
+            }
         }
         Ok(())
     }
@@ -150,7 +173,9 @@ impl<N: Network> CallStack<N> {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
             CallStack::Evaluate(authorization) => authorization.next(),
-            CallStack::Execute(authorization, ..) => authorization.next(),
+            CallStack::Execute(authorization, ..) | CallStack::CheckExecution(authorization, ..) => {
+                authorization.next()
+            }
         }
     }
 
@@ -164,7 +189,9 @@ impl<N: Network> CallStack<N> {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
             CallStack::Evaluate(authorization) => authorization.peek_next(),
-            CallStack::Execute(authorization, ..) => authorization.peek_next(),
+            CallStack::Execute(authorization, ..) | CallStack::CheckExecution(authorization, ..) => {
+                authorization.peek_next()
+            }
         }
     }
 }
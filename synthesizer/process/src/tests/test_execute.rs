@@ -17,6 +17,7 @@ use crate::{
     CallStack,
     Process,
     Stack,
+    SynthesisDiagnostics,
     Trace,
 };
 use circuit::{network::AleoV0, Aleo};
@@ -1304,6 +1305,79 @@ finalize compute:
     assert_eq!(candidate, Value::from_str("8u64").unwrap());
 }
 
+#[test]
+fn test_process_execute_and_finalize_halt_code() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+function compute:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    async compute r0 r1 into r2;
+    output r2 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    div r0 r1 into r2;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input values, which divide by zero in the finalize scope.
+    let r0 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("0u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Ensure the finalize scope halts, and the error carries the halt and its code.
+    let error = process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap_err();
+    let halt = HaltError::find(&error).unwrap();
+    assert_eq!(halt.code(), HaltCode::DivisionByZero);
+    assert_eq!(halt.message(), "Integer division failed on: 3u64 and 0u64");
+    // Ensure the message of the error is not changed by the code.
+    assert!(error.to_string().ends_with("): Integer division failed on: 3u64 and 0u64"), "{error}");
+}
+
 #[test]
 fn test_process_execute_and_finalize_increment_decrement_via_get_set() {
     // Initialize a new program.
@@ -2645,3 +2719,119 @@ fn test_program_exceeding_transaction_spend_limit() {
     let result = Stack::initialize(&process, &program);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_process_check_execution() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program overflow.aleo;
+
+function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    mul r0 1u8 into r2;
+    add r2 r1 into r3;
+    output r3 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize a function call that overflows in the `add` instruction.
+    let r0 = Value::<CurrentNetwork>::from_str("255u8").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("1u8").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+
+    // Check that the execution is not satisfied, and that the `add` instruction is reported.
+    let error = process.check_execution::<CurrentAleo, _>(authorization, rng).unwrap_err();
+    let diagnostics = error.downcast_ref::<SynthesisDiagnostics<CurrentNetwork>>().unwrap();
+    assert_eq!(diagnostics.program_id, *program.id());
+    assert_eq!(diagnostics.function_name, function_name);
+    assert!(diagnostics.scope.starts_with("instruction_1"), "Unexpected scope '{}'", diagnostics.scope);
+    assert_eq!(diagnostics.instruction, Some((1, "add r2 r1 into r3;".to_string())));
+    assert_eq!(diagnostics.halt_code, None);
+
+    // Authorize a function call that does not overflow.
+    let r0 = Value::<CurrentNetwork>::from_str("254u8").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("1u8").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+
+    // Check that the execution is satisfied.
+    process.check_execution::<CurrentAleo, _>(authorization.replicate(), rng).unwrap();
+
+    // Check that proving the execution agrees.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), &[Value::<CurrentNetwork>::from_str("255u8").unwrap()]);
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_check_execution_halt_code() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program halting.aleo;
+
+function compute:
+    input r0 as u8.private;
+    add r0 1u8 into r1;
+    div r1 0u8 into r2;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize a function call, which divides by zero in the `div` instruction.
+    let r0 = Value::<CurrentNetwork>::from_str("3u8").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+        .unwrap();
+
+    // Check that the evaluation halts with the code of the halt.
+    let error = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap_err();
+    assert_eq!(HaltError::find(&error).map(HaltError::code), Some(HaltCode::DivisionByZero));
+
+    // Check that the execution halts with the code of the halt.
+    let error = process.execute::<CurrentAleo, _>(authorization.replicate(), rng).unwrap_err();
+    assert_eq!(HaltError::find(&error).map(HaltError::code), Some(HaltCode::DivisionByZero));
+
+    // Check that the synthesis halts, and that the halt and the `div` instruction are reported.
+    let error = process.check_execution::<CurrentAleo, _>(authorization, rng).unwrap_err();
+    let diagnostics = error.downcast_ref::<SynthesisDiagnostics<CurrentNetwork>>().unwrap();
+    assert_eq!(diagnostics.program_id, *program.id());
+    assert_eq!(diagnostics.function_name, function_name);
+    assert_eq!(diagnostics.scope, "instruction_1");
+    assert_eq!(diagnostics.instruction, Some((1, "div r1 0u8 into r2;".to_string())));
+    assert_eq!(diagnostics.halt_code, Some(HaltCode::DivisionByZero));
+}
@@ -18,6 +18,9 @@ pub use call_metrics::*;
 mod inclusion;
 pub use inclusion::*;
 
+mod synthesis_diagnostics;
+pub use synthesis_diagnostics::*;
+
 use circuit::Assignment;
 use console::{
     network::prelude::*,
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::{HaltCode, Network},
+    program::{Identifier, ProgramID},
+};
+
+use core::fmt;
+
+/// The prefix of the scope each instruction is synthesized in, when checking an execution.
+pub(crate) const INSTRUCTION_SCOPE_PREFIX: &str = "instruction_";
+
+/// Describes the first unsatisfied constraint found while synthesizing a transition circuit,
+/// or the halt that aborted the synthesis.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SynthesisDiagnostics<N: Network> {
+    /// The program ID of the unsatisfied transition.
+    pub program_id: ProgramID<N>,
+    /// The function name of the unsatisfied transition.
+    pub function_name: Identifier<N>,
+    /// The index of the first unsatisfied constraint in the transition circuit.
+    /// If the synthesis halted, this is the number of constraints synthesized before the halt.
+    pub constraint_index: u64,
+    /// The scope of the first unsatisfied constraint, or of the halt.
+    pub scope: String,
+    /// The index and source of the instruction that introduced the constraint, if any.
+    pub instruction: Option<(usize, String)>,
+    /// The code of the halt, if the synthesis halted instead of completing.
+    pub halt_code: Option<HaltCode>,
+}
+
+impl<N: Network> SynthesisDiagnostics<N> {
+    /// Returns the index of the instruction for the given constraint scope, if the constraint was introduced by one.
+    pub(crate) fn instruction_index(scope: &str) -> Option<usize> {
+        scope.split('.').next()?.strip_prefix(INSTRUCTION_SCOPE_PREFIX)?.parse().ok()
+    }
+}
+
+impl<N: Network> fmt::Display for SynthesisDiagnostics<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.halt_code {
+            Some(code) => write!(
+                f,
+                "'{}/{}' halted ({code}) after {} constraints (scope '{}')",
+                self.program_id, self.function_name, self.constraint_index, self.scope
+            )?,
+            None => write!(
+                f,
+                "'{}/{}' is not satisfied: constraint {} (scope '{}')",
+                self.program_id, self.function_name, self.constraint_index, self.scope
+            )?,
+        }
+        match &self.instruction {
+            Some((index, instruction)) => write!(f, " in instruction {index} ({instruction})"),
+            None => write!(f, " outside of the function instructions"),
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for SynthesisDiagnostics<N> {}
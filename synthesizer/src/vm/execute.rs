@@ -104,6 +104,29 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         debug_assert!(authorization.is_fee_private() || authorization.is_fee_public(), "Expected a fee authorization");
         self.execute_fee_authorization_raw(authorization, query, rng)
     }
+
+    /// Checks that the transition circuits of the given authorization are satisfied, without proving them.
+    ///
+    /// This is considerably faster than `execute_authorization`, and is intended for debugging executions.
+    /// If a circuit is not satisfied or halts, the returned error downcasts to `SynthesisDiagnostics`,
+    /// which describes the first unsatisfied constraint or the halt, and the instruction that introduced it.
+    pub fn check_execution<R: Rng + CryptoRng>(&self, authorization: Authorization<N>, rng: &mut R) -> Result<()> {
+        let timer = timer!("VM::check_execution");
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                // Check the execution.
+                $process.check_execution::<$aleo, _>(authorization.clone(), rng)
+            }};
+        }
+
+        // Check the authorization.
+        let result = process!(self, logic);
+        finish!(timer, "Check the execution");
+        result
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
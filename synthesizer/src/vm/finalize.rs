@@ -38,6 +38,34 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         candidate_solutions: &Solutions<N>,
         candidate_transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
         rng: &mut R,
+    ) -> Result<(Ratifications<N>, Transactions<N>, Vec<N::TransactionID>, Vec<FinalizeOperation<N>>)> {
+        self.speculate_with_block_time(
+            state,
+            coinbase_reward,
+            N::BLOCK_TIME,
+            candidate_ratifications,
+            candidate_solutions,
+            candidate_transactions,
+            rng,
+        )
+    }
+
+    /// Speculates on the given list of transactions in the VM, for a chain with the given target block time.
+    /// The block time (in seconds) is used to compute the block reward, if `coinbase_reward` is `Some`.
+    ///
+    /// Returns the confirmed transactions, aborted transaction IDs,
+    /// and finalize operations from pre-ratify and post-ratify.
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn speculate_with_block_time<'a, R: Rng + CryptoRng>(
+        &self,
+        state: FinalizeGlobalState,
+        coinbase_reward: Option<u64>,
+        block_time: u16,
+        candidate_ratifications: Vec<Ratify<N>>,
+        candidate_solutions: &Solutions<N>,
+        candidate_transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
+        rng: &mut R,
     ) -> Result<(Ratifications<N>, Transactions<N>, Vec<N::TransactionID>, Vec<FinalizeOperation<N>>)> {
         let timer = timer!("VM::speculate");
 
@@ -61,6 +89,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             self.atomic_speculate(
                 state,
                 coinbase_reward,
+                block_time,
                 candidate_ratifications,
                 candidate_solutions,
                 verified_transactions.into_iter(),
@@ -126,8 +155,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             transactions.iter().map(|confirmed| confirmed.to_unconfirmed_transaction()).collect::<Result<Vec<_>>>()?;
 
         // Performs a **dry-run** over the list of ratifications, solutions, and transactions.
+        // Note: The block time is unused, as the ratifications are not modified when `coinbase_reward = None`.
         let (speculate_ratifications, confirmed_transactions, aborted_transactions, ratified_finalize_operations) =
-            self.atomic_speculate(state, None, candidate_ratifications, solutions, candidate_transactions.iter())?;
+            self.atomic_speculate(
+                state,
+                None,
+                N::BLOCK_TIME,
+                candidate_ratifications,
+                solutions,
+                candidate_transactions.iter(),
+            )?;
 
         // Ensure the ratifications after speculation match.
         if ratifications != &speculate_ratifications {
@@ -187,11 +224,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///   - If `coinbase_reward = None`, then the `ratifications` will not be modified.
     ///   - If `coinbase_reward = Some(coinbase_reward)`, then the method will append a
     ///     `Ratify::BlockReward(block_reward)` and `Ratify::PuzzleReward(puzzle_reward)`
-    ///     to the front of the `ratifications` list.
+    ///     to the front of the `ratifications` list, where the block reward is scheduled by `block_time`.
+    #[allow(clippy::too_many_arguments)]
     fn atomic_speculate<'a>(
         &self,
         state: FinalizeGlobalState,
         coinbase_reward: Option<u64>,
+        block_time: u16,
         ratifications: Vec<Ratify<N>>,
         solutions: &Solutions<N>,
         transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
@@ -235,12 +274,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _, _) => true,
+                Ratify::Genesis(..) => true,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _, _) => false,
+                Ratify::Genesis(..) => false,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
             });
 
@@ -508,7 +547,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Compute the block reward.
                     let block_reward = ledger_block::block_reward(
                         N::STARTING_SUPPLY,
-                        N::BLOCK_TIME,
+                        block_time,
                         coinbase_reward,
                         transaction_fees,
                     );
@@ -570,12 +609,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, {
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _, _) => true,
+                Ratify::Genesis(..) => true,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _, _) => false,
+                Ratify::Genesis(..) => false,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
             });
 
@@ -922,7 +961,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Iterate over the ratifications.
         for ratify in pre_ratifications {
             match ratify {
-                Ratify::Genesis(committee, public_balances, bonded_balances) => {
+                Ratify::Genesis(committee, public_balances, bonded_balances, _) => {
                     // Ensure this is the genesis block.
                     ensure!(state.block_height() == 0, "Ratify::Genesis(..) expected a genesis block");
                     // Ensure the genesis committee round is 0.
@@ -1601,7 +1640,14 @@ finalize transfer_public:
 
         // Ensure the dry run of the redeployment will cause a reject transaction to be created.
         let (_, candidate_transactions, aborted_transaction_ids, _) = vm
-            .atomic_speculate(sample_finalize_state(1), None, vec![], &None.into(), [deployment_transaction].iter())
+            .atomic_speculate(
+                sample_finalize_state(1),
+                None,
+                CurrentNetwork::BLOCK_TIME,
+                vec![],
+                &None.into(),
+                [deployment_transaction].iter(),
+            )
             .unwrap();
         assert_eq!(candidate_transactions.len(), 1);
         assert!(matches!(candidate_transactions[0], ConfirmedTransaction::RejectedDeploy(..)));
@@ -1703,8 +1749,16 @@ finalize transfer_public:
         // Transfer_20 -> Balance = 20 - 20 = 0
         {
             let transactions = [mint_10.clone(), transfer_10.clone(), transfer_20.clone()];
-            let (_, confirmed_transactions, aborted_transaction_ids, _) =
-                vm.atomic_speculate(sample_finalize_state(1), None, vec![], &None.into(), transactions.iter()).unwrap();
+            let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
+                .atomic_speculate(
+                    sample_finalize_state(1),
+                    None,
+                    CurrentNetwork::BLOCK_TIME,
+                    vec![],
+                    &None.into(),
+                    transactions.iter(),
+                )
+                .unwrap();
 
             // Assert that all the transactions are accepted.
             assert_eq!(confirmed_transactions.len(), 3);
@@ -1723,8 +1777,16 @@ finalize transfer_public:
         // Transfer_30 -> Balance = 30 - 30 = 0
         {
             let transactions = [transfer_20.clone(), mint_10.clone(), mint_20.clone(), transfer_30.clone()];
-            let (_, confirmed_transactions, aborted_transaction_ids, _) =
-                vm.atomic_speculate(sample_finalize_state(1), None, vec![], &None.into(), transactions.iter()).unwrap();
+            let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
+                .atomic_speculate(
+                    sample_finalize_state(1),
+                    None,
+                    CurrentNetwork::BLOCK_TIME,
+                    vec![],
+                    &None.into(),
+                    transactions.iter(),
+                )
+                .unwrap();
 
             // Assert that all the transactions are accepted.
             assert_eq!(confirmed_transactions.len(), 4);
@@ -1743,8 +1805,16 @@ finalize transfer_public:
         // Transfer_10 -> Balance = 0 - 10 = -10 (should be rejected)
         {
             let transactions = [transfer_20.clone(), transfer_10.clone()];
-            let (_, confirmed_transactions, aborted_transaction_ids, _) =
-                vm.atomic_speculate(sample_finalize_state(1), None, vec![], &None.into(), transactions.iter()).unwrap();
+            let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
+                .atomic_speculate(
+                    sample_finalize_state(1),
+                    None,
+                    CurrentNetwork::BLOCK_TIME,
+                    vec![],
+                    &None.into(),
+                    transactions.iter(),
+                )
+                .unwrap();
 
             // Assert that the accepted and rejected transactions are correct.
             assert_eq!(confirmed_transactions.len(), 2);
@@ -1767,8 +1837,16 @@ finalize transfer_public:
         // Transfer_10 -> Balance = 10 - 10 = 0
         {
             let transactions = [mint_20.clone(), transfer_30.clone(), transfer_20.clone(), transfer_10.clone()];
-            let (_, confirmed_transactions, aborted_transaction_ids, _) =
-                vm.atomic_speculate(sample_finalize_state(1), None, vec![], &None.into(), transactions.iter()).unwrap();
+            let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
+                .atomic_speculate(
+                    sample_finalize_state(1),
+                    None,
+                    CurrentNetwork::BLOCK_TIME,
+                    vec![],
+                    &None.into(),
+                    transactions.iter(),
+                )
+                .unwrap();
 
             // Assert that the accepted and rejected transactions are correct.
             assert_eq!(confirmed_transactions.len(), 4);
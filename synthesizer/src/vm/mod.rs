@@ -22,9 +22,10 @@ mod finalize;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, convert, process};
+use algorithms::crypto_hash::sha256;
 use console::{
     account::{Address, PrivateKey},
-    network::prelude::*,
+    network::{prelude::*, HaltError},
     program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Value},
     types::{Field, Group, U64},
 };
@@ -84,8 +85,12 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     atomic_lock: Arc<Mutex<()>>,
     /// The lock for ensuring there is no concurrency when advancing blocks.
     block_lock: Arc<Mutex<()>>,
-    /// A cache containing the list of recent partially-verified transactions.
-    partially_verified_transactions: Arc<RwLock<LruCache<N::TransactionID, ()>>>,
+    /// A cache containing the list of recent partially-verified transactions,
+    /// mapping each transaction ID to the checksum of the verified transaction bytes.
+    partially_verified_transactions: Arc<RwLock<LruCache<N::TransactionID, [u8; 32]>>>,
+    /// The number of proofs verified by the VM, for tests that check the partially-verified cache.
+    #[cfg(test)]
+    num_proof_verifications: Arc<std::sync::atomic::AtomicUsize>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -192,6 +197,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             partially_verified_transactions: Arc::new(RwLock::new(LruCache::new(
                 NonZeroUsize::new(Transactions::<N>::MAX_TRANSACTIONS).unwrap(),
             ))),
+            #[cfg(test)]
+            num_proof_verifications: Default::default(),
         })
     }
 
@@ -215,7 +222,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Returns the partially-verified transactions.
     #[inline]
-    pub fn partially_verified_transactions(&self) -> Arc<RwLock<LruCache<N::TransactionID, ()>>> {
+    pub fn partially_verified_transactions(&self) -> Arc<RwLock<LruCache<N::TransactionID, [u8; 32]>>> {
         self.partially_verified_transactions.clone()
     }
 }
@@ -264,6 +271,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns a new genesis block for a beacon chain.
     pub fn genesis_beacon<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Block<N>> {
+        self.genesis_beacon_with_spec_id(private_key, None, rng)
+    }
+
+    /// Returns a new genesis block for a beacon chain, which commits to the given chain spec ID, if one is given.
+    pub fn genesis_beacon_with_spec_id<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        spec_id: Option<Field<N>>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
         let private_keys = [*private_key, PrivateKey::new(rng)?, PrivateKey::new(rng)?, PrivateKey::new(rng)?];
 
         // Construct the committee members.
@@ -292,7 +309,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             .map(|(address, (amount, _))| (*address, (*address, *address, *amount)))
             .collect();
         // Return the genesis block.
-        self.genesis_quorum(private_key, committee, public_balances, bonded_balances, rng)
+        self.genesis_quorum_with_spec_id(private_key, committee, public_balances, bonded_balances, spec_id, rng)
     }
 
     /// Returns a new genesis block for a quorum chain.
@@ -303,6 +320,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         public_balances: IndexMap<Address<N>, u64>,
         bonded_balances: IndexMap<Address<N>, (Address<N>, Address<N>, u64)>,
         rng: &mut R,
+    ) -> Result<Block<N>> {
+        self.genesis_quorum_with_spec_id(private_key, committee, public_balances, bonded_balances, None, rng)
+    }
+
+    /// Returns a new genesis block for a quorum chain, which commits to the given chain spec ID, if one is given.
+    ///
+    /// Note: The chain spec ID is committed in the genesis ratification, so that it changes the genesis block hash.
+    pub fn genesis_quorum_with_spec_id<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        committee: Committee<N>,
+        public_balances: IndexMap<Address<N>, u64>,
+        bonded_balances: IndexMap<Address<N>, (Address<N>, Address<N>, u64)>,
+        spec_id: Option<Field<N>>,
+        rng: &mut R,
     ) -> Result<Block<N>> {
         // Retrieve the total stake.
         let total_stake = committee.total_stake();
@@ -332,8 +364,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let inputs = [caller.to_string(), format!("{amount}_u64")];
 
         // Prepare the ratifications.
-        let ratifications =
-            vec![Ratify::Genesis(Box::new(committee), Box::new(public_balances), Box::new(bonded_balances))];
+        let ratifications = vec![Ratify::Genesis(
+            Box::new(committee),
+            Box::new(public_balances),
+            Box::new(bonded_balances),
+            spec_id.map(Box::new),
+        )];
         // Prepare the solutions.
         let solutions = Solutions::<N>::from(None); // The genesis block does not require solutions.
         // Prepare the aborted solution IDs.
@@ -432,11 +468,28 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         removal_error
                     })?;
                 }
+                // Clear the partially-verified transactions cache, as the block may have contained a deployment.
+                self.partially_verified_transactions.write().clear();
                 // Return the finalize error.
                 Err(finalize_error)
             }
         }
     }
+
+    /// Removes the last `n` blocks from the VM.
+    ///
+    /// Note: This clears the partially-verified transactions cache, as a removed deployment may be
+    /// redeployed with different verifying keys, which the cached executions were not verified against.
+    pub fn remove_last_n(&self, n: u32) -> Result<()> {
+        // Acquire the block lock, which is needed to ensure this function is not called concurrently.
+        let _block_lock = self.block_lock.lock();
+
+        // Remove the blocks.
+        self.block_store().remove_last_n(n)?;
+        // Clear the partially-verified transactions cache.
+        self.partially_verified_transactions.write().clear();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -454,7 +507,6 @@ pub(crate) mod test_helpers {
 
     use indexmap::IndexMap;
     use once_cell::sync::OnceCell;
-    use std::borrow::Borrow;
     use synthesizer_snark::VerifyingKey;
 
     pub(crate) type CurrentNetwork = MainnetV0;
@@ -729,7 +781,7 @@ function compute:
     ) -> Result<Block<MainnetV0>> {
         // Get the most recent block.
         let block_hash =
-            vm.block_store().get_block_hash(*vm.block_store().heights().max().unwrap().borrow()).unwrap().unwrap();
+            vm.block_store().get_block_hash(vm.block_store().max_height().unwrap()).unwrap().unwrap();
         let previous_block = vm.block_store().get_block(&block_hash).unwrap().unwrap();
 
         // Construct the new block header.
@@ -124,11 +124,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         lap!(timer, "Check for duplicate elements");
 
-        // First, verify the fee.
-        self.check_fee(transaction, rejected_id)?;
+        // Compute the checksum of the transaction bytes.
+        // Note: The transaction ID does not commit to the proofs, so the cache is keyed on the full bytes.
+        let checksum = sha256(&transaction.to_bytes_le()?);
+        // Check if the transaction exists in the partially-verified cache, with the same checksum.
+        let is_partially_verified =
+            self.partially_verified_transactions.read().peek(&transaction.id()) == Some(&checksum);
 
-        // Check if the transaction exists in the partially-verified cache.
-        let is_partially_verified = self.partially_verified_transactions.read().peek(&transaction.id()).is_some();
+        // First, verify the fee.
+        self.check_fee_with_cache(transaction, rejected_id, is_partially_verified)?;
 
         // Next, verify the deployment or execution.
         match transaction {
@@ -156,7 +160,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Verify the deployment.
                     match try_vm_runtime!(|| self.check_deployment_internal(deployment, rng)) {
                         Ok(result) => result?,
-                        Err(_) => bail!("VM safely halted transaction '{id}' during verification"),
+                        Err(payload) => {
+                            let context = format!("VM safely halted transaction '{id}' during verification");
+                            return Err(HaltError::from_panic(payload).context(context));
+                        }
                     }
                 }
             }
@@ -172,7 +179,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Verify the execution.
                 match try_vm_runtime!(|| self.check_execution_internal(execution, is_partially_verified)) {
                     Ok(result) => result?,
-                    Err(_) => bail!("VM safely halted transaction '{id}' during verification"),
+                    Err(payload) => {
+                        let context = format!("VM safely halted transaction '{id}' during verification");
+                        return Err(HaltError::from_panic(payload).context(context));
+                    }
                 }
             }
             Transaction::Fee(..) => { /* no-op */ }
@@ -181,7 +191,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // If the above checks have passed and this is not a fee transaction,
         // then add the transaction ID to the partially-verified transactions cache.
         if !matches!(transaction, Transaction::Fee(..)) && !is_partially_verified {
-            self.partially_verified_transactions.write().push(transaction.id(), ());
+            self.partially_verified_transactions.write().push(transaction.id(), checksum);
         }
 
         finish!(timer, "Verify the transaction");
@@ -191,6 +201,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the `fee` in the given transaction. On failure, returns an error.
     #[inline]
     pub fn check_fee(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        self.check_fee_with_cache(transaction, rejected_id, false)
+    }
+
+    /// Verifies the `fee` in the given transaction, skipping the fee proof if the transaction
+    /// is partially-verified. On failure, returns an error.
+    fn check_fee_with_cache(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        is_partially_verified: bool,
+    ) -> Result<()> {
         match transaction {
             Transaction::Deploy(id, _, deployment, fee) => {
                 // Ensure the rejected ID is not present.
@@ -206,7 +227,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
                 }
                 // Verify the fee.
-                self.check_fee_internal(fee, deployment_id)?;
+                self.check_fee_internal(fee, deployment_id, is_partially_verified)?;
             }
             Transaction::Execute(id, execution, fee) => {
                 // Ensure the rejected ID is not present.
@@ -234,7 +255,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)");
                     }
                     // Verify the fee.
-                    self.check_fee_internal(fee, execution_id)?;
+                    self.check_fee_internal(fee, execution_id, is_partially_verified)?;
                 } else {
                     // Ensure the fee can be safely skipped.
                     ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
@@ -246,7 +267,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Transaction::Fee(id, fee) => {
                 // Verify the fee.
                 match rejected_id {
-                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id)?,
+                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id, is_partially_verified)?,
                     None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
                 }
             }
@@ -271,6 +292,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }};
         }
 
+        #[cfg(test)]
+        self.num_proof_verifications.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
         // Process the logic.
         let timer = timer!("VM::check_deployment");
         let result = process!(self, logic).map_err(|error| anyhow!("Deployment verification failed - {error}"));
@@ -289,7 +313,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Verify the execution proof, if it has not been partially-verified before.
         let verification = match is_partially_verified {
             true => Ok(()),
-            false => self.process.read().verify_execution(execution),
+            false => {
+                #[cfg(test)]
+                self.num_proof_verifications.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                self.process.read().verify_execution(execution)
+            }
         };
         lap!(timer, "Verify the execution");
 
@@ -312,15 +340,27 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
     /// use `VM::check_fee` instead.
     #[inline]
-    fn check_fee_internal(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+    fn check_fee_internal(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        is_partially_verified: bool,
+    ) -> Result<()> {
         let timer = timer!("VM::check_fee");
 
         // Ensure the fee does not exceed the limit.
         let fee_amount = fee.amount()?;
         ensure!(*fee_amount <= N::MAX_FEE, "Fee verification failed: fee exceeds the maximum limit");
 
-        // Verify the fee.
-        let verification = self.process.read().verify_fee(fee, deployment_or_execution_id);
+        // Verify the fee proof, if it has not been partially-verified before.
+        let verification = match is_partially_verified {
+            true => Ok(()),
+            false => {
+                #[cfg(test)]
+                self.num_proof_verifications.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                self.process.read().verify_fee(fee, deployment_or_execution_id)
+            }
+        };
         lap!(timer, "Verify the fee");
 
         // TODO (howardwu): This check is technically insufficient. Consider moving this upstream
@@ -369,6 +409,7 @@ mod tests {
         types::Field,
     };
     use ledger_block::{Block, Header, Metadata, Transaction};
+    use std::sync::atomic::Ordering;
 
     type CurrentNetwork = test_helpers::CurrentNetwork;
 
@@ -393,6 +434,96 @@ mod tests {
         vm.check_transaction(&execution_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_verify_partially_verified_with_tampered_proof() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch two execution transactions.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let other = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+
+        // Ensure the transaction verifies, which adds it to the partially-verified cache.
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        assert!(vm.partially_verified_transactions().read().contains(&transaction.id()));
+
+        // Construct a transaction with the same ID, but with the execution proof of the other transaction.
+        let Transaction::Execute(_, execution, fee) = &transaction else { panic!("Expected an execution") };
+        let Transaction::Execute(_, other_execution, _) = &other else { panic!("Expected an execution") };
+        let tampered_execution = Execution::from(
+            execution.transitions().cloned(),
+            execution.global_state_root(),
+            other_execution.proof().cloned(),
+        )
+        .unwrap();
+        let tampered = Transaction::from_execution(tampered_execution, fee.clone()).unwrap();
+        assert_eq!(transaction.id(), tampered.id());
+        assert_ne!(transaction.to_bytes_le().unwrap(), tampered.to_bytes_le().unwrap());
+
+        // Ensure the tampered transaction is fully re-verified and rejected.
+        let num_proof_verifications = vm.num_proof_verifications.load(Ordering::SeqCst);
+        assert!(vm.check_transaction(&tampered, None, rng).is_err());
+        assert!(vm.num_proof_verifications.load(Ordering::SeqCst) > num_proof_verifications);
+        // Ensure the original transaction still verifies.
+        vm.check_transaction(&transaction, None, rng).unwrap();
+    }
+
+    #[test]
+    fn test_check_speculate_skips_verified_proofs() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Fetch two execution transactions, and verify them, which adds them to the partially-verified cache.
+        let transactions = [
+            crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng),
+            crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng),
+        ];
+        for transaction in &transactions {
+            vm.check_transaction(transaction, None, rng).unwrap();
+        }
+
+        // Construct the next block with the verified transactions.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &private_key, &transactions, rng).unwrap();
+        assert_eq!(block.transactions().num_accepted(), transactions.len());
+
+        // Ensure validating the block does not verify any proof.
+        let num_proof_verifications = vm.num_proof_verifications.load(Ordering::SeqCst);
+        let state = sample_finalize_state(1);
+        vm.check_speculate(state, block.ratifications(), block.solutions(), block.transactions(), rng).unwrap();
+        assert_eq!(vm.num_proof_verifications.load(Ordering::SeqCst), num_proof_verifications);
+
+        // Ensure a transaction that is not in the cache has both of its proofs verified.
+        vm.partially_verified_transactions().write().clear();
+        vm.check_transaction(&transactions[0], None, rng).unwrap();
+        assert_eq!(vm.num_proof_verifications.load(Ordering::SeqCst), num_proof_verifications + 2);
+    }
+
+    #[test]
+    fn test_remove_last_n_clears_verified_transactions() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Fetch an execution transaction, and verify it, which adds it to the partially-verified cache.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        assert!(vm.partially_verified_transactions().read().contains(&transaction.id()));
+
+        // Add the next block with the verified transaction.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &private_key, &[transaction.clone()], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Remove the block, which could have contained a deployment that is later redeployed with other keys.
+        vm.remove_last_n(1).unwrap();
+        assert!(vm.partially_verified_transactions().read().is_empty());
+
+        // Ensure the transaction has its proofs verified again.
+        let num_proof_verifications = vm.num_proof_verifications.load(Ordering::SeqCst);
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        assert_eq!(vm.num_proof_verifications.load(Ordering::SeqCst), num_proof_verifications + 2);
+    }
+
     #[test]
     fn test_verify_deployment() {
         let rng = &mut TestRng::default();
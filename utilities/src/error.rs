@@ -41,6 +41,7 @@ impl Error for crate::io::Error {}
 /// This macro provides a VM runtime environment which will safely halt
 /// without producing logs that look like unexpected behavior.
 /// It prints to stderr using the format: "VM safely halted at <location>: <halt message>".
+/// A halt is returned as the error of the result, and its `HaltError` is recovered with `HaltError::from_panic`.
 #[macro_export]
 macro_rules! try_vm_runtime {
     ($e:expr) => {{
@@ -48,19 +49,18 @@ macro_rules! try_vm_runtime {
 
         // Set a custom hook before calling catch_unwind to
         // indicate that the panic was expected and handled.
+        let previous_hook = panic::take_hook();
         panic::set_hook(Box::new(|e| {
-            let msg = e.to_string();
-            let msg = msg.split_ascii_whitespace().skip_while(|&word| word != "panicked").collect::<Vec<&str>>();
-            let mut msg = msg.join(" ");
-            msg = msg.replacen("panicked", "VM safely halted", 1);
-            eprintln!("{msg}");
+            let location = e.location().map(|location| location.to_string()).unwrap_or_default();
+            let message = $crate::HaltError::message_of(e.payload()).unwrap_or("unknown halt");
+            eprintln!("VM safely halted at {location}: {message}");
         }));
 
         // Perform the operation that may panic.
         let result = panic::catch_unwind(panic::AssertUnwindSafe($e));
 
-        // Restore the standard panic hook.
-        let _ = panic::take_hook();
+        // Restore the previous panic hook, so that a nested runtime does not remove the hook of its caller.
+        panic::set_hook(previous_hook);
 
         // Return the result, allowing regular error-handling.
         result
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{any::Any, fmt};
+
+/// The category of a halt, so that failures can be distinguished without inspecting the message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HaltCode {
+    /// An arithmetic operation overflowed or underflowed.
+    Overflow,
+    /// A division or remainder operation had a divisor of zero.
+    DivisionByZero,
+    /// An operation is not supported for the type of its operands.
+    TypeMismatch,
+    /// A value is outside of the range permitted by an operation.
+    RangeViolation,
+    /// A value could not be parsed.
+    ParseFailure,
+    /// Any other halt.
+    Custom,
+}
+
+impl fmt::Display for HaltCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "overflow"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::TypeMismatch => write!(f, "type mismatch"),
+            Self::RangeViolation => write!(f, "range violation"),
+            Self::ParseFailure => write!(f, "parse failure"),
+            Self::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+/// A halt, along with its code. This is the panic payload of a halt.
+///
+/// Note: As the payload is not a string, a panic hook must retrieve the message with `HaltError::message_of`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HaltError {
+    /// The code of the halt.
+    code: HaltCode,
+    /// The message of the halt.
+    message: String,
+}
+
+impl HaltError {
+    /// Initializes a new halt error.
+    pub fn new<S: Into<String>>(code: HaltCode, message: S) -> Self {
+        Self { code, message: message.into() }
+    }
+
+    /// Halts with this error as the panic payload.
+    pub fn halt<T>(self) -> T {
+        std::panic::panic_any(self)
+    }
+
+    /// Initializes a halt error from the payload of a caught panic.
+    /// If the panic did not originate from a halt, the code is `Custom`.
+    pub fn from_panic(payload: Box<dyn Any + Send>) -> Self {
+        let payload = match payload.downcast::<Self>() {
+            Ok(error) => return *error,
+            Err(payload) => payload,
+        };
+        let message = match Self::message_of(payload.as_ref()) {
+            Some(message) => message.to_string(),
+            None => "unknown halt".to_string(),
+        };
+        Self { code: HaltCode::Custom, message }
+    }
+
+    /// Returns the message of the given panic payload, if it is a halt or a string.
+    pub fn message_of(payload: &(dyn Any + Send)) -> Option<&str> {
+        if let Some(error) = payload.downcast_ref::<Self>() {
+            Some(&error.message)
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            Some(message)
+        } else {
+            payload.downcast_ref::<&'static str>().copied()
+        }
+    }
+
+    /// Returns the halt error in the chain of the given error, if any.
+    pub fn find(error: &anyhow::Error) -> Option<&Self> {
+        error.chain().find_map(|error| error.downcast_ref::<Self>())
+    }
+
+    /// Returns an error with the given context, followed by the message of the halt.
+    /// The halt error remains in the chain of the returned error (see `HaltError::find`).
+    pub fn context<C: fmt::Display>(self, context: C) -> anyhow::Error {
+        let message = format!("{context}: {}", self.message);
+        anyhow::Error::new(self).context(message)
+    }
+
+    /// Returns the code of the halt.
+    pub const fn code(&self) -> HaltCode {
+        self.code
+    }
+
+    /// Returns the message of the halt.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for HaltError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for HaltError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_panic() {
+        // Ensure the halt code is carried in the payload.
+        let result = std::panic::catch_unwind(|| HaltError::new(HaltCode::Overflow, "Overflowed").halt::<()>());
+        let error = HaltError::from_panic(result.unwrap_err());
+        assert_eq!(error.code(), HaltCode::Overflow);
+        assert_eq!(error.message(), "Overflowed");
+
+        // Ensure a panic that is not a halt is recorded as custom, even if its message names a code.
+        let result = std::panic::catch_unwind(|| panic!("Panicked (overflow)"));
+        let error = HaltError::from_panic(result.unwrap_err());
+        assert_eq!(error.code(), HaltCode::Custom);
+        assert_eq!(error.message(), "Panicked (overflow)");
+    }
+
+    #[test]
+    fn test_context() {
+        let error = HaltError::new(HaltCode::DivisionByZero, "Divided").context("Failed to evaluate");
+        // Ensure the message is unchanged by the code.
+        assert_eq!(error.to_string(), "Failed to evaluate: Divided");
+        // Ensure the halt error is found in the chain, including after more context is added.
+        assert_eq!(HaltError::find(&error).map(HaltError::code), Some(HaltCode::DivisionByZero));
+        let error = error.context("Failed to execute");
+        assert_eq!(HaltError::find(&error).map(HaltError::code), Some(HaltCode::DivisionByZero));
+        // Ensure an error without a halt has no halt error.
+        assert_eq!(HaltError::find(&anyhow::anyhow!("Failed (division by zero)")), None);
+    }
+
+    #[test]
+    fn test_message_of() {
+        let result = std::panic::catch_unwind(|| HaltError::new(HaltCode::Custom, "Halted").halt::<()>());
+        assert_eq!(HaltError::message_of(result.unwrap_err().as_ref()), Some("Halted"));
+        let result = std::panic::catch_unwind(|| panic!("Panicked {}", 1));
+        assert_eq!(HaltError::message_of(result.unwrap_err().as_ref()), Some("Panicked 1"));
+        let result = std::panic::catch_unwind(|| std::panic::panic_any(1u8));
+        assert_eq!(HaltError::message_of(result.unwrap_err().as_ref()), None);
+    }
+}
@@ -58,6 +58,11 @@ pub use bytes::*;
 pub mod error;
 pub use error::*;
 
+#[cfg(feature = "std")]
+pub mod halt;
+#[cfg(feature = "std")]
+pub use halt::*;
+
 pub mod iterator;
 pub use iterator::*;
 